@@ -3,7 +3,6 @@ import glob
 
 
 parser = DemoParser("path/to/demo.dem")
-df = parser.parse_event("player_death", other=["game_time", "round_start_time"])
-df["player_died_time"] = df["game_time"] - df["round_start_time"]
+df = parser.parse_event("player_death", other=["round", "round_time", "time_since_freeze_end", "time_since_bomb_plant"])
 
-print(df.loc[:, ["attacker_name", "player_died_time"]])
\ No newline at end of file
+print(df.loc[:, ["attacker_name", "round", "time_since_freeze_end", "time_since_bomb_plant"]])
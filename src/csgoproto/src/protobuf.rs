@@ -1,4 +1,5 @@
 // This file is @generated by prost-build.
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgProtoBufHeader {
     #[prost(fixed64, optional, tag="1")]
@@ -24,6 +25,7 @@ pub struct CMsgProtoBufHeader {
     #[prost(uint32, optional, tag="201")]
     pub gc_dir_index_source: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CChinaAgreementSessionsStartAgreementSessionInGameRequest {
     #[prost(uint32, optional, tag="1")]
@@ -33,6 +35,7 @@ pub struct CChinaAgreementSessionsStartAgreementSessionInGameRequest {
     #[prost(string, optional, tag="3")]
     pub client_ipaddress: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CChinaAgreementSessionsStartAgreementSessionInGameResponse {
     #[prost(string, optional, tag="1")]
@@ -74,6 +77,7 @@ impl GcProtoBufMsgSrc {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoidOwner {
     #[prost(uint32, optional, tag="1")]
@@ -81,6 +85,7 @@ pub struct CMsgSoidOwner {
     #[prost(uint64, optional, tag="2")]
     pub id: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSoSingleObject {
     #[prost(int32, optional, tag="2")]
@@ -92,6 +97,7 @@ pub struct CMsgSoSingleObject {
     #[prost(message, optional, tag="5")]
     pub owner_soid: ::core::option::Option<CMsgSoidOwner>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSoMultipleObjects {
     #[prost(message, repeated, tag="2")]
@@ -103,6 +109,7 @@ pub struct CMsgSoMultipleObjects {
 }
 /// Nested message and enum types in `CMsgSOMultipleObjects`.
 pub mod c_msg_so_multiple_objects {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SingleObject {
         #[prost(int32, optional, tag="1")]
@@ -111,6 +118,7 @@ pub mod c_msg_so_multiple_objects {
         pub object_data: ::core::option::Option<::prost::bytes::Bytes>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheSubscribed {
     #[prost(message, repeated, tag="2")]
@@ -122,6 +130,7 @@ pub struct CMsgSoCacheSubscribed {
 }
 /// Nested message and enum types in `CMsgSOCacheSubscribed`.
 pub mod c_msg_so_cache_subscribed {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SubscribedType {
         #[prost(int32, optional, tag="1")]
@@ -130,11 +139,13 @@ pub mod c_msg_so_cache_subscribed {
         pub object_data: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheUnsubscribed {
     #[prost(message, optional, tag="2")]
     pub owner_soid: ::core::option::Option<CMsgSoidOwner>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheSubscriptionCheck {
     #[prost(fixed64, optional, tag="2")]
@@ -142,16 +153,19 @@ pub struct CMsgSoCacheSubscriptionCheck {
     #[prost(message, optional, tag="3")]
     pub owner_soid: ::core::option::Option<CMsgSoidOwner>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheSubscriptionRefresh {
     #[prost(message, optional, tag="2")]
     pub owner_soid: ::core::option::Option<CMsgSoidOwner>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheVersion {
     #[prost(fixed64, optional, tag="1")]
     pub version: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgAccountDetails {
     #[prost(bool, optional, tag="1")]
@@ -191,6 +205,7 @@ pub struct CMsgAccountDetails {
     #[prost(bool, optional, tag="19")]
     pub eligible_for_community_market: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGcMultiplexMessage {
     #[prost(uint32, optional, tag="1")]
@@ -202,11 +217,13 @@ pub struct CMsgGcMultiplexMessage {
     #[prost(bool, optional, tag="4")]
     pub replytogc: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcMultiplexMessageResponse {
     #[prost(uint32, optional, tag="1")]
     pub msgtype: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CgcToGcMsgMasterAck {
     #[prost(uint32, optional, tag="1")]
@@ -214,14 +231,17 @@ pub struct CgcToGcMsgMasterAck {
     #[prost(uint32, optional, tag="2")]
     pub gc_type: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CgcToGcMsgMasterAckResponse {
     #[prost(int32, optional, tag="1", default="2")]
     pub eresult: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CgcToGcMsgMasterStartupComplete {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CgcToGcMsgRouted {
     #[prost(uint32, optional, tag="1")]
@@ -233,6 +253,7 @@ pub struct CgcToGcMsgRouted {
     #[prost(uint32, optional, tag="4")]
     pub ip: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CgcToGcMsgRoutedReply {
     #[prost(uint32, optional, tag="1")]
@@ -240,6 +261,7 @@ pub struct CgcToGcMsgRoutedReply {
     #[prost(bytes="bytes", optional, tag="2")]
     pub net_message: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcUpdateSessionIp {
     #[prost(fixed64, optional, tag="1")]
@@ -247,16 +269,19 @@ pub struct CMsgGcUpdateSessionIp {
     #[prost(fixed32, optional, tag="2")]
     pub ip: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcRequestSessionIp {
     #[prost(fixed64, optional, tag="1")]
     pub steamid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcRequestSessionIpResponse {
     #[prost(fixed32, optional, tag="1")]
     pub ip: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSoCacheHaveVersion {
     #[prost(message, optional, tag="1")]
@@ -264,6 +289,7 @@ pub struct CMsgSoCacheHaveVersion {
     #[prost(fixed64, optional, tag="2")]
     pub version: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgClientHello {
     #[prost(uint32, optional, tag="1")]
@@ -285,6 +311,7 @@ pub struct CMsgClientHello {
     #[prost(uint32, optional, tag="9")]
     pub steam_launcher: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgServerHello {
     #[prost(uint32, optional, tag="1")]
@@ -304,6 +331,7 @@ pub struct CMsgServerHello {
     #[prost(uint32, optional, tag="9")]
     pub socache_control: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgClientWelcome {
     #[prost(uint32, optional, tag="1")]
@@ -331,6 +359,7 @@ pub struct CMsgClientWelcome {
 }
 /// Nested message and enum types in `CMsgClientWelcome`.
 pub mod c_msg_client_welcome {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Location {
         #[prost(float, optional, tag="1")]
@@ -341,6 +370,7 @@ pub mod c_msg_client_welcome {
         pub country: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgConnectionStatus {
     #[prost(enumeration="GcConnectionStatus", optional, tag="1", default="HaveSession")]
@@ -356,6 +386,7 @@ pub struct CMsgConnectionStatus {
     #[prost(int32, optional, tag="6")]
     pub estimated_wait_seconds_remaining: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CWorkshopPopulateItemDescriptionsRequest {
     #[prost(uint32, optional, tag="1")]
@@ -365,6 +396,7 @@ pub struct CWorkshopPopulateItemDescriptionsRequest {
 }
 /// Nested message and enum types in `CWorkshop_PopulateItemDescriptions_Request`.
 pub mod c_workshop_populate_item_descriptions_request {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SingleItemDescription {
         #[prost(uint32, optional, tag="1")]
@@ -374,6 +406,7 @@ pub mod c_workshop_populate_item_descriptions_request {
         #[prost(bool, optional, tag="3")]
         pub one_per_account: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ItemDescriptionsLanguageBlock {
         #[prost(string, optional, tag="1")]
@@ -382,6 +415,7 @@ pub mod c_workshop_populate_item_descriptions_request {
         pub descriptions: ::prost::alloc::vec::Vec<SingleItemDescription>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CWorkshopGetContributorsRequest {
     #[prost(uint32, optional, tag="1")]
@@ -389,11 +423,13 @@ pub struct CWorkshopGetContributorsRequest {
     #[prost(uint32, optional, tag="2")]
     pub gameitemid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CWorkshopGetContributorsResponse {
     #[prost(fixed64, repeated, packed="false", tag="1")]
     pub contributors: ::prost::alloc::vec::Vec<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CWorkshopSetItemPaymentRulesRequest {
     #[prost(uint32, optional, tag="1")]
@@ -413,6 +449,7 @@ pub struct CWorkshopSetItemPaymentRulesRequest {
 }
 /// Nested message and enum types in `CWorkshop_SetItemPaymentRules_Request`.
 pub mod c_workshop_set_item_payment_rules_request {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct WorkshopItemPaymentRule {
         #[prost(uint64, optional, tag="1")]
@@ -424,6 +461,7 @@ pub mod c_workshop_set_item_payment_rules_request {
         #[prost(uint32, optional, tag="4", default="1")]
         pub rule_type: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct WorkshopDirectPaymentRule {
         #[prost(uint64, optional, tag="1")]
@@ -431,6 +469,7 @@ pub mod c_workshop_set_item_payment_rules_request {
         #[prost(string, optional, tag="2")]
         pub rule_description: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct PartnerItemPaymentRule {
         #[prost(uint32, optional, tag="1")]
@@ -441,9 +480,11 @@ pub mod c_workshop_set_item_payment_rules_request {
         pub rule_description: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CWorkshopSetItemPaymentRulesResponse {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CGameServersAggregationQueryRequest {
     #[prost(string, optional, tag="1")]
@@ -451,6 +492,7 @@ pub struct CGameServersAggregationQueryRequest {
     #[prost(string, repeated, tag="3")]
     pub group_fields: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CGameServersAggregationQueryResponse {
     #[prost(message, repeated, tag="1")]
@@ -458,6 +500,7 @@ pub struct CGameServersAggregationQueryResponse {
 }
 /// Nested message and enum types in `CGameServers_AggregationQuery_Response`.
 pub mod c_game_servers_aggregation_query_response {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Group {
         #[prost(string, repeated, tag="1")]
@@ -476,6 +519,7 @@ pub mod c_game_servers_aggregation_query_response {
         pub player_capacity: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CWorkshopAddSpecialPaymentRequest {
     #[prost(uint32, optional, tag="1")]
@@ -489,9 +533,11 @@ pub struct CWorkshopAddSpecialPaymentRequest {
     #[prost(uint64, optional, tag="5")]
     pub payment_row_usd: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CWorkshopAddSpecialPaymentResponse {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CProductInfoSetRichPresenceLocalizationRequest {
     #[prost(uint32, optional, tag="1")]
@@ -503,6 +549,7 @@ pub struct CProductInfoSetRichPresenceLocalizationRequest {
 }
 /// Nested message and enum types in `CProductInfo_SetRichPresenceLocalization_Request`.
 pub mod c_product_info_set_rich_presence_localization_request {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Token {
         #[prost(string, optional, tag="1")]
@@ -510,6 +557,7 @@ pub mod c_product_info_set_rich_presence_localization_request {
         #[prost(string, optional, tag="2")]
         pub value: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct LanguageSection {
         #[prost(string, optional, tag="1")]
@@ -518,9 +566,11 @@ pub mod c_product_info_set_rich_presence_localization_request {
         pub tokens: ::prost::alloc::vec::Vec<Token>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CProductInfoSetRichPresenceLocalizationResponse {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSerializedSoCache {
     #[prost(uint32, optional, tag="1")]
@@ -532,6 +582,7 @@ pub struct CMsgSerializedSoCache {
 }
 /// Nested message and enum types in `CMsgSerializedSOCache`.
 pub mod c_msg_serialized_so_cache {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct TypeCache {
         #[prost(uint32, optional, tag="1")]
@@ -541,6 +592,7 @@ pub mod c_msg_serialized_so_cache {
         #[prost(uint32, optional, tag="3")]
         pub service_id: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Cache {
         #[prost(uint32, optional, tag="1")]
@@ -554,6 +606,7 @@ pub mod c_msg_serialized_so_cache {
     }
     /// Nested message and enum types in `Cache`.
     pub mod cache {
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct Version {
             #[prost(uint32, optional, tag="1")]
@@ -632,6 +685,7 @@ impl GcConnectionStatus {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoFileHeader {
     #[prost(string, required, tag="1")]
@@ -665,6 +719,7 @@ pub struct CDemoFileHeader {
     #[prost(int32, optional, tag="15")]
     pub server_start_tick: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CGameInfo {
     #[prost(message, optional, tag="4")]
@@ -674,6 +729,7 @@ pub struct CGameInfo {
 }
 /// Nested message and enum types in `CGameInfo`.
 pub mod c_game_info {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CDotaGameInfo {
         #[prost(uint64, optional, tag="1")]
@@ -701,6 +757,7 @@ pub mod c_game_info {
     }
     /// Nested message and enum types in `CDotaGameInfo`.
     pub mod c_dota_game_info {
+        #[derive(::serde::Serialize)]
         #[derive(Clone, PartialEq, ::prost::Message)]
         pub struct CPlayerInfo {
             #[prost(string, optional, tag="1")]
@@ -714,6 +771,7 @@ pub mod c_game_info {
             #[prost(int32, optional, tag="5")]
             pub game_team: ::core::option::Option<i32>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct CHeroSelectEvent {
             #[prost(bool, optional, tag="1")]
@@ -724,12 +782,14 @@ pub mod c_game_info {
             pub hero_id: ::core::option::Option<i32>,
         }
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CcsGameInfo {
         #[prost(int32, repeated, packed="false", tag="1")]
         pub round_start_ticks: ::prost::alloc::vec::Vec<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoFileInfo {
     #[prost(float, optional, tag="1")]
@@ -741,11 +801,13 @@ pub struct CDemoFileInfo {
     #[prost(message, optional, tag="4")]
     pub game_info: ::core::option::Option<CGameInfo>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoPacket {
     #[prost(bytes="bytes", optional, tag="3")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoFullPacket {
     #[prost(message, optional, tag="1")]
@@ -753,6 +815,7 @@ pub struct CDemoFullPacket {
     #[prost(message, optional, tag="2")]
     pub packet: ::core::option::Option<CDemoPacket>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoSaveGame {
     #[prost(bytes="bytes", optional, tag="1")]
@@ -764,19 +827,23 @@ pub struct CDemoSaveGame {
     #[prost(int32, optional, tag="4")]
     pub version: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CDemoSyncTick {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoConsoleCmd {
     #[prost(string, optional, tag="1")]
     pub cmdstring: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoSendTables {
     #[prost(bytes="bytes", optional, tag="1")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoClassInfo {
     #[prost(message, repeated, tag="1")]
@@ -784,6 +851,7 @@ pub struct CDemoClassInfo {
 }
 /// Nested message and enum types in `CDemoClassInfo`.
 pub mod c_demo_class_info {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ClassT {
         #[prost(int32, optional, tag="1")]
@@ -794,6 +862,7 @@ pub mod c_demo_class_info {
         pub table_name: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoCustomData {
     #[prost(int32, optional, tag="1")]
@@ -801,11 +870,13 @@ pub struct CDemoCustomData {
     #[prost(bytes="bytes", optional, tag="2")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoCustomDataCallbacks {
     #[prost(string, repeated, tag="1")]
     pub save_id: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoAnimationHeader {
     #[prost(sint32, optional, tag="1")]
@@ -815,6 +886,7 @@ pub struct CDemoAnimationHeader {
     #[prost(bytes="bytes", optional, tag="3")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoAnimationData {
     #[prost(sint32, optional, tag="1")]
@@ -828,6 +900,7 @@ pub struct CDemoAnimationData {
     #[prost(int64, optional, tag="5")]
     pub data_checksum: ::core::option::Option<i64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoStringTables {
     #[prost(message, repeated, tag="1")]
@@ -835,6 +908,7 @@ pub struct CDemoStringTables {
 }
 /// Nested message and enum types in `CDemoStringTables`.
 pub mod c_demo_string_tables {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ItemsT {
         #[prost(string, optional, tag="1")]
@@ -842,6 +916,7 @@ pub mod c_demo_string_tables {
         #[prost(bytes="bytes", optional, tag="2")]
         pub data: ::core::option::Option<::prost::bytes::Bytes>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct TableT {
         #[prost(string, optional, tag="1")]
@@ -854,9 +929,11 @@ pub mod c_demo_string_tables {
         pub table_flags: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CDemoStop {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoUserCmd {
     #[prost(int32, optional, tag="1")]
@@ -864,11 +941,13 @@ pub struct CDemoUserCmd {
     #[prost(bytes="bytes", optional, tag="2")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoSpawnGroups {
     #[prost(bytes="bytes", repeated, tag="3")]
     pub msgs: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDemoRecovery {
     #[prost(message, optional, tag="1")]
@@ -878,6 +957,7 @@ pub struct CDemoRecovery {
 }
 /// Nested message and enum types in `CDemoRecovery`.
 pub mod c_demo_recovery {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DemoInitialSpawnGroupEntry {
         #[prost(uint32, optional, tag="1")]
@@ -973,6 +1053,7 @@ impl EDemoCommands {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEngineGotvSyncPacket {
     #[prost(uint64, optional, tag="1")]
@@ -996,6 +1077,7 @@ pub struct CEngineGotvSyncPacket {
     #[prost(uint32, optional, tag="11")]
     pub cdndelay: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GameServerPing {
     #[prost(int32, optional, tag="2")]
@@ -1005,6 +1087,7 @@ pub struct GameServerPing {
     #[prost(uint32, optional, tag="5")]
     pub instances: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DataCenterPing {
     #[prost(fixed32, optional, tag="1")]
@@ -1012,6 +1095,7 @@ pub struct DataCenterPing {
     #[prost(sint32, optional, tag="2")]
     pub ping: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DetailedSearchStatistic {
     #[prost(uint32, optional, tag="1")]
@@ -1021,6 +1105,7 @@ pub struct DetailedSearchStatistic {
     #[prost(uint32, optional, tag="4")]
     pub players_searching: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TournamentPlayer {
     #[prost(uint32, optional, tag="1")]
@@ -1038,6 +1123,7 @@ pub struct TournamentPlayer {
     #[prost(string, optional, tag="7")]
     pub player_desc: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TournamentTeam {
     #[prost(int32, optional, tag="1")]
@@ -1051,6 +1137,7 @@ pub struct TournamentTeam {
     #[prost(message, repeated, tag="5")]
     pub players: ::prost::alloc::vec::Vec<TournamentPlayer>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TournamentEvent {
     #[prost(int32, optional, tag="1")]
@@ -1072,6 +1159,7 @@ pub struct TournamentEvent {
     #[prost(uint32, optional, tag="9")]
     pub active_section_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GlobalStatistics {
     #[prost(uint32, optional, tag="1")]
@@ -1107,6 +1195,7 @@ pub struct GlobalStatistics {
     #[prost(uint32, optional, tag="16")]
     pub required_appid_version2: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OperationalStatisticDescription {
     #[prost(string, optional, tag="1")]
@@ -1114,6 +1203,7 @@ pub struct OperationalStatisticDescription {
     #[prost(uint32, optional, tag="2")]
     pub idkey: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OperationalStatisticElement {
     #[prost(uint32, optional, tag="1")]
@@ -1121,6 +1211,7 @@ pub struct OperationalStatisticElement {
     #[prost(int32, repeated, packed="false", tag="2")]
     pub values: ::prost::alloc::vec::Vec<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OperationalStatisticsPacket {
     #[prost(int32, optional, tag="1")]
@@ -1130,6 +1221,7 @@ pub struct OperationalStatisticsPacket {
     #[prost(message, repeated, tag="3")]
     pub values: ::prost::alloc::vec::Vec<OperationalStatisticElement>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OperationalVarValue {
     #[prost(string, optional, tag="1")]
@@ -1141,6 +1233,7 @@ pub struct OperationalVarValue {
     #[prost(bytes="bytes", optional, tag="4")]
     pub svalue: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlayerRankingInfo {
     #[prost(uint32, optional, tag="1")]
@@ -1176,6 +1269,7 @@ pub struct PlayerRankingInfo {
 }
 /// Nested message and enum types in `PlayerRankingInfo`.
 pub mod player_ranking_info {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct PerMapRank {
         #[prost(uint32, optional, tag="1")]
@@ -1186,6 +1280,7 @@ pub mod player_ranking_info {
         pub wins: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct PlayerCommendationInfo {
     #[prost(uint32, optional, tag="1")]
@@ -1195,6 +1290,7 @@ pub struct PlayerCommendationInfo {
     #[prost(uint32, optional, tag="4")]
     pub cmd_leader: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlayerMedalsInfo {
     #[prost(uint32, repeated, packed="false", tag="7")]
@@ -1202,6 +1298,7 @@ pub struct PlayerMedalsInfo {
     #[prost(uint32, optional, tag="8")]
     pub featured_display_item_defidx: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct AccountActivity {
     #[prost(uint32, optional, tag="1")]
@@ -1213,6 +1310,7 @@ pub struct AccountActivity {
     #[prost(uint64, optional, tag="4")]
     pub matchid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct TournamentMatchSetup {
     #[prost(int32, optional, tag="1")]
@@ -1224,6 +1322,7 @@ pub struct TournamentMatchSetup {
     #[prost(int32, optional, tag="4")]
     pub event_stage_id: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ServerHltvInfo {
     #[prost(uint32, optional, tag="1")]
@@ -1267,6 +1366,7 @@ pub struct ServerHltvInfo {
     #[prost(uint32, optional, tag="21")]
     pub flags: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct IpAddressMask {
     #[prost(uint32, optional, tag="1")]
@@ -1282,6 +1382,7 @@ pub struct IpAddressMask {
     #[prost(uint32, optional, tag="6")]
     pub token: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgCsgoSteamUserStatChange {
     #[prost(int32, optional, tag="1")]
@@ -1291,6 +1392,7 @@ pub struct CMsgCsgoSteamUserStatChange {
     #[prost(bool, optional, tag="3")]
     pub absolute: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct XpProgressData {
     #[prost(uint32, optional, tag="1")]
@@ -1298,6 +1400,7 @@ pub struct XpProgressData {
     #[prost(int32, optional, tag="2")]
     pub xp_category: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MatchEndItemUpdates {
     #[prost(uint64, optional, tag="1")]
@@ -1307,6 +1410,7 @@ pub struct MatchEndItemUpdates {
     #[prost(uint32, optional, tag="3")]
     pub item_attr_delta_value: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ScoreLeaderboardData {
     #[prost(uint64, optional, tag="1")]
@@ -1322,6 +1426,7 @@ pub struct ScoreLeaderboardData {
 }
 /// Nested message and enum types in `ScoreLeaderboardData`.
 pub mod score_leaderboard_data {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Entry {
         #[prost(uint32, optional, tag="1")]
@@ -1329,6 +1434,7 @@ pub mod score_leaderboard_data {
         #[prost(uint32, optional, tag="2")]
         pub val: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct AccountEntries {
         #[prost(uint32, optional, tag="1")]
@@ -1337,6 +1443,7 @@ pub mod score_leaderboard_data {
         pub entries: ::prost::alloc::vec::Vec<Entry>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlayerQuestData {
     #[prost(uint32, optional, tag="1")]
@@ -1358,6 +1465,7 @@ pub struct PlayerQuestData {
 }
 /// Nested message and enum types in `PlayerQuestData`.
 pub mod player_quest_data {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct QuestItemData {
         #[prost(uint64, optional, tag="1")]
@@ -1368,6 +1476,7 @@ pub mod player_quest_data {
         pub quest_bonus_points_earned: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeepPlayerStatsEntry {
     #[prost(uint32, optional, tag="1")]
@@ -1427,6 +1536,7 @@ pub struct DeepPlayerStatsEntry {
     #[prost(uint32, repeated, packed="false", tag="34")]
     pub mates: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DeepPlayerMatchEvent {
     #[prost(uint32, optional, tag="1")]
@@ -1458,6 +1568,7 @@ pub struct DeepPlayerMatchEvent {
     #[prost(int32, optional, tag="14")]
     pub event_data: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGcServerQuestUpdateData {
     #[prost(message, repeated, tag="1")]
@@ -1471,6 +1582,7 @@ pub struct CMsgGcServerQuestUpdateData {
     #[prost(uint32, optional, tag="5")]
     pub flags: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ServerConfirm {
     #[prost(uint32, optional, tag="1")]
@@ -1482,6 +1594,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ServerConfirm {
     #[prost(uint32, optional, tag="4")]
     pub retry: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ServerReservationUpdate {
     #[prost(uint32, optional, tag="1")]
@@ -1489,6 +1602,7 @@ pub struct CMsgGccStrike15V2Gc2ServerReservationUpdate {
     #[prost(uint32, optional, tag="2")]
     pub viewers_external_steam: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingStart {
     #[prost(uint32, repeated, packed="false", tag="1")]
@@ -1508,11 +1622,13 @@ pub struct CMsgGccStrike15V2MatchmakingStart {
     #[prost(uint64, optional, tag="8")]
     pub lobby_id: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingStop {
     #[prost(int32, optional, tag="1")]
     pub abandon: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientUpdateNote {
     #[prost(int32, optional, tag="1")]
@@ -1524,6 +1640,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientUpdateNote {
     #[prost(float, optional, tag="4")]
     pub distance: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingClient2ServerPing {
     #[prost(message, repeated, tag="1")]
@@ -1545,6 +1662,7 @@ pub struct CMsgGccStrike15V2MatchmakingClient2ServerPing {
     #[prost(string, optional, tag="9")]
     pub debug_message: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientUpdate {
     #[prost(int32, optional, tag="1")]
@@ -1580,6 +1698,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientUpdate {
     #[prost(uint32, repeated, packed="false", tag="18")]
     pub insecure_account_id_sessions: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2TournamentMatchDraft {
     #[prost(int32, optional, tag="1")]
@@ -1625,6 +1744,7 @@ pub struct CDataGccStrike15V2TournamentMatchDraft {
 }
 /// Nested message and enum types in `CDataGCCStrike15_v2_TournamentMatchDraft`.
 pub mod c_data_gcc_strike15_v2_tournament_match_draft {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Entry {
         #[prost(int32, optional, tag="1")]
@@ -1633,6 +1753,7 @@ pub mod c_data_gcc_strike15_v2_tournament_match_draft {
         pub team_id_ct: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CPreMatchInfoData {
     #[prost(int32, optional, tag="1")]
@@ -1646,6 +1767,7 @@ pub struct CPreMatchInfoData {
 }
 /// Nested message and enum types in `CPreMatchInfoData`.
 pub mod c_pre_match_info_data {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct TeamStats {
         #[prost(int32, optional, tag="1")]
@@ -1656,6 +1778,7 @@ pub mod c_pre_match_info_data {
         pub match_info_teams: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ServerReserve {
     #[prost(uint32, repeated, packed="false", tag="1")]
@@ -1701,6 +1824,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ServerReserve {
     #[prost(int32, repeated, packed="false", tag="21")]
     pub teammate_colors: ::prost::alloc::vec::Vec<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingServerReservationResponse {
     #[prost(uint64, optional, tag="1")]
@@ -1742,6 +1866,7 @@ pub struct CMsgGccStrike15V2MatchmakingServerReservationResponse {
     #[prost(uint32, optional, tag="19")]
     pub cpus_online: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientReserve {
     #[prost(uint64, optional, tag="1")]
@@ -1763,6 +1888,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientReserve {
     #[prost(uint32, optional, tag="9")]
     pub gs_location_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingServerRoundStats {
     #[prost(uint64, optional, tag="1")]
@@ -1832,15 +1958,18 @@ pub struct CMsgGccStrike15V2MatchmakingServerRoundStats {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_MatchmakingServerRoundStats`.
 pub mod c_msg_gcc_strike15_v2_matchmaking_server_round_stats {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DropInfo {
         #[prost(uint32, optional, tag="1")]
         pub account_mvp: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingClient2GcHello {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientHello {
     #[prost(uint32, optional, tag="1")]
@@ -1884,6 +2013,7 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientHello {
     #[prost(uint64, optional, tag="21")]
     pub owcaseid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2AccountPrivacySettings {
     #[prost(message, repeated, tag="1")]
@@ -1891,6 +2021,7 @@ pub struct CMsgGccStrike15V2AccountPrivacySettings {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_AccountPrivacySettings`.
 pub mod c_msg_gcc_strike15_v2_account_privacy_settings {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Setting {
         #[prost(uint32, optional, tag="1")]
@@ -1899,6 +2030,7 @@ pub mod c_msg_gcc_strike15_v2_account_privacy_settings {
         pub setting_value: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientAbandon {
     #[prost(uint32, optional, tag="1")]
@@ -1910,16 +2042,19 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientAbandon {
     #[prost(uint32, optional, tag="4")]
     pub penalty_reason: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientGcRankUpdate {
     #[prost(message, repeated, tag="1")]
     pub rankings: ::prost::alloc::vec::Vec<PlayerRankingInfo>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingOperator2GcBlogUpdate {
     #[prost(string, optional, tag="1")]
     pub main_post_url: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ServerNotificationForUserPenalty {
     #[prost(uint32, optional, tag="1")]
@@ -1931,6 +2066,7 @@ pub struct CMsgGccStrike15V2ServerNotificationForUserPenalty {
     #[prost(bool, optional, tag="4")]
     pub communication_cooldown: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientReportPlayer {
     #[prost(uint32, optional, tag="1")]
@@ -1952,6 +2088,7 @@ pub struct CMsgGccStrike15V2ClientReportPlayer {
     #[prost(bool, optional, tag="9")]
     pub report_from_demo: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientCommendPlayer {
     #[prost(uint32, optional, tag="1")]
@@ -1963,6 +2100,7 @@ pub struct CMsgGccStrike15V2ClientCommendPlayer {
     #[prost(uint32, optional, tag="10")]
     pub tokens: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientReportServer {
     #[prost(uint32, optional, tag="1")]
@@ -1978,6 +2116,7 @@ pub struct CMsgGccStrike15V2ClientReportServer {
     #[prost(uint64, optional, tag="8")]
     pub match_id: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientReportResponse {
     #[prost(uint64, optional, tag="1")]
@@ -1993,6 +2132,7 @@ pub struct CMsgGccStrike15V2ClientReportResponse {
     #[prost(uint32, optional, tag="6")]
     pub tokens: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestWatchInfoFriends {
     #[prost(uint32, optional, tag="1")]
@@ -2008,6 +2148,7 @@ pub struct CMsgGccStrike15V2ClientRequestWatchInfoFriends {
     #[prost(message, repeated, tag="6")]
     pub data_center_pings: ::prost::alloc::vec::Vec<DataCenterPing>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WatchableMatchInfo {
     #[prost(uint32, optional, tag="1")]
@@ -2037,6 +2178,7 @@ pub struct WatchableMatchInfo {
     #[prost(uint64, optional, tag="13")]
     pub reservation_id: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestJoinFriendData {
     #[prost(uint32, optional, tag="1")]
@@ -2052,6 +2194,7 @@ pub struct CMsgGccStrike15V2ClientRequestJoinFriendData {
     #[prost(string, optional, tag="6")]
     pub errormsg: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestJoinServerData {
     #[prost(uint32, optional, tag="1")]
@@ -2069,6 +2212,7 @@ pub struct CMsgGccStrike15V2ClientRequestJoinServerData {
     #[prost(string, optional, tag="7")]
     pub errormsg: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcCstrike15V2ClientRequestNewMission {
     #[prost(uint32, optional, tag="2")]
@@ -2076,6 +2220,7 @@ pub struct CMsgGcCstrike15V2ClientRequestNewMission {
     #[prost(uint32, optional, tag="3")]
     pub campaign_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcCstrike15V2ClientRedeemMissionReward {
     #[prost(uint32, optional, tag="1")]
@@ -2087,6 +2232,7 @@ pub struct CMsgGcCstrike15V2ClientRedeemMissionReward {
     #[prost(uint32, optional, tag="4")]
     pub expected_cost: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGcCstrike15V2ClientRedeemFreeReward {
     #[prost(uint32, optional, tag="1")]
@@ -2096,6 +2242,7 @@ pub struct CMsgGcCstrike15V2ClientRedeemFreeReward {
     #[prost(uint64, repeated, packed="false", tag="3")]
     pub items: ::prost::alloc::vec::Vec<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGcCstrike15V2Gc2ServerNotifyXpRewarded {
     #[prost(message, repeated, tag="1")]
@@ -2119,11 +2266,13 @@ pub struct CMsgGcCstrike15V2Gc2ServerNotifyXpRewarded {
     #[prost(uint32, optional, tag="10")]
     pub xp_trail_level: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientNetworkConfig {
     #[prost(bytes="bytes", optional, tag="1")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15ClientDeepStats {
     #[prost(uint32, optional, tag="1")]
@@ -2135,6 +2284,7 @@ pub struct CMsgGccStrike15ClientDeepStats {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_ClientDeepStats`.
 pub mod c_msg_gcc_strike15_client_deep_stats {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DeepStatsRange {
         #[prost(uint32, optional, tag="1")]
@@ -2144,6 +2294,7 @@ pub mod c_msg_gcc_strike15_client_deep_stats {
         #[prost(bool, optional, tag="3")]
         pub frozen: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DeepStatsMatch {
         #[prost(message, optional, tag="1")]
@@ -2152,6 +2303,7 @@ pub mod c_msg_gcc_strike15_client_deep_stats {
         pub events: ::prost::alloc::vec::Vec<super::DeepPlayerMatchEvent>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2WatchInfoUsers {
     #[prost(uint32, optional, tag="1")]
@@ -2163,6 +2315,7 @@ pub struct CMsgGccStrike15V2WatchInfoUsers {
     #[prost(uint32, optional, tag="5")]
     pub extended_timeout: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestPlayersProfile {
     #[prost(uint32, optional, tag="1")]
@@ -2174,6 +2327,7 @@ pub struct CMsgGccStrike15V2ClientRequestPlayersProfile {
     #[prost(uint32, optional, tag="4")]
     pub request_level: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PlayersProfile {
     #[prost(uint32, optional, tag="1")]
@@ -2181,6 +2335,7 @@ pub struct CMsgGccStrike15V2PlayersProfile {
     #[prost(message, repeated, tag="2")]
     pub account_profiles: ::prost::alloc::vec::Vec<CMsgGccStrike15V2MatchmakingGc2ClientHello>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PremierSeasonSummary {
     #[prost(uint32, optional, tag="1")]
@@ -2194,6 +2349,7 @@ pub struct CMsgGccStrike15V2PremierSeasonSummary {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_PremierSeasonSummary`.
 pub mod c_msg_gcc_strike15_v2_premier_season_summary {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DataPerWeek {
         #[prost(uint64, optional, tag="1")]
@@ -2203,6 +2359,7 @@ pub mod c_msg_gcc_strike15_v2_premier_season_summary {
         #[prost(uint32, optional, tag="3")]
         pub matches_played: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DataPerMap {
         #[prost(uint32, optional, tag="1")]
@@ -2233,6 +2390,7 @@ pub mod c_msg_gcc_strike15_v2_premier_season_summary {
         pub rounds_5k: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PlayerOverwatchCaseUpdate {
     #[prost(uint64, optional, tag="1")]
@@ -2252,6 +2410,7 @@ pub struct CMsgGccStrike15V2PlayerOverwatchCaseUpdate {
     #[prost(uint32, optional, tag="9")]
     pub reason: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PlayerOverwatchCaseAssignment {
     #[prost(uint64, optional, tag="1")]
@@ -2277,6 +2436,7 @@ pub struct CMsgGccStrike15V2PlayerOverwatchCaseAssignment {
     #[prost(uint32, optional, tag="11")]
     pub reason: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PlayerOverwatchCaseStatus {
     #[prost(uint64, optional, tag="1")]
@@ -2284,6 +2444,7 @@ pub struct CMsgGccStrike15V2PlayerOverwatchCaseStatus {
     #[prost(uint32, optional, tag="2")]
     pub statusid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CClientHeaderOverwatchEvidence {
     #[prost(uint32, optional, tag="1")]
@@ -2291,6 +2452,7 @@ pub struct CClientHeaderOverwatchEvidence {
     #[prost(uint64, optional, tag="2")]
     pub caseid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientTextMsg {
     #[prost(uint32, optional, tag="1")]
@@ -2300,6 +2462,7 @@ pub struct CMsgGccStrike15V2Gc2ClientTextMsg {
     #[prost(bytes="bytes", optional, tag="3")]
     pub payload: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcTextMsg {
     #[prost(uint32, optional, tag="1")]
@@ -2307,6 +2470,7 @@ pub struct CMsgGccStrike15V2Client2GcTextMsg {
     #[prost(bytes="bytes", repeated, tag="2")]
     pub args: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchEndRunRewardDrops {
     #[prost(message, optional, tag="3")]
@@ -2314,6 +2478,7 @@ pub struct CMsgGccStrike15V2MatchEndRunRewardDrops {
     #[prost(message, optional, tag="4")]
     pub match_end_quest_data: ::core::option::Option<CMsgGcServerQuestUpdateData>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CEconItemPreviewDataBlock {
     #[prost(uint32, optional, tag="1")]
@@ -2359,6 +2524,7 @@ pub struct CEconItemPreviewDataBlock {
 }
 /// Nested message and enum types in `CEconItemPreviewDataBlock`.
 pub mod c_econ_item_preview_data_block {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Sticker {
         #[prost(uint32, optional, tag="1")]
@@ -2383,16 +2549,19 @@ pub mod c_econ_item_preview_data_block {
         pub pattern: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchEndRewardDropsNotification {
     #[prost(message, optional, tag="6")]
     pub iteminfo: ::core::option::Option<CEconItemPreviewDataBlock>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgItemAcknowledged {
     #[prost(message, optional, tag="1")]
     pub iteminfo: ::core::option::Option<CEconItemPreviewDataBlock>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcEconPreviewDataBlockRequest {
     #[prost(uint64, optional, tag="1")]
@@ -2404,29 +2573,35 @@ pub struct CMsgGccStrike15V2Client2GcEconPreviewDataBlockRequest {
     #[prost(uint64, optional, tag="4")]
     pub param_m: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcEconPreviewDataBlockResponse {
     #[prost(message, optional, tag="1")]
     pub iteminfo: ::core::option::Option<CEconItemPreviewDataBlock>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListRequestCurrentLiveGames {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListRequestLiveGameForUser {
     #[prost(uint32, optional, tag="1")]
     pub accountid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListRequestRecentUserGames {
     #[prost(uint32, optional, tag="1")]
     pub accountid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListRequestTournamentGames {
     #[prost(int32, optional, tag="1")]
     pub eventid: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListRequestFullGameInfo {
     #[prost(uint64, optional, tag="1")]
@@ -2436,6 +2611,7 @@ pub struct CMsgGccStrike15V2MatchListRequestFullGameInfo {
     #[prost(uint32, optional, tag="3")]
     pub token: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2MatchInfo {
     #[prost(uint64, optional, tag="1")]
@@ -2449,6 +2625,7 @@ pub struct CDataGccStrike15V2MatchInfo {
     #[prost(message, repeated, tag="5")]
     pub roundstatsall: ::prost::alloc::vec::Vec<CMsgGccStrike15V2MatchmakingServerRoundStats>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2TournamentGroupTeam {
     #[prost(int32, optional, tag="1")]
@@ -2458,6 +2635,7 @@ pub struct CDataGccStrike15V2TournamentGroupTeam {
     #[prost(bool, optional, tag="3")]
     pub correctpick: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2TournamentGroup {
     #[prost(uint32, optional, tag="1")]
@@ -2483,12 +2661,14 @@ pub struct CDataGccStrike15V2TournamentGroup {
 }
 /// Nested message and enum types in `CDataGCCStrike15_v2_TournamentGroup`.
 pub mod c_data_gcc_strike15_v2_tournament_group {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Picks {
         #[prost(int32, repeated, packed="false", tag="1")]
         pub pickids: ::prost::alloc::vec::Vec<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2TournamentSection {
     #[prost(uint32, optional, tag="1")]
@@ -2500,6 +2680,7 @@ pub struct CDataGccStrike15V2TournamentSection {
     #[prost(message, repeated, tag="4")]
     pub groups: ::prost::alloc::vec::Vec<CDataGccStrike15V2TournamentGroup>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CDataGccStrike15V2TournamentInfo {
     #[prost(message, repeated, tag="1")]
@@ -2509,6 +2690,7 @@ pub struct CDataGccStrike15V2TournamentInfo {
     #[prost(message, repeated, tag="3")]
     pub tournament_teams: ::prost::alloc::vec::Vec<TournamentTeam>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchList {
     #[prost(uint32, optional, tag="1")]
@@ -2524,6 +2706,7 @@ pub struct CMsgGccStrike15V2MatchList {
     #[prost(message, optional, tag="6")]
     pub tournamentinfo: ::core::option::Option<CDataGccStrike15V2TournamentInfo>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchListTournamentOperatorMgmt {
     #[prost(int32, optional, tag="1")]
@@ -2533,6 +2716,7 @@ pub struct CMsgGccStrike15V2MatchListTournamentOperatorMgmt {
     #[prost(uint32, optional, tag="3")]
     pub accountid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Predictions {
     #[prost(uint32, optional, tag="1")]
@@ -2542,6 +2726,7 @@ pub struct CMsgGccStrike15V2Predictions {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_Predictions`.
 pub mod c_msg_gcc_strike15_v2_predictions {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct GroupMatchTeamPick {
         #[prost(int32, optional, tag="1")]
@@ -2556,6 +2741,7 @@ pub mod c_msg_gcc_strike15_v2_predictions {
         pub itemid: ::core::option::Option<u64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Fantasy {
     #[prost(uint32, optional, tag="1")]
@@ -2565,6 +2751,7 @@ pub struct CMsgGccStrike15V2Fantasy {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_Fantasy`.
 pub mod c_msg_gcc_strike15_v2_fantasy {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct FantasySlot {
         #[prost(int32, optional, tag="1")]
@@ -2574,6 +2761,7 @@ pub mod c_msg_gcc_strike15_v2_fantasy {
         #[prost(uint64, optional, tag="3")]
         pub itemid: ::core::option::Option<u64>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct FantasyTeam {
         #[prost(int32, optional, tag="1")]
@@ -2582,11 +2770,13 @@ pub mod c_msg_gcc_strike15_v2_fantasy {
         pub slots: ::prost::alloc::vec::Vec<FantasySlot>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CAttributeString {
     #[prost(string, optional, tag="1")]
     pub value: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgLegacySource1ClientWelcome {
     #[prost(uint32, optional, tag="1")]
@@ -2614,6 +2804,7 @@ pub struct CMsgLegacySource1ClientWelcome {
 }
 /// Nested message and enum types in `CMsgLegacySource1ClientWelcome`.
 pub mod c_msg_legacy_source1_client_welcome {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Location {
         #[prost(float, optional, tag="1")]
@@ -2624,6 +2815,7 @@ pub mod c_msg_legacy_source1_client_welcome {
         pub country: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgCStrike15Welcome {
     #[prost(uint32, optional, tag="5")]
@@ -2641,6 +2833,7 @@ pub struct CMsgCStrike15Welcome {
     #[prost(uint64, optional, tag="19")]
     pub uniqueid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientVarValueNotificationInfo {
     #[prost(string, optional, tag="1")]
@@ -2654,6 +2847,7 @@ pub struct CMsgGccStrike15V2ClientVarValueNotificationInfo {
     #[prost(string, repeated, tag="5")]
     pub choked_blocks: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ServerVarValueNotificationInfo {
     #[prost(uint32, optional, tag="1")]
@@ -2665,9 +2859,11 @@ pub struct CMsgGccStrike15V2ServerVarValueNotificationInfo {
     #[prost(uint32, repeated, packed="false", tag="4")]
     pub userdata: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2GiftsLeaderboardRequest {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2GiftsLeaderboardResponse {
     #[prost(uint32, optional, tag="1")]
@@ -2683,6 +2879,7 @@ pub struct CMsgGccStrike15V2GiftsLeaderboardResponse {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_GiftsLeaderboardResponse`.
 pub mod c_msg_gcc_strike15_v2_gifts_leaderboard_response {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct GiftLeaderboardEntry {
         #[prost(uint32, optional, tag="1")]
@@ -2691,6 +2888,7 @@ pub mod c_msg_gcc_strike15_v2_gifts_leaderboard_response {
         pub gifts: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientSubmitSurveyVote {
     #[prost(uint32, optional, tag="1")]
@@ -2698,11 +2896,13 @@ pub struct CMsgGccStrike15V2ClientSubmitSurveyVote {
     #[prost(uint32, optional, tag="2")]
     pub vote: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Server2GcClientValidate {
     #[prost(uint32, optional, tag="1")]
     pub accountid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientTournamentInfo {
     #[prost(uint32, optional, tag="1")]
@@ -2714,6 +2914,7 @@ pub struct CMsgGccStrike15V2Gc2ClientTournamentInfo {
     #[prost(uint32, repeated, packed="false", tag="4")]
     pub teamids: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoEconCoupon {
     #[prost(uint32, optional, tag="1")]
@@ -2723,6 +2924,7 @@ pub struct CsoEconCoupon {
     #[prost(fixed32, optional, tag="3")]
     pub expiration_date: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsoAccountItemPersonalStore {
     #[prost(uint32, optional, tag="1")]
@@ -2732,6 +2934,7 @@ pub struct CsoAccountItemPersonalStore {
     #[prost(uint64, repeated, packed="false", tag="3")]
     pub items: ::prost::alloc::vec::Vec<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsoAccountXpShop {
     #[prost(uint32, optional, tag="1")]
@@ -2741,11 +2944,13 @@ pub struct CsoAccountXpShop {
     #[prost(uint32, repeated, packed="false", tag="3")]
     pub xp_tracks: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoAccountKeychainRemoveToolCharges {
     #[prost(uint32, optional, tag="1")]
     pub charges: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoQuestProgress {
     #[prost(uint32, optional, tag="1")]
@@ -2755,6 +2960,7 @@ pub struct CsoQuestProgress {
     #[prost(uint32, optional, tag="3")]
     pub bonus_points: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoAccountSeasonalOperation {
     #[prost(uint32, optional, tag="1")]
@@ -2772,6 +2978,7 @@ pub struct CsoAccountSeasonalOperation {
     #[prost(uint32, optional, tag="7")]
     pub season_pass_time: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoAccountRecurringSubscription {
     #[prost(uint32, optional, tag="1")]
@@ -2779,6 +2986,7 @@ pub struct CsoAccountRecurringSubscription {
     #[prost(uint32, optional, tag="2")]
     pub time_initiated: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsoPersonaDataPublic {
     #[prost(int32, optional, tag="1")]
@@ -2792,6 +3000,7 @@ pub struct CsoPersonaDataPublic {
     #[prost(uint32, optional, tag="5")]
     pub xp_trail_level: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientNotifyXpShop {
     #[prost(message, optional, tag="1")]
@@ -2803,9 +3012,11 @@ pub struct CMsgGccStrike15V2Gc2ClientNotifyXpShop {
     #[prost(uint32, optional, tag="4")]
     pub current_level: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcAckXpShopTracks {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2MatchmakingGc2ClientSearchStats {
     #[prost(uint32, optional, tag="1")]
@@ -2819,16 +3030,19 @@ pub struct CMsgGccStrike15V2MatchmakingGc2ClientSearchStats {
     #[prost(uint32, optional, tag="5")]
     pub note_level: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcGlobalGameSubscribe {
     #[prost(uint64, optional, tag="1")]
     pub ticket: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcGlobalGameUnsubscribe {
     #[prost(int32, optional, tag="1")]
     pub timeleft: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGcGlobalGamePlay {
     #[prost(uint64, optional, tag="1")]
@@ -2838,11 +3052,13 @@ pub struct CMsgGcGlobalGamePlay {
     #[prost(uint32, optional, tag="3")]
     pub msperpoint: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2AcknowledgePenalty {
     #[prost(int32, optional, tag="1")]
     pub acknowledged: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcRequestPrestigeCoin {
     #[prost(uint32, optional, tag="1")]
@@ -2854,6 +3070,7 @@ pub struct CMsgGccStrike15V2Client2GcRequestPrestigeCoin {
     #[prost(uint32, optional, tag="4")]
     pub prestigetime: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Client2GcStreamUnlock {
     #[prost(uint64, optional, tag="1")]
@@ -2861,11 +3078,13 @@ pub struct CMsgGccStrike15V2Client2GcStreamUnlock {
     #[prost(int32, optional, tag="2")]
     pub os: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientToGcRequestElevate {
     #[prost(uint32, optional, tag="1")]
     pub stage: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientToGcChat {
     #[prost(uint64, optional, tag="1")]
@@ -2873,6 +3092,7 @@ pub struct CMsgGccStrike15V2ClientToGcChat {
     #[prost(string, optional, tag="2")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2GcToClientChat {
     #[prost(uint32, optional, tag="1")]
@@ -2880,6 +3100,7 @@ pub struct CMsgGccStrike15V2GcToClientChat {
     #[prost(string, optional, tag="2")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientAuthKeyCode {
     #[prost(uint32, optional, tag="1")]
@@ -2887,11 +3108,13 @@ pub struct CMsgGccStrike15V2ClientAuthKeyCode {
     #[prost(string, optional, tag="2")]
     pub code: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15GotvSyncPacket {
     #[prost(message, optional, tag="1")]
     pub data: ::core::option::Option<CEngineGotvSyncPacket>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlayerDecalDigitalSignature {
     #[prost(bytes="bytes", optional, tag="1")]
@@ -2923,6 +3146,7 @@ pub struct PlayerDecalDigitalSignature {
     #[prost(uint32, optional, tag="14")]
     pub tint_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientPlayerDecalSign {
     #[prost(message, optional, tag="1")]
@@ -2930,11 +3154,13 @@ pub struct CMsgGccStrike15V2ClientPlayerDecalSign {
     #[prost(uint64, optional, tag="2")]
     pub itemid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2BetaEnrollment {
     #[prost(uint32, optional, tag="1")]
     pub eresult: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientLogonFatalError {
     #[prost(uint32, optional, tag="1")]
@@ -2944,6 +3170,7 @@ pub struct CMsgGccStrike15V2ClientLogonFatalError {
     #[prost(string, optional, tag="3")]
     pub country: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientPollState {
     #[prost(uint32, optional, tag="1")]
@@ -2953,6 +3180,7 @@ pub struct CMsgGccStrike15V2ClientPollState {
     #[prost(int32, repeated, packed="false", tag="3")]
     pub values: ::prost::alloc::vec::Vec<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PartyRegister {
     #[prost(uint32, optional, tag="1")]
@@ -2974,6 +3202,7 @@ pub struct CMsgGccStrike15V2PartyRegister {
     #[prost(uint32, optional, tag="9")]
     pub game_type: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PartySearch {
     #[prost(uint32, optional, tag="1")]
@@ -2989,6 +3218,7 @@ pub struct CMsgGccStrike15V2PartySearch {
     #[prost(uint32, optional, tag="6")]
     pub game_type: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PartySearchResults {
     #[prost(message, repeated, tag="1")]
@@ -2996,6 +3226,7 @@ pub struct CMsgGccStrike15V2PartySearchResults {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_Party_SearchResults`.
 pub mod c_msg_gcc_strike15_v2_party_search_results {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Entry {
         #[prost(uint32, optional, tag="1")]
@@ -3014,6 +3245,7 @@ pub mod c_msg_gcc_strike15_v2_party_search_results {
         pub accountid: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2PartyInvite {
     #[prost(uint32, optional, tag="1")]
@@ -3021,6 +3253,7 @@ pub struct CMsgGccStrike15V2PartyInvite {
     #[prost(uint32, optional, tag="2")]
     pub lobbyid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2AccountRequestCoPlays {
     #[prost(message, repeated, tag="1")]
@@ -3030,6 +3263,7 @@ pub struct CMsgGccStrike15V2AccountRequestCoPlays {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_Account_RequestCoPlays`.
 pub mod c_msg_gcc_strike15_v2_account_request_co_plays {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Player {
         #[prost(uint32, optional, tag="1")]
@@ -3040,6 +3274,7 @@ pub mod c_msg_gcc_strike15_v2_account_request_co_plays {
         pub online: ::core::option::Option<bool>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientToGcRequestTicket {
     #[prost(fixed64, optional, tag="1")]
@@ -3051,14 +3286,17 @@ pub struct CMsgGccStrike15V2ClientToGcRequestTicket {
     #[prost(string, optional, tag="5")]
     pub gameserver_sdr_routing: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGcToClientSteamDatagramTicket {
     #[prost(bytes="bytes", optional, tag="16")]
     pub serialized_ticket: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestOffers {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientRequestSouvenir {
     #[prost(uint64, optional, tag="1")]
@@ -3068,6 +3306,7 @@ pub struct CMsgGccStrike15V2ClientRequestSouvenir {
     #[prost(int32, optional, tag="3")]
     pub eventid: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientAccountBalance {
     #[prost(uint64, optional, tag="1")]
@@ -3075,6 +3314,7 @@ pub struct CMsgGccStrike15V2ClientAccountBalance {
     #[prost(string, optional, tag="2")]
     pub url: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientPartyJoinRelay {
     #[prost(uint32, optional, tag="1")]
@@ -3082,6 +3322,7 @@ pub struct CMsgGccStrike15V2ClientPartyJoinRelay {
     #[prost(uint64, optional, tag="2")]
     pub lobbyid: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientPartyWarning {
     #[prost(message, repeated, tag="1")]
@@ -3089,6 +3330,7 @@ pub struct CMsgGccStrike15V2ClientPartyWarning {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_ClientPartyWarning`.
 pub mod c_msg_gcc_strike15_v2_client_party_warning {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Entry {
         #[prost(uint32, optional, tag="1")]
@@ -3097,6 +3339,7 @@ pub mod c_msg_gcc_strike15_v2_client_party_warning {
         pub warntype: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2SetEventFavorite {
     #[prost(uint64, optional, tag="1")]
@@ -3104,11 +3347,13 @@ pub struct CMsgGccStrike15V2SetEventFavorite {
     #[prost(bool, optional, tag="2")]
     pub is_favorite: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2GetEventFavoritesRequest {
     #[prost(bool, optional, tag="1")]
     pub all_events: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2GetEventFavoritesResponse {
     #[prost(bool, optional, tag="1")]
@@ -3118,6 +3363,7 @@ pub struct CMsgGccStrike15V2GetEventFavoritesResponse {
     #[prost(string, optional, tag="3")]
     pub json_featured: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientPerfReport {
     #[prost(message, repeated, tag="1")]
@@ -3125,6 +3371,7 @@ pub struct CMsgGccStrike15V2ClientPerfReport {
 }
 /// Nested message and enum types in `CMsgGCCStrike15_v2_ClientPerfReport`.
 pub mod c_msg_gcc_strike15_v2_client_perf_report {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Entry {
         #[prost(uint32, optional, tag="1")]
@@ -3141,6 +3388,7 @@ pub mod c_msg_gcc_strike15_v2_client_perf_report {
         pub status: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CvDiagnostic {
     #[prost(uint32, optional, tag="1")]
@@ -3152,6 +3400,7 @@ pub struct CvDiagnostic {
     #[prost(string, optional, tag="4")]
     pub string_value: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2ClientReportValidation {
     #[prost(string, optional, tag="1")]
@@ -3195,6 +3444,7 @@ pub struct CMsgGccStrike15V2ClientReportValidation {
     #[prost(message, repeated, tag="20")]
     pub diagnostics: ::prost::alloc::vec::Vec<CvDiagnostic>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientRefuseSecureMode {
     #[prost(string, optional, tag="1")]
@@ -3216,6 +3466,7 @@ pub struct CMsgGccStrike15V2Gc2ClientRefuseSecureMode {
     #[prost(string, optional, tag="9")]
     pub files_prevented_trusted: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientRequestValidation {
     #[prost(bool, optional, tag="1")]
@@ -3223,6 +3474,7 @@ pub struct CMsgGccStrike15V2Gc2ClientRequestValidation {
     #[prost(string, optional, tag="2")]
     pub module: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientInitSystem {
     #[prost(bool, optional, tag="1")]
@@ -3244,6 +3496,7 @@ pub struct CMsgGccStrike15V2Gc2ClientInitSystem {
     #[prost(bool, optional, tag="9")]
     pub load_system: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2Gc2ClientInitSystemResponse {
     #[prost(bool, optional, tag="1")]
@@ -3267,6 +3520,7 @@ pub struct CMsgGccStrike15V2Gc2ClientInitSystemResponse {
     #[prost(int32, optional, tag="10")]
     pub aux_system2: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgGccStrike15V2SetPlayerLeaderboardSafeName {
     #[prost(string, optional, tag="1")]
@@ -4103,6 +4357,7 @@ impl ENetworkDisconnectionReason {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgVector {
     #[prost(float, optional, tag="1")]
@@ -4114,6 +4369,7 @@ pub struct CMsgVector {
     #[prost(float, optional, tag="4")]
     pub w: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgVector2D {
     #[prost(float, optional, tag="1")]
@@ -4121,6 +4377,7 @@ pub struct CMsgVector2D {
     #[prost(float, optional, tag="2")]
     pub y: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgQAngle {
     #[prost(float, optional, tag="1")]
@@ -4130,6 +4387,7 @@ pub struct CMsgQAngle {
     #[prost(float, optional, tag="3")]
     pub z: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgQuaternion {
     #[prost(float, optional, tag="1")]
@@ -4141,6 +4399,7 @@ pub struct CMsgQuaternion {
     #[prost(float, optional, tag="4")]
     pub w: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgTransform {
     #[prost(message, optional, tag="1")]
@@ -4150,6 +4409,7 @@ pub struct CMsgTransform {
     #[prost(message, optional, tag="3")]
     pub orientation: ::core::option::Option<CMsgQuaternion>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgRgba {
     #[prost(int32, optional, tag="1")]
@@ -4161,6 +4421,7 @@ pub struct CMsgRgba {
     #[prost(int32, optional, tag="4")]
     pub a: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgPlayerInfo {
     #[prost(string, optional, tag="1")]
@@ -4176,11 +4437,13 @@ pub struct CMsgPlayerInfo {
     #[prost(bool, optional, tag="6")]
     pub ishltv: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMsg {
     #[prost(uint32, optional, tag="1", default="16777215")]
     pub target_entity: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgCVars {
     #[prost(message, repeated, tag="1")]
@@ -4188,6 +4451,7 @@ pub struct CMsgCVars {
 }
 /// Nested message and enum types in `CMsg_CVars`.
 pub mod c_msg_c_vars {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CVar {
         #[prost(string, optional, tag="1")]
@@ -4196,14 +4460,17 @@ pub mod c_msg_c_vars {
         pub value: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CnetMsgNop {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CnetMsgSplitScreenUser {
     #[prost(int32, optional, tag="1")]
     pub slot: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgTick {
     #[prost(uint32, optional, tag="1")]
@@ -4227,6 +4494,7 @@ pub struct CnetMsgTick {
     #[prost(uint32, optional, tag="13")]
     pub host_frame_irregular_arrival_pct_x10: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgStringCmd {
     #[prost(string, optional, tag="1")]
@@ -4234,11 +4502,13 @@ pub struct CnetMsgStringCmd {
     #[prost(uint32, optional, tag="2")]
     pub prediction_sync: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgSetConVar {
     #[prost(message, optional, tag="1")]
     pub convars: ::core::option::Option<CMsgCVars>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgSignonState {
     #[prost(enumeration="SignonStateT", optional, tag="1", default="SignonstateNone")]
@@ -4254,6 +4524,7 @@ pub struct CnetMsgSignonState {
     #[prost(string, optional, tag="6")]
     pub addons: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgGameEvent {
     #[prost(string, optional, tag="1")]
@@ -4265,6 +4536,7 @@ pub struct CsvcMsgGameEvent {
 }
 /// Nested message and enum types in `CSVCMsg_GameEvent`.
 pub mod csvc_msg_game_event {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct KeyT {
         #[prost(int32, optional, tag="1")]
@@ -4285,6 +4557,7 @@ pub mod csvc_msg_game_event {
         pub val_uint64: ::core::option::Option<u64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgListGameEvents {
     #[prost(message, repeated, tag="1")]
@@ -4292,6 +4565,7 @@ pub struct CsvcMsgListGameEvents {
 }
 /// Nested message and enum types in `CSVCMsgList_GameEvents`.
 pub mod csvc_msg_list_game_events {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct EventT {
         #[prost(int32, optional, tag="1")]
@@ -4300,6 +4574,7 @@ pub mod csvc_msg_list_game_events {
         pub event: ::core::option::Option<super::CsvcMsgGameEvent>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgSpawnGroupLoad {
     #[prost(string, optional, tag="1")]
@@ -4343,6 +4618,7 @@ pub struct CnetMsgSpawnGroupLoad {
     #[prost(string, optional, tag="20")]
     pub worldgroupname: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgSpawnGroupManifestUpdate {
     #[prost(uint32, optional, tag="1")]
@@ -4352,6 +4628,7 @@ pub struct CnetMsgSpawnGroupManifestUpdate {
     #[prost(bool, optional, tag="3")]
     pub manifestincomplete: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CnetMsgSpawnGroupSetCreationTick {
     #[prost(uint32, optional, tag="1")]
@@ -4361,6 +4638,7 @@ pub struct CnetMsgSpawnGroupSetCreationTick {
     #[prost(uint32, optional, tag="3")]
     pub creationsequence: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CnetMsgSpawnGroupUnload {
     #[prost(uint32, optional, tag="1")]
@@ -4370,11 +4648,13 @@ pub struct CnetMsgSpawnGroupUnload {
     #[prost(int32, optional, tag="3")]
     pub tickcount: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CnetMsgSpawnGroupLoadCompleted {
     #[prost(uint32, optional, tag="1")]
     pub spawngrouphandle: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgGameSessionConfiguration {
     #[prost(bool, optional, tag="1")]
@@ -4416,6 +4696,7 @@ pub struct CsvcMsgGameSessionConfiguration {
     #[prost(string, optional, tag="18")]
     pub landmarkname: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CnetMsgDebugOverlay {
     #[prost(int32, optional, tag="1")]
@@ -4585,6 +4866,7 @@ impl SpawnGroupFlagsT {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVguiMenu {
     #[prost(string, optional, tag="1")]
@@ -4596,6 +4878,7 @@ pub struct CcsUsrMsgVguiMenu {
 }
 /// Nested message and enum types in `CCSUsrMsg_VGUIMenu`.
 pub mod ccs_usr_msg_vgui_menu {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Keys {
         #[prost(string, optional, tag="1")]
@@ -4604,21 +4887,25 @@ pub mod ccs_usr_msg_vgui_menu {
         pub value: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgGeiger {
     #[prost(int32, optional, tag="1")]
     pub range: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgTrain {
     #[prost(int32, optional, tag="1")]
     pub train: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgHudText {
     #[prost(string, optional, tag="1")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgHudMsg {
     #[prost(int32, optional, tag="1")]
@@ -4642,6 +4929,7 @@ pub struct CcsUsrMsgHudMsg {
     #[prost(string, optional, tag="11")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgShake {
     #[prost(int32, optional, tag="1")]
@@ -4653,6 +4941,7 @@ pub struct CcsUsrMsgShake {
     #[prost(float, optional, tag="4")]
     pub duration: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgFade {
     #[prost(int32, optional, tag="1")]
@@ -4664,6 +4953,7 @@ pub struct CcsUsrMsgFade {
     #[prost(message, optional, tag="4")]
     pub clr: ::core::option::Option<CMsgRgba>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRumble {
     #[prost(int32, optional, tag="1")]
@@ -4673,6 +4963,7 @@ pub struct CcsUsrMsgRumble {
     #[prost(int32, optional, tag="3")]
     pub flags: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCloseCaption {
     #[prost(uint32, optional, tag="1")]
@@ -4684,6 +4975,7 @@ pub struct CcsUsrMsgCloseCaption {
     #[prost(string, optional, tag="4")]
     pub cctoken: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCloseCaptionDirect {
     #[prost(uint32, optional, tag="1")]
@@ -4693,11 +4985,13 @@ pub struct CcsUsrMsgCloseCaptionDirect {
     #[prost(bool, optional, tag="3")]
     pub from_player: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSendAudio {
     #[prost(string, optional, tag="1")]
     pub radio_sound: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRawAudio {
     #[prost(int32, optional, tag="1")]
@@ -4709,6 +5003,7 @@ pub struct CcsUsrMsgRawAudio {
     #[prost(string, optional, tag="4")]
     pub voice_filename: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVoiceMask {
     #[prost(message, repeated, tag="1")]
@@ -4718,6 +5013,7 @@ pub struct CcsUsrMsgVoiceMask {
 }
 /// Nested message and enum types in `CCSUsrMsg_VoiceMask`.
 pub mod ccs_usr_msg_voice_mask {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct PlayerMask {
         #[prost(int32, optional, tag="1")]
@@ -4726,6 +5022,7 @@ pub mod ccs_usr_msg_voice_mask {
         pub ban_masks: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgDamage {
     #[prost(int32, optional, tag="1")]
@@ -4735,6 +5032,7 @@ pub struct CcsUsrMsgDamage {
     #[prost(int32, optional, tag="3", default="-1")]
     pub victim_entindex: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRadioText {
     #[prost(int32, optional, tag="1")]
@@ -4746,16 +5044,19 @@ pub struct CcsUsrMsgRadioText {
     #[prost(string, repeated, tag="4")]
     pub params: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgHintText {
     #[prost(string, optional, tag="1")]
     pub message: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgKeyHintText {
     #[prost(string, repeated, tag="1")]
     pub messages: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgProcessSpottedEntityUpdate {
     #[prost(bool, optional, tag="1")]
@@ -4765,6 +5066,7 @@ pub struct CcsUsrMsgProcessSpottedEntityUpdate {
 }
 /// Nested message and enum types in `CCSUsrMsg_ProcessSpottedEntityUpdate`.
 pub mod ccs_usr_msg_process_spotted_entity_update {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SpottedEntityUpdate {
         #[prost(int32, optional, tag="1", default="-1")]
@@ -4787,11 +5089,13 @@ pub mod ccs_usr_msg_process_spotted_entity_update {
         pub player_has_c4: ::core::option::Option<bool>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSendPlayerItemDrops {
     #[prost(message, repeated, tag="1")]
     pub entity_updates: ::prost::alloc::vec::Vec<CEconItemPreviewDataBlock>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSendPlayerItemFound {
     #[prost(message, optional, tag="1")]
@@ -4799,6 +5103,7 @@ pub struct CcsUsrMsgSendPlayerItemFound {
     #[prost(int32, optional, tag="2", default="-1")]
     pub playerslot: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgReloadEffect {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -4812,6 +5117,7 @@ pub struct CcsUsrMsgReloadEffect {
     #[prost(float, optional, tag="5")]
     pub origin_z: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgWeaponSound {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -4829,6 +5135,7 @@ pub struct CcsUsrMsgWeaponSound {
     #[prost(fixed32, optional, tag="7")]
     pub source_soundscapeid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgUpdateScreenHealthBar {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -4840,6 +5147,7 @@ pub struct CcsUsrMsgUpdateScreenHealthBar {
     #[prost(int32, optional, tag="4")]
     pub style: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgEntityOutlineHighlight {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -4847,11 +5155,13 @@ pub struct CcsUsrMsgEntityOutlineHighlight {
     #[prost(bool, optional, tag="2")]
     pub removehighlight: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgAdjustMoney {
     #[prost(int32, optional, tag="1")]
     pub amount: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgReportHit {
     #[prost(float, optional, tag="1")]
@@ -4863,6 +5173,7 @@ pub struct CcsUsrMsgReportHit {
     #[prost(float, optional, tag="3")]
     pub pos_z: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgKillCam {
     #[prost(int32, optional, tag="1")]
@@ -4872,6 +5183,7 @@ pub struct CcsUsrMsgKillCam {
     #[prost(int32, optional, tag="3", default="-1")]
     pub second_target: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgDesiredTimescale {
     #[prost(float, optional, tag="1")]
@@ -4883,11 +5195,13 @@ pub struct CcsUsrMsgDesiredTimescale {
     #[prost(float, optional, tag="4")]
     pub start_blend_time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCurrentTimescale {
     #[prost(float, optional, tag="1")]
     pub cur_timescale: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgAchievementEvent {
     #[prost(int32, optional, tag="1")]
@@ -4897,6 +5211,7 @@ pub struct CcsUsrMsgAchievementEvent {
     #[prost(int32, optional, tag="3")]
     pub user_id: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgMatchEndConditions {
     #[prost(int32, optional, tag="1")]
@@ -4908,6 +5223,7 @@ pub struct CcsUsrMsgMatchEndConditions {
     #[prost(float, optional, tag="4")]
     pub mp_timelimit: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgPlayerStatsUpdate {
     #[prost(int32, optional, tag="1")]
@@ -4921,6 +5237,7 @@ pub struct CcsUsrMsgPlayerStatsUpdate {
 }
 /// Nested message and enum types in `CCSUsrMsg_PlayerStatsUpdate`.
 pub mod ccs_usr_msg_player_stats_update {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Stat {
         #[prost(int32, optional, tag="1")]
@@ -4929,6 +5246,7 @@ pub mod ccs_usr_msg_player_stats_update {
         pub delta: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgQuestProgress {
     #[prost(uint32, optional, tag="1")]
@@ -4940,16 +5258,19 @@ pub struct CcsUsrMsgQuestProgress {
     #[prost(bool, optional, tag="4")]
     pub is_event_quest: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgScoreLeaderboardData {
     #[prost(message, optional, tag="1")]
     pub data: ::core::option::Option<ScoreLeaderboardData>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgPlayerDecalDigitalSignature {
     #[prost(message, optional, tag="1")]
     pub data: ::core::option::Option<PlayerDecalDigitalSignature>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgXRankGet {
     #[prost(int32, optional, tag="1")]
@@ -4957,6 +5278,7 @@ pub struct CcsUsrMsgXRankGet {
     #[prost(int32, optional, tag="2")]
     pub controller: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgXRankUpd {
     #[prost(int32, optional, tag="1")]
@@ -4966,6 +5288,7 @@ pub struct CcsUsrMsgXRankUpd {
     #[prost(int32, optional, tag="3")]
     pub ranking: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCallVoteFailed {
     #[prost(int32, optional, tag="1")]
@@ -4973,6 +5296,7 @@ pub struct CcsUsrMsgCallVoteFailed {
     #[prost(int32, optional, tag="2")]
     pub time: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVoteStart {
     #[prost(int32, optional, tag="1")]
@@ -4992,6 +5316,7 @@ pub struct CcsUsrMsgVoteStart {
     #[prost(int32, optional, tag="8", default="-1")]
     pub player_slot_target: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVotePass {
     #[prost(int32, optional, tag="1")]
@@ -5003,6 +5328,7 @@ pub struct CcsUsrMsgVotePass {
     #[prost(string, optional, tag="4")]
     pub details_str: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVoteFailed {
     #[prost(int32, optional, tag="1")]
@@ -5010,11 +5336,13 @@ pub struct CcsUsrMsgVoteFailed {
     #[prost(int32, optional, tag="2")]
     pub reason: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgVoteSetup {
     #[prost(string, repeated, tag="1")]
     pub potential_issues: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSendLastKillerDamageToClient {
     #[prost(int32, optional, tag="1")]
@@ -5030,6 +5358,7 @@ pub struct CcsUsrMsgSendLastKillerDamageToClient {
     #[prost(int32, optional, tag="6")]
     pub actual_damage_taken: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgServerRankUpdate {
     #[prost(message, repeated, tag="1")]
@@ -5037,6 +5366,7 @@ pub struct CcsUsrMsgServerRankUpdate {
 }
 /// Nested message and enum types in `CCSUsrMsg_ServerRankUpdate`.
 pub mod ccs_usr_msg_server_rank_update {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct RankUpdate {
         #[prost(int32, optional, tag="1")]
@@ -5053,16 +5383,19 @@ pub mod ccs_usr_msg_server_rank_update {
         pub rank_type_id: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgXpUpdate {
     #[prost(message, optional, tag="1")]
     pub data: ::core::option::Option<CMsgGcCstrike15V2Gc2ServerNotifyXpRewarded>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgItemPickup {
     #[prost(string, optional, tag="1")]
     pub item: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgShowMenu {
     #[prost(int32, optional, tag="1")]
@@ -5072,26 +5405,31 @@ pub struct CcsUsrMsgShowMenu {
     #[prost(string, optional, tag="3")]
     pub menu_string: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgBarTime {
     #[prost(string, optional, tag="1")]
     pub time: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgAmmoDenied {
     #[prost(int32, optional, tag="1")]
     pub ammoidx: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgMarkAchievement {
     #[prost(string, optional, tag="1")]
     pub achievement: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgMatchStatsUpdate {
     #[prost(string, optional, tag="1")]
     pub update: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgItemDrop {
     #[prost(int64, optional, tag="1")]
@@ -5099,11 +5437,13 @@ pub struct CcsUsrMsgItemDrop {
     #[prost(bool, optional, tag="2")]
     pub death: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgGlowPropTurnOff {
     #[prost(int32, optional, tag="1", default="-1")]
     pub entidx: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRoundBackupFilenames {
     #[prost(int32, optional, tag="1")]
@@ -5115,6 +5455,7 @@ pub struct CcsUsrMsgRoundBackupFilenames {
     #[prost(string, optional, tag="4")]
     pub nicename: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSsui {
     #[prost(bool, optional, tag="1")]
@@ -5124,6 +5465,7 @@ pub struct CcsUsrMsgSsui {
     #[prost(float, optional, tag="3")]
     pub end_time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgSurvivalStats {
     #[prost(uint64, optional, tag="1")]
@@ -5139,6 +5481,7 @@ pub struct CcsUsrMsgSurvivalStats {
 }
 /// Nested message and enum types in `CCSUsrMsg_SurvivalStats`.
 pub mod ccs_usr_msg_survival_stats {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Fact {
         #[prost(int32, optional, tag="1")]
@@ -5150,6 +5493,7 @@ pub mod ccs_usr_msg_survival_stats {
         #[prost(float, optional, tag="4")]
         pub interestingness: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Placement {
         #[prost(uint64, optional, tag="1")]
@@ -5159,6 +5503,7 @@ pub mod ccs_usr_msg_survival_stats {
         #[prost(int32, optional, tag="3")]
         pub placement: ::core::option::Option<i32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Damage {
         #[prost(uint64, optional, tag="1")]
@@ -5173,6 +5518,7 @@ pub mod ccs_usr_msg_survival_stats {
         pub from_hits: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgEndOfMatchAllPlayersData {
     #[prost(message, repeated, tag="1")]
@@ -5182,6 +5528,7 @@ pub struct CcsUsrMsgEndOfMatchAllPlayersData {
 }
 /// Nested message and enum types in `CCSUsrMsg_EndOfMatchAllPlayersData`.
 pub mod ccs_usr_msg_end_of_match_all_players_data {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Accolade {
         #[prost(int32, optional, tag="1")]
@@ -5191,6 +5538,7 @@ pub mod ccs_usr_msg_end_of_match_all_players_data {
         #[prost(int32, optional, tag="3")]
         pub position: ::core::option::Option<i32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct PlayerData {
         #[prost(int32, optional, tag="1", default="-1")]
@@ -5211,6 +5559,7 @@ pub mod ccs_usr_msg_end_of_match_all_players_data {
         pub isbot: ::core::option::Option<bool>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRoundEndReportData {
     #[prost(message, optional, tag="1")]
@@ -5220,6 +5569,7 @@ pub struct CcsUsrMsgRoundEndReportData {
 }
 /// Nested message and enum types in `CCSUsrMsg_RoundEndReportData`.
 pub mod ccs_usr_msg_round_end_report_data {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct RerEvent {
         #[prost(float, optional, tag="1")]
@@ -5239,6 +5589,7 @@ pub mod ccs_usr_msg_round_end_report_data {
     }
     /// Nested message and enum types in `RerEvent`.
     pub mod rer_event {
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct Victim {
             #[prost(int32, optional, tag="1")]
@@ -5254,11 +5605,13 @@ pub mod ccs_usr_msg_round_end_report_data {
             #[prost(bool, optional, tag="6")]
             pub is_dead: ::core::option::Option<bool>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct Objective {
             #[prost(int32, optional, tag="1")]
             pub r#type: ::core::option::Option<i32>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct Damage {
             #[prost(int32, optional, tag="1", default="-1")]
@@ -5275,6 +5628,7 @@ pub mod ccs_usr_msg_round_end_report_data {
             pub return_num_hits: ::core::option::Option<i32>,
         }
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct InitialConditions {
         #[prost(int32, optional, tag="1")]
@@ -5285,6 +5639,7 @@ pub mod ccs_usr_msg_round_end_report_data {
         pub terrorist_odds: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgPostRoundDamageReport {
     #[prost(uint64, optional, tag="1")]
@@ -5302,16 +5657,19 @@ pub struct CcsUsrMsgPostRoundDamageReport {
     #[prost(int32, optional, tag="7")]
     pub taken_num_hits: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCurrentRoundOdds {
     #[prost(int32, optional, tag="1")]
     pub odds: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgDeepStats {
     #[prost(message, optional, tag="1")]
     pub stats: ::core::option::Option<CMsgGccStrike15ClientDeepStats>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgShootInfo {
     #[prost(int32, optional, tag="1")]
@@ -5323,41 +5681,49 @@ pub struct CcsUsrMsgShootInfo {
     #[prost(message, optional, tag="4")]
     pub shoot_dir: ::core::option::Option<CMsgQAngle>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgResetHud {
     #[prost(bool, optional, tag="1")]
     pub reset: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgGameTitle {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgRequestState {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgStopSpectatorMode {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgDisconnectToLobby {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgWarmupHasEnded {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgClientInfo {
     #[prost(int32, optional, tag="1")]
     pub dummy: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgServerRankRevealAll {
     #[prost(int32, optional, tag="1")]
@@ -5365,6 +5731,7 @@ pub struct CcsUsrMsgServerRankRevealAll {
     #[prost(message, optional, tag="2")]
     pub reservation: ::core::option::Option<CMsgGccStrike15V2MatchmakingGc2ServerReserve>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgPreMatchSayText {
     #[prost(uint32, optional, tag="1")]
@@ -5374,6 +5741,7 @@ pub struct CcsUsrMsgPreMatchSayText {
     #[prost(bool, optional, tag="3")]
     pub all_chat: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgCounterStrafe {
     #[prost(int32, optional, tag="1")]
@@ -5381,6 +5749,7 @@ pub struct CcsUsrMsgCounterStrafe {
     #[prost(int32, optional, tag="2")]
     pub total_keys_down: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CcsUsrMsgDamagePrediction {
     #[prost(int32, optional, tag="1")]
@@ -5679,11 +6048,13 @@ impl EcsUsrMsgDisconnectToLobbyAction {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageAchievementEvent {
     #[prost(uint32, optional, tag="1")]
     pub achievement: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageCloseCaption {
     #[prost(fixed32, optional, tag="1")]
@@ -5695,6 +6066,7 @@ pub struct CUserMessageCloseCaption {
     #[prost(int32, optional, tag="4", default="-1")]
     pub ent_index: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageCloseCaptionDirect {
     #[prost(fixed32, optional, tag="1")]
@@ -5706,6 +6078,7 @@ pub struct CUserMessageCloseCaptionDirect {
     #[prost(int32, optional, tag="4", default="-1")]
     pub ent_index: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageCloseCaptionPlaceholder {
     #[prost(string, optional, tag="1")]
@@ -5717,11 +6090,13 @@ pub struct CUserMessageCloseCaptionPlaceholder {
     #[prost(int32, optional, tag="4", default="-1")]
     pub ent_index: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageCurrentTimescale {
     #[prost(float, optional, tag="1")]
     pub current: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageDesiredTimescale {
     #[prost(float, optional, tag="1")]
@@ -5733,6 +6108,7 @@ pub struct CUserMessageDesiredTimescale {
     #[prost(float, optional, tag="4")]
     pub blenddeltamultiplier: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageFade {
     #[prost(uint32, optional, tag="1")]
@@ -5744,6 +6120,7 @@ pub struct CUserMessageFade {
     #[prost(fixed32, optional, tag="4")]
     pub color: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageShake {
     #[prost(uint32, optional, tag="1")]
@@ -5755,6 +6132,7 @@ pub struct CUserMessageShake {
     #[prost(float, optional, tag="4")]
     pub duration: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageShakeDir {
     #[prost(message, optional, tag="1")]
@@ -5762,6 +6140,7 @@ pub struct CUserMessageShakeDir {
     #[prost(message, optional, tag="2")]
     pub direction: ::core::option::Option<CMsgVector>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageWaterShake {
     #[prost(uint32, optional, tag="1")]
@@ -5773,6 +6152,7 @@ pub struct CUserMessageWaterShake {
     #[prost(float, optional, tag="4")]
     pub duration: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageScreenTilt {
     #[prost(uint32, optional, tag="1")]
@@ -5786,6 +6166,7 @@ pub struct CUserMessageScreenTilt {
     #[prost(float, optional, tag="5")]
     pub time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageSayText {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -5795,6 +6176,7 @@ pub struct CUserMessageSayText {
     #[prost(bool, optional, tag="3")]
     pub chat: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageSayText2 {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -5812,6 +6194,7 @@ pub struct CUserMessageSayText2 {
     #[prost(string, optional, tag="7")]
     pub param4: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageHudMsg {
     #[prost(uint32, optional, tag="1")]
@@ -5829,11 +6212,13 @@ pub struct CUserMessageHudMsg {
     #[prost(string, optional, tag="11")]
     pub message: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageHudText {
     #[prost(string, optional, tag="1")]
     pub message: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageTextMsg {
     #[prost(uint32, optional, tag="1")]
@@ -5841,12 +6226,15 @@ pub struct CUserMessageTextMsg {
     #[prost(string, repeated, tag="2")]
     pub param: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageGameTitle {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageResetHud {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageSendAudio {
     #[prost(string, optional, tag="1")]
@@ -5854,6 +6242,7 @@ pub struct CUserMessageSendAudio {
     #[prost(bool, optional, tag="2")]
     pub stop: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageAudioParameter {
     #[prost(uint32, optional, tag="1")]
@@ -5865,6 +6254,7 @@ pub struct CUserMessageAudioParameter {
     #[prost(uint32, optional, tag="4")]
     pub int_value: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageVoiceMask {
     #[prost(uint32, repeated, packed="false", tag="1")]
@@ -5874,9 +6264,11 @@ pub struct CUserMessageVoiceMask {
     #[prost(bool, optional, tag="3")]
     pub mod_enable: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageRequestState {
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageRumble {
     #[prost(int32, optional, tag="1")]
@@ -5886,6 +6278,7 @@ pub struct CUserMessageRumble {
     #[prost(int32, optional, tag="3")]
     pub flags: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageSayTextChannel {
     #[prost(int32, optional, tag="1")]
@@ -5895,6 +6288,7 @@ pub struct CUserMessageSayTextChannel {
     #[prost(string, optional, tag="3")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageColoredText {
     #[prost(uint32, optional, tag="1")]
@@ -5910,16 +6304,19 @@ pub struct CUserMessageColoredText {
     #[prost(int32, optional, tag="6")]
     pub context_team_id: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageItemPickup {
     #[prost(string, optional, tag="1")]
     pub itemname: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageAmmoDenied {
     #[prost(uint32, optional, tag="1")]
     pub ammo_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageShowMenu {
     #[prost(uint32, optional, tag="1")]
@@ -5931,6 +6328,7 @@ pub struct CUserMessageShowMenu {
     #[prost(string, optional, tag="4")]
     pub menustring: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageCreditsMsg {
     #[prost(enumeration="ERollType", optional, tag="1", default="RollNone")]
@@ -5938,11 +6336,13 @@ pub struct CUserMessageCreditsMsg {
     #[prost(float, optional, tag="2")]
     pub logo_length: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessagePlayJingle {
     #[prost(message, optional, tag="1")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessageScreenOverlay {
     #[prost(bool, optional, tag="1")]
@@ -5950,6 +6350,7 @@ pub struct CEntityMessageScreenOverlay {
     #[prost(message, optional, tag="2")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessageRemoveAllDecals {
     #[prost(bool, optional, tag="1")]
@@ -5957,6 +6358,7 @@ pub struct CEntityMessageRemoveAllDecals {
     #[prost(message, optional, tag="2")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessagePropagateForce {
     #[prost(message, optional, tag="1")]
@@ -5964,6 +6366,7 @@ pub struct CEntityMessagePropagateForce {
     #[prost(message, optional, tag="2")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessageDoSpark {
     #[prost(message, optional, tag="1")]
@@ -5983,6 +6386,7 @@ pub struct CEntityMessageDoSpark {
     #[prost(message, optional, tag="8")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CEntityMessageFixAngle {
     #[prost(bool, optional, tag="1")]
@@ -5992,6 +6396,7 @@ pub struct CEntityMessageFixAngle {
     #[prost(message, optional, tag="3")]
     pub entity_msg: ::core::option::Option<CEntityMsg>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageCameraTransition {
     #[prost(uint32, optional, tag="1")]
@@ -6003,6 +6408,7 @@ pub struct CUserMessageCameraTransition {
 }
 /// Nested message and enum types in `CUserMessageCameraTransition`.
 pub mod c_user_message_camera_transition {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct TransitionDataDriven {
         #[prost(string, optional, tag="1")]
@@ -6013,6 +6419,7 @@ pub mod c_user_message_camera_transition {
         pub duration: ::core::option::Option<f32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMsgParticleManager {
     #[prost(enumeration="ParticleMessage", required, tag="1", default="GameParticleManagerEventCreate")]
@@ -6092,9 +6499,11 @@ pub struct CUserMsgParticleManager {
 }
 /// Nested message and enum types in `CUserMsg_ParticleManager`.
 pub mod c_user_msg_particle_manager {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ReleaseParticleIndex {
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CreateParticle {
         #[prost(fixed64, optional, tag="1")]
@@ -6118,11 +6527,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="10")]
         pub aggregation_position: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DestroyParticle {
         #[prost(bool, optional, tag="1")]
         pub destroy_immediately: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DestroyParticleInvolving {
         #[prost(bool, optional, tag="1")]
@@ -6130,6 +6541,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(uint32, optional, tag="3", default="16777215")]
         pub entity_handle: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DestroyParticleNamed {
         #[prost(fixed64, optional, tag="1")]
@@ -6141,6 +6553,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(bool, optional, tag="4")]
         pub play_endcap: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleObsolete {
         #[prost(int32, optional, tag="1")]
@@ -6148,6 +6561,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="2")]
         pub position: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleFwdObsolete {
         #[prost(int32, optional, tag="1")]
@@ -6155,6 +6569,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="2")]
         pub forward: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleOrientObsolete {
         #[prost(int32, optional, tag="1")]
@@ -6168,6 +6583,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="5")]
         pub left: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleTransform {
         #[prost(int32, optional, tag="1")]
@@ -6179,6 +6595,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(float, optional, tag="4")]
         pub interpolation_interval: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleFallback {
         #[prost(int32, optional, tag="1")]
@@ -6186,6 +6603,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="2")]
         pub position: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleOffset {
         #[prost(int32, optional, tag="1")]
@@ -6195,6 +6613,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="3")]
         pub angle_offset: ::core::option::Option<super::CMsgQAngle>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleEnt {
         #[prost(int32, optional, tag="1")]
@@ -6214,6 +6633,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="8")]
         pub offset_angles: ::core::option::Option<super::CMsgQAngle>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleSetFrozen {
         #[prost(bool, optional, tag="1")]
@@ -6221,11 +6641,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(float, optional, tag="2")]
         pub transition_duration: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateParticleShouldDraw {
         #[prost(bool, optional, tag="1")]
         pub should_draw: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ChangeControlPointAttachment {
         #[prost(int32, optional, tag="1")]
@@ -6235,6 +6657,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(uint32, optional, tag="3", default="16777215")]
         pub entity_handle: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct UpdateEntityPosition {
         #[prost(uint32, optional, tag="1", default="16777215")]
@@ -6242,6 +6665,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(message, optional, tag="2")]
         pub position: ::core::option::Option<super::CMsgVector>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SetParticleFoWProperties {
         #[prost(int32, optional, tag="1")]
@@ -6251,11 +6675,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(float, optional, tag="3")]
         pub fow_radius: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SetParticleShouldCheckFoW {
         #[prost(bool, optional, tag="1")]
         pub check_fow: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetControlPointModel {
         #[prost(int32, optional, tag="1")]
@@ -6263,6 +6689,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(string, optional, tag="2")]
         pub model_name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetControlPointSnapshot {
         #[prost(int32, optional, tag="1")]
@@ -6270,11 +6697,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(string, optional, tag="2")]
         pub snapshot_name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetParticleText {
         #[prost(string, optional, tag="1")]
         pub text: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetTextureAttribute {
         #[prost(string, optional, tag="1")]
@@ -6282,11 +6711,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(string, optional, tag="2")]
         pub texture_name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SetSceneObjectGenericFlag {
         #[prost(bool, optional, tag="1")]
         pub flag_value: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SetSceneObjectTintAndDesat {
         #[prost(fixed32, optional, tag="1")]
@@ -6294,21 +6725,25 @@ pub mod c_user_msg_particle_manager {
         #[prost(float, optional, tag="2")]
         pub desat: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ParticleSkipToTime {
         #[prost(float, optional, tag="1")]
         pub skip_to_time: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ParticleCanFreeze {
         #[prost(bool, optional, tag="1")]
         pub can_freeze: ::core::option::Option<bool>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ParticleFreezeTransitionOverride {
         #[prost(float, optional, tag="1")]
         pub freeze_transition_override: ::core::option::Option<f32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct FreezeParticleInvolving {
         #[prost(bool, optional, tag="1")]
@@ -6318,6 +6753,7 @@ pub mod c_user_msg_particle_manager {
         #[prost(uint32, optional, tag="3", default="16777215")]
         pub entity_handle: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct AddModellistOverrideElement {
         #[prost(string, optional, tag="1")]
@@ -6327,11 +6763,13 @@ pub mod c_user_msg_particle_manager {
         #[prost(uint32, optional, tag="3")]
         pub groupid: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct ClearModellistOverride {
         #[prost(uint32, optional, tag="1")]
         pub groupid: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetParticleNamedValueContext {
         #[prost(message, repeated, tag="1")]
@@ -6345,6 +6783,7 @@ pub mod c_user_msg_particle_manager {
     }
     /// Nested message and enum types in `SetParticleNamedValueContext`.
     pub mod set_particle_named_value_context {
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct FloatContextValue {
             #[prost(uint32, optional, tag="1")]
@@ -6352,6 +6791,7 @@ pub mod c_user_msg_particle_manager {
             #[prost(float, optional, tag="2")]
             pub value: ::core::option::Option<f32>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct VectorContextValue {
             #[prost(uint32, optional, tag="1")]
@@ -6359,6 +6799,7 @@ pub mod c_user_msg_particle_manager {
             #[prost(message, optional, tag="2")]
             pub value: ::core::option::Option<super::super::CMsgVector>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct TransformContextValue {
             #[prost(uint32, optional, tag="1")]
@@ -6368,6 +6809,7 @@ pub mod c_user_msg_particle_manager {
             #[prost(message, optional, tag="3")]
             pub translation: ::core::option::Option<super::super::CMsgVector>,
         }
+        #[derive(::serde::Serialize)]
         #[derive(Clone, Copy, PartialEq, ::prost::Message)]
         pub struct EHandleContext {
             #[prost(uint32, optional, tag="1")]
@@ -6376,6 +6818,7 @@ pub mod c_user_msg_particle_manager {
             pub ent_index: ::core::option::Option<u32>,
         }
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CreatePhysicsSim {
         #[prost(string, optional, tag="1")]
@@ -6385,14 +6828,17 @@ pub mod c_user_msg_particle_manager {
         #[prost(uint32, optional, tag="3")]
         pub max_particle_count: ::core::option::Option<u32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct DestroyPhysicsSim {
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetVData {
         #[prost(string, optional, tag="1")]
         pub vdata_name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SetMaterialOverride {
         #[prost(string, optional, tag="1")]
@@ -6401,11 +6847,13 @@ pub mod c_user_msg_particle_manager {
         pub include_children: ::core::option::Option<bool>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMsgHudError {
     #[prost(int32, optional, tag="1")]
     pub order_id: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMsgCustomGameEvent {
     #[prost(string, optional, tag="1")]
@@ -6413,6 +6861,7 @@ pub struct CUserMsgCustomGameEvent {
     #[prost(bytes="bytes", optional, tag="2")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageHapticsManagerPulse {
     #[prost(int32, optional, tag="1")]
@@ -6424,6 +6873,7 @@ pub struct CUserMessageHapticsManagerPulse {
     #[prost(float, optional, tag="4")]
     pub effect_duration: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageHapticsManagerEffect {
     #[prost(int32, optional, tag="1")]
@@ -6433,6 +6883,7 @@ pub struct CUserMessageHapticsManagerEffect {
     #[prost(float, optional, tag="3")]
     pub effect_scale: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageAnimStateGraphState {
     #[prost(int32, optional, tag="1")]
@@ -6440,6 +6891,7 @@ pub struct CUserMessageAnimStateGraphState {
     #[prost(bytes="bytes", optional, tag="2")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageUpdateCssClasses {
     #[prost(int32, optional, tag="1")]
@@ -6449,16 +6901,19 @@ pub struct CUserMessageUpdateCssClasses {
     #[prost(bool, optional, tag="3")]
     pub is_add: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageServerFrameTime {
     #[prost(float, optional, tag="1")]
     pub frame_time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageLagCompensationError {
     #[prost(float, optional, tag="1")]
     pub distance: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageRequestDllStatus {
     #[prost(string, optional, tag="1")]
@@ -6466,6 +6921,7 @@ pub struct CUserMessageRequestDllStatus {
     #[prost(bool, optional, tag="2")]
     pub full_report: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageRequestUtilAction {
     #[prost(int32, optional, tag="2")]
@@ -6479,6 +6935,7 @@ pub struct CUserMessageRequestUtilAction {
     #[prost(int32, optional, tag="6")]
     pub util5: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageUtilMsgResponse {
     #[prost(fixed32, optional, tag="1")]
@@ -6508,6 +6965,7 @@ pub struct CUserMessageUtilMsgResponse {
 }
 /// Nested message and enum types in `CUserMessage_UtilMsg_Response`.
 pub mod c_user_message_util_msg_response {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ItemDetail {
         #[prost(int32, optional, tag="1")]
@@ -6520,6 +6978,7 @@ pub mod c_user_message_util_msg_response {
         pub name: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageDllStatus {
     #[prost(string, optional, tag="1")]
@@ -6541,6 +7000,7 @@ pub struct CUserMessageDllStatus {
 }
 /// Nested message and enum types in `CUserMessage_DllStatus`.
 pub mod c_user_message_dll_status {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CvDiagnostic {
         #[prost(uint32, optional, tag="1")]
@@ -6552,6 +7012,7 @@ pub mod c_user_message_dll_status {
         #[prost(string, optional, tag="4")]
         pub string_value: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct CModule {
         #[prost(uint64, optional, tag="1")]
@@ -6564,6 +7025,7 @@ pub mod c_user_message_dll_status {
         pub timestamp: ::core::option::Option<u32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CUserMessageRequestInventory {
     #[prost(int32, optional, tag="1")]
@@ -6573,6 +7035,7 @@ pub struct CUserMessageRequestInventory {
     #[prost(int32, optional, tag="3")]
     pub options: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageInventoryResponse {
     #[prost(fixed32, optional, tag="1")]
@@ -6604,6 +7067,7 @@ pub struct CUserMessageInventoryResponse {
 }
 /// Nested message and enum types in `CUserMessage_Inventory_Response`.
 pub mod c_user_message_inventory_response {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct InventoryDetail {
         #[prost(int32, optional, tag="1")]
@@ -6628,6 +7092,7 @@ pub mod c_user_message_inventory_response {
         pub base_hash: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageRequestDiagnostic {
     #[prost(message, repeated, tag="1")]
@@ -6635,6 +7100,7 @@ pub struct CUserMessageRequestDiagnostic {
 }
 /// Nested message and enum types in `CUserMessageRequestDiagnostic`.
 pub mod c_user_message_request_diagnostic {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Diagnostic {
         #[prost(int32, optional, tag="1")]
@@ -6665,6 +7131,7 @@ pub mod c_user_message_request_diagnostic {
         pub context: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageDiagnosticResponse {
     #[prost(message, repeated, tag="1")]
@@ -6682,6 +7149,7 @@ pub struct CUserMessageDiagnosticResponse {
 }
 /// Nested message and enum types in `CUserMessage_Diagnostic_Response`.
 pub mod c_user_message_diagnostic_response {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Diagnostic {
         #[prost(int32, optional, tag="1")]
@@ -6716,6 +7184,7 @@ pub mod c_user_message_diagnostic_response {
         pub placebo: ::core::option::Option<i64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageExtraUserData {
     #[prost(int32, optional, tag="1")]
@@ -6729,6 +7198,7 @@ pub struct CUserMessageExtraUserData {
     #[prost(bytes="bytes", repeated, tag="5")]
     pub detail2: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessageNotifyResponseFound {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -6758,6 +7228,7 @@ pub struct CUserMessageNotifyResponseFound {
 }
 /// Nested message and enum types in `CUserMessage_NotifyResponseFound`.
 pub mod c_user_message_notify_response_found {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Criteria {
         #[prost(uint32, optional, tag="1")]
@@ -6766,6 +7237,7 @@ pub mod c_user_message_notify_response_found {
         pub value: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserMessagePlayResponseConditional {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -7192,6 +7664,7 @@ impl EHapticPulseType {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgClientInfo {
     #[prost(fixed32, optional, tag="1")]
@@ -7205,6 +7678,7 @@ pub struct CclcMsgClientInfo {
     #[prost(string, optional, tag="6")]
     pub friends_name: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgMove {
     #[prost(bytes="bytes", optional, tag="3")]
@@ -7212,6 +7686,7 @@ pub struct CclcMsgMove {
     #[prost(uint32, optional, tag="4")]
     pub last_command_number: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgVoiceAudio {
     #[prost(enumeration="VoiceDataFormatT", optional, tag="1", default="VoicedataFormatSteam")]
@@ -7233,6 +7708,7 @@ pub struct CMsgVoiceAudio {
     #[prost(float, optional, tag="9")]
     pub voice_level: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgVoiceData {
     #[prost(message, optional, tag="1")]
@@ -7242,6 +7718,7 @@ pub struct CclcMsgVoiceData {
     #[prost(uint32, optional, tag="3")]
     pub tick: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgBaselineAck {
     #[prost(int32, optional, tag="1")]
@@ -7249,11 +7726,13 @@ pub struct CclcMsgBaselineAck {
     #[prost(int32, optional, tag="2")]
     pub baseline_nr: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgListenEvents {
     #[prost(fixed32, repeated, packed="false", tag="1")]
     pub event_mask: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgRespondCvarValue {
     #[prost(int32, optional, tag="1")]
@@ -7265,6 +7744,7 @@ pub struct CclcMsgRespondCvarValue {
     #[prost(string, optional, tag="4")]
     pub value: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgFileCrcCheck {
     #[prost(int32, optional, tag="1")]
@@ -7278,26 +7758,31 @@ pub struct CclcMsgFileCrcCheck {
     #[prost(fixed32, optional, tag="5")]
     pub crc: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgLoadingProgress {
     #[prost(int32, optional, tag="1")]
     pub progress: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgSplitPlayerConnect {
     #[prost(string, optional, tag="1")]
     pub playername: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgSplitPlayerDisconnect {
     #[prost(int32, optional, tag="1")]
     pub slot: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgServerStatus {
     #[prost(bool, optional, tag="1")]
     pub simplified: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgRequestPause {
     #[prost(enumeration="RequestPauseT", optional, tag="1", default="RpPause")]
@@ -7305,16 +7790,19 @@ pub struct CclcMsgRequestPause {
     #[prost(int32, optional, tag="2")]
     pub pause_group: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgCmdKeyValues {
     #[prost(bytes="bytes", optional, tag="1")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgRconServerDetails {
     #[prost(bytes="bytes", optional, tag="1")]
     pub token: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource2SystemSpecs {
     #[prost(string, optional, tag="1")]
@@ -7342,6 +7830,7 @@ pub struct CMsgSource2SystemSpecs {
     #[prost(uint32, optional, tag="47")]
     pub gpu_texture_memory_size_mb: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource2VProfLiteReportItem {
     #[prost(string, optional, tag="1")]
@@ -7381,6 +7870,7 @@ pub struct CMsgSource2VProfLiteReportItem {
     #[prost(uint32, optional, tag="44")]
     pub usec_1secmax_p99_all: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource2VProfLiteReport {
     #[prost(message, optional, tag="1")]
@@ -7390,6 +7880,7 @@ pub struct CMsgSource2VProfLiteReport {
     #[prost(uint32, optional, tag="3")]
     pub discarded_frames: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSource2NetworkFlowQuality {
     #[prost(uint32, optional, tag="1")]
@@ -7451,6 +7942,7 @@ pub struct CMsgSource2NetworkFlowQuality {
     #[prost(sint32, optional, tag="66")]
     pub recvmargin_p95: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgDiagnostic {
     #[prost(message, optional, tag="1")]
@@ -7458,6 +7950,7 @@ pub struct CclcMsgDiagnostic {
     #[prost(message, optional, tag="2")]
     pub vprof_report: ::core::option::Option<CMsgSource2VProfLiteReport>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CSource2MetricsMatchPerfSummaryNotification {
     #[prost(uint32, optional, tag="1")]
@@ -7475,6 +7968,7 @@ pub struct CSource2MetricsMatchPerfSummaryNotification {
 }
 /// Nested message and enum types in `CSource2Metrics_MatchPerfSummary_Notification`.
 pub mod c_source2_metrics_match_perf_summary_notification {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Client {
         #[prost(message, optional, tag="1")]
@@ -7487,6 +7981,7 @@ pub mod c_source2_metrics_match_perf_summary_notification {
         pub steamid: ::core::option::Option<u64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgServerInfo {
     #[prost(int32, optional, tag="1")]
@@ -7522,6 +8017,7 @@ pub struct CsvcMsgServerInfo {
     #[prost(bytes="bytes", optional, tag="20")]
     pub game_session_manifest: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgClassInfo {
     #[prost(bool, optional, tag="1")]
@@ -7531,6 +8027,7 @@ pub struct CsvcMsgClassInfo {
 }
 /// Nested message and enum types in `CSVCMsg_ClassInfo`.
 pub mod csvc_msg_class_info {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ClassT {
         #[prost(int32, optional, tag="1")]
@@ -7539,11 +8036,13 @@ pub mod csvc_msg_class_info {
         pub class_name: ::core::option::Option<::prost::alloc::string::String>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgSetPause {
     #[prost(bool, optional, tag="1")]
     pub paused: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgVoiceInit {
     #[prost(int32, optional, tag="1")]
@@ -7553,11 +8052,13 @@ pub struct CsvcMsgVoiceInit {
     #[prost(int32, optional, tag="3", default="0")]
     pub version: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgPrint {
     #[prost(string, optional, tag="1")]
     pub text: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgSounds {
     #[prost(bool, optional, tag="1")]
@@ -7567,6 +8068,7 @@ pub struct CsvcMsgSounds {
 }
 /// Nested message and enum types in `CSVCMsg_Sounds`.
 pub mod csvc_msg_sounds {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct SounddataT {
         #[prost(sint32, optional, tag="1")]
@@ -7609,6 +8111,7 @@ pub mod csvc_msg_sounds {
         pub sound_resource_id: ::core::option::Option<u64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgPrefetch {
     #[prost(int32, optional, tag="1")]
@@ -7616,6 +8119,7 @@ pub struct CsvcMsgPrefetch {
     #[prost(enumeration="PrefetchType", optional, tag="2", default="PftSound")]
     pub resource_type: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgSetView {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -7623,6 +8127,7 @@ pub struct CsvcMsgSetView {
     #[prost(int32, optional, tag="2", default="-1")]
     pub slot: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgFixAngle {
     #[prost(bool, optional, tag="1")]
@@ -7630,11 +8135,13 @@ pub struct CsvcMsgFixAngle {
     #[prost(message, optional, tag="2")]
     pub angle: ::core::option::Option<CMsgQAngle>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgCrosshairAngle {
     #[prost(message, optional, tag="1")]
     pub angle: ::core::option::Option<CMsgQAngle>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgBspDecal {
     #[prost(message, optional, tag="1")]
@@ -7648,6 +8155,7 @@ pub struct CsvcMsgBspDecal {
     #[prost(bool, optional, tag="5")]
     pub low_priority: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgSplitScreen {
     #[prost(enumeration="ESplitScreenMessageType", optional, tag="1", default="MsgSplitscreenAdduser")]
@@ -7657,6 +8165,7 @@ pub struct CsvcMsgSplitScreen {
     #[prost(int32, optional, tag="3", default="-1")]
     pub player_index: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgGetCvarValue {
     #[prost(int32, optional, tag="1")]
@@ -7664,6 +8173,7 @@ pub struct CsvcMsgGetCvarValue {
     #[prost(string, optional, tag="2")]
     pub cvar_name: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgMenu {
     #[prost(int32, optional, tag="1")]
@@ -7671,6 +8181,7 @@ pub struct CsvcMsgMenu {
     #[prost(bytes="bytes", optional, tag="2")]
     pub menu_key_values: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgUserMessage {
     #[prost(int32, optional, tag="1")]
@@ -7680,6 +8191,7 @@ pub struct CsvcMsgUserMessage {
     #[prost(int32, optional, tag="3")]
     pub passthrough: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgSendTable {
     #[prost(bool, optional, tag="1")]
@@ -7693,6 +8205,7 @@ pub struct CsvcMsgSendTable {
 }
 /// Nested message and enum types in `CSVCMsg_SendTable`.
 pub mod csvc_msg_send_table {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SendpropT {
         #[prost(int32, optional, tag="1")]
@@ -7715,6 +8228,7 @@ pub mod csvc_msg_send_table {
         pub num_bits: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgGameEventList {
     #[prost(message, repeated, tag="1")]
@@ -7722,6 +8236,7 @@ pub struct CsvcMsgGameEventList {
 }
 /// Nested message and enum types in `CSVCMsg_GameEventList`.
 pub mod csvc_msg_game_event_list {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct KeyT {
         #[prost(int32, optional, tag="1")]
@@ -7729,6 +8244,7 @@ pub mod csvc_msg_game_event_list {
         #[prost(string, optional, tag="2")]
         pub name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DescriptorT {
         #[prost(int32, optional, tag="1")]
@@ -7739,6 +8255,7 @@ pub mod csvc_msg_game_event_list {
         pub keys: ::prost::alloc::vec::Vec<KeyT>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgPacketEntities {
     #[prost(int32, optional, tag="1")]
@@ -7786,6 +8303,7 @@ pub struct CsvcMsgPacketEntities {
 }
 /// Nested message and enum types in `CSVCMsg_PacketEntities`.
 pub mod csvc_msg_packet_entities {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct AlternateBaselineT {
         #[prost(int32, optional, tag="1")]
@@ -7793,6 +8311,7 @@ pub mod csvc_msg_packet_entities {
         #[prost(int32, optional, tag="2")]
         pub baseline_index: ::core::option::Option<i32>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct NonTransmittedEntitiesT {
         #[prost(int32, optional, tag="1")]
@@ -7801,6 +8320,7 @@ pub mod csvc_msg_packet_entities {
         pub data: ::core::option::Option<::prost::bytes::Bytes>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgTempEntities {
     #[prost(bool, optional, tag="1")]
@@ -7810,6 +8330,7 @@ pub struct CsvcMsgTempEntities {
     #[prost(bytes="bytes", optional, tag="3")]
     pub entity_data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgCreateStringTable {
     #[prost(string, optional, tag="1")]
@@ -7833,6 +8354,7 @@ pub struct CsvcMsgCreateStringTable {
     #[prost(bool, optional, tag="10")]
     pub using_varint_bitcounts: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgUpdateStringTable {
     #[prost(int32, optional, tag="1")]
@@ -7842,6 +8364,7 @@ pub struct CsvcMsgUpdateStringTable {
     #[prost(bytes="bytes", optional, tag="3")]
     pub string_data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgVoiceData {
     #[prost(message, optional, tag="1")]
@@ -7859,6 +8382,7 @@ pub struct CsvcMsgVoiceData {
     #[prost(int32, optional, tag="7")]
     pub passthrough: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgPacketReliable {
     #[prost(int32, optional, tag="1")]
@@ -7868,6 +8392,7 @@ pub struct CsvcMsgPacketReliable {
     #[prost(bool, optional, tag="3")]
     pub state: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgFullFrameSplit {
     #[prost(int32, optional, tag="1")]
@@ -7879,6 +8404,7 @@ pub struct CsvcMsgFullFrameSplit {
     #[prost(bytes="bytes", optional, tag="4")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgHltvStatus {
     #[prost(string, optional, tag="1")]
@@ -7890,16 +8416,19 @@ pub struct CsvcMsgHltvStatus {
     #[prost(int32, optional, tag="4")]
     pub proxies: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgServerSteamId {
     #[prost(uint64, optional, tag="1")]
     pub steam_id: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgCmdKeyValues {
     #[prost(bytes="bytes", optional, tag="1")]
     pub data: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgRconServerDetails {
     #[prost(bytes="bytes", optional, tag="1")]
@@ -7907,6 +8436,7 @@ pub struct CsvcMsgRconServerDetails {
     #[prost(string, optional, tag="2")]
     pub details: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgIpcAddress {
     #[prost(fixed64, optional, tag="1")]
@@ -7914,6 +8444,7 @@ pub struct CMsgIpcAddress {
     #[prost(uint32, optional, tag="2")]
     pub process_id: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgServerPeer {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -7929,11 +8460,13 @@ pub struct CMsgServerPeer {
     #[prost(bool, optional, tag="6")]
     pub is_listenserver_host: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgPeerList {
     #[prost(message, repeated, tag="1")]
     pub peer: ::prost::alloc::vec::Vec<CMsgServerPeer>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgClearAllStringTables {
     #[prost(string, optional, tag="1")]
@@ -7941,6 +8474,7 @@ pub struct CsvcMsgClearAllStringTables {
     #[prost(bool, optional, tag="3")]
     pub create_tables_skipped: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoFlattenedSerializerFieldT {
     #[prost(int32, optional, tag="1")]
@@ -7970,6 +8504,7 @@ pub struct ProtoFlattenedSerializerFieldT {
 }
 /// Nested message and enum types in `ProtoFlattenedSerializerField_t`.
 pub mod proto_flattened_serializer_field_t {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct PolymorphicFieldT {
         #[prost(int32, optional, tag="1")]
@@ -7978,6 +8513,7 @@ pub mod proto_flattened_serializer_field_t {
         pub polymorphic_field_serializer_version: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProtoFlattenedSerializerT {
     #[prost(int32, optional, tag="1")]
@@ -7987,6 +8523,7 @@ pub struct ProtoFlattenedSerializerT {
     #[prost(int32, repeated, packed="false", tag="3")]
     pub fields_index: ::prost::alloc::vec::Vec<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgFlattenedSerializer {
     #[prost(message, repeated, tag="1")]
@@ -7996,11 +8533,13 @@ pub struct CsvcMsgFlattenedSerializer {
     #[prost(message, repeated, tag="3")]
     pub fields: ::prost::alloc::vec::Vec<ProtoFlattenedSerializerFieldT>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgStopSound {
     #[prost(fixed32, optional, tag="1")]
     pub guid: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CBidirMsgRebroadcastGameEvent {
     #[prost(bool, optional, tag="1")]
@@ -8012,11 +8551,13 @@ pub struct CBidirMsgRebroadcastGameEvent {
     #[prost(uint64, optional, tag="4")]
     pub receivingclients: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CBidirMsgRebroadcastSource {
     #[prost(int32, optional, tag="1")]
     pub eventsource: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgServerNetworkStats {
     #[prost(bool, optional, tag="1")]
@@ -8072,6 +8613,7 @@ pub struct CMsgServerNetworkStats {
 }
 /// Nested message and enum types in `CMsgServerNetworkStats`.
 pub mod c_msg_server_network_stats {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Port {
         #[prost(int32, optional, tag="1")]
@@ -8079,6 +8621,7 @@ pub mod c_msg_server_network_stats {
         #[prost(string, optional, tag="2")]
         pub name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Player {
         #[prost(uint64, optional, tag="1")]
@@ -8099,6 +8642,7 @@ pub mod c_msg_server_network_stats {
         pub engine_latency_ms: ::core::option::Option<i32>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsvcMsgHltvReplay {
     #[prost(int32, optional, tag="1")]
@@ -8118,6 +8662,7 @@ pub struct CsvcMsgHltvReplay {
     #[prost(int32, optional, tag="8")]
     pub reason: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CclcMsgHltvReplay {
     #[prost(int32, optional, tag="1")]
@@ -8131,11 +8676,13 @@ pub struct CclcMsgHltvReplay {
     #[prost(float, optional, tag="5")]
     pub event_time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgBroadcastCommand {
     #[prost(string, optional, tag="1")]
     pub cmd: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CclcMsgHltvFixupOperatorTick {
     #[prost(int32, optional, tag="1")]
@@ -8155,6 +8702,7 @@ pub struct CclcMsgHltvFixupOperatorTick {
     #[prost(message, optional, tag="8")]
     pub view_offset: ::core::option::Option<CMsgVector>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgHltvFixupOperatorStatus {
     #[prost(uint32, optional, tag="1")]
@@ -8162,6 +8710,7 @@ pub struct CsvcMsgHltvFixupOperatorStatus {
     #[prost(string, optional, tag="2")]
     pub override_operator_name: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgServerUserCmd {
     #[prost(bytes="bytes", optional, tag="1")]
@@ -8175,6 +8724,7 @@ pub struct CMsgServerUserCmd {
     #[prost(int32, optional, tag="5")]
     pub client_tick: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsvcMsgUserCommands {
     #[prost(message, repeated, tag="1")]
@@ -8654,6 +9204,7 @@ impl ReplayEventTypeT {
         }
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CInButtonStatePb {
     #[prost(uint64, optional, tag="1")]
@@ -8663,6 +9214,7 @@ pub struct CInButtonStatePb {
     #[prost(uint64, optional, tag="3")]
     pub buttonstate3: ::core::option::Option<u64>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CSubtickMoveStep {
     #[prost(uint64, optional, tag="1")]
@@ -8676,6 +9228,7 @@ pub struct CSubtickMoveStep {
     #[prost(float, optional, tag="5")]
     pub analog_left_delta: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CBaseUserCmdPb {
     #[prost(int32, optional, tag="1")]
@@ -8713,11 +9266,13 @@ pub struct CBaseUserCmdPb {
     #[prost(int32, optional, tag="21")]
     pub cmd_flags: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CUserCmdBasePb {
     #[prost(message, optional, tag="1")]
     pub base: ::core::option::Option<CBaseUserCmdPb>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsgoInterpolationInfoPb {
     #[prost(int32, optional, tag="1", default="-1")]
@@ -8727,11 +9282,13 @@ pub struct CsgoInterpolationInfoPb {
     #[prost(float, optional, tag="3", default="0")]
     pub frac: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsgoInterpolationInfoPbCl {
     #[prost(float, optional, tag="3", default="0")]
     pub frac: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CsgoInputHistoryEntryPb {
     #[prost(message, optional, tag="2")]
@@ -8765,6 +9322,7 @@ pub struct CsgoInputHistoryEntryPb {
     #[prost(message, optional, tag="69")]
     pub target_abs_ang_check: ::core::option::Option<CMsgQAngle>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CsgoUserCmdPb {
     #[prost(message, optional, tag="1")]
@@ -8786,6 +9344,7 @@ pub struct CsgoUserCmdPb {
     #[prost(bool, optional, tag="13", default="false")]
     pub is_predicting_kill_ragdolls: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgVDebugGameSessionIdEvent {
     #[prost(int32, optional, tag="1")]
@@ -8793,6 +9352,7 @@ pub struct CMsgVDebugGameSessionIdEvent {
     #[prost(string, optional, tag="2")]
     pub gamesessionid: ::core::option::Option<::prost::alloc::string::String>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgPlaceDecalEvent {
     #[prost(message, optional, tag="1")]
@@ -8824,16 +9384,19 @@ pub struct CMsgPlaceDecalEvent {
     #[prost(bool, optional, tag="14")]
     pub is_adjacent: ::core::option::Option<bool>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgClearWorldDecalsEvent {
     #[prost(uint32, optional, tag="1")]
     pub flagstoclear: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgClearEntityDecalsEvent {
     #[prost(uint32, optional, tag="1")]
     pub flagstoclear: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgClearDecalsForSkeletonInstanceEvent {
     #[prost(uint32, optional, tag="1")]
@@ -8843,6 +9406,7 @@ pub struct CMsgClearDecalsForSkeletonInstanceEvent {
     #[prost(uint32, optional, tag="3")]
     pub skeletoninstancehash: ::core::option::Option<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource1LegacyGameEventList {
     #[prost(message, repeated, tag="1")]
@@ -8850,6 +9414,7 @@ pub struct CMsgSource1LegacyGameEventList {
 }
 /// Nested message and enum types in `CMsgSource1LegacyGameEventList`.
 pub mod c_msg_source1_legacy_game_event_list {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct KeyT {
         #[prost(int32, optional, tag="1")]
@@ -8857,6 +9422,7 @@ pub mod c_msg_source1_legacy_game_event_list {
         #[prost(string, optional, tag="2")]
         pub name: ::core::option::Option<::prost::alloc::string::String>,
     }
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DescriptorT {
         #[prost(int32, optional, tag="1")]
@@ -8867,6 +9433,7 @@ pub mod c_msg_source1_legacy_game_event_list {
         pub keys: ::prost::alloc::vec::Vec<KeyT>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource1LegacyListenEvents {
     #[prost(int32, optional, tag="1")]
@@ -8874,6 +9441,7 @@ pub struct CMsgSource1LegacyListenEvents {
     #[prost(uint32, repeated, packed="false", tag="2")]
     pub eventarraybits: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSource1LegacyGameEvent {
     #[prost(string, optional, tag="1")]
@@ -8889,6 +9457,7 @@ pub struct CMsgSource1LegacyGameEvent {
 }
 /// Nested message and enum types in `CMsgSource1LegacyGameEvent`.
 pub mod c_msg_source1_legacy_game_event {
+    #[derive(::serde::Serialize)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct KeyT {
         #[prost(int32, optional, tag="1")]
@@ -8909,6 +9478,7 @@ pub mod c_msg_source1_legacy_game_event {
         pub val_uint64: ::core::option::Option<u64>,
     }
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSosStartSoundEvent {
     #[prost(int32, optional, tag="1")]
@@ -8924,11 +9494,13 @@ pub struct CMsgSosStartSoundEvent {
     #[prost(float, optional, tag="6")]
     pub start_time: ::core::option::Option<f32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSosStopSoundEvent {
     #[prost(int32, optional, tag="1")]
     pub soundevent_guid: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CMsgSosStopSoundEventHash {
     #[prost(fixed32, optional, tag="1")]
@@ -8936,6 +9508,7 @@ pub struct CMsgSosStopSoundEventHash {
     #[prost(int32, optional, tag="2", default="-1")]
     pub source_entity_index: ::core::option::Option<i32>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSosSetSoundEventParams {
     #[prost(int32, optional, tag="1")]
@@ -8943,6 +9516,7 @@ pub struct CMsgSosSetSoundEventParams {
     #[prost(bytes="bytes", optional, tag="5")]
     pub packed_params: ::core::option::Option<::prost::bytes::Bytes>,
 }
+#[derive(::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CMsgSosSetLibraryStackFields {
     #[prost(fixed32, optional, tag="1")]
@@ -157,6 +157,12 @@ pub fn parse_voice(path_or_buf: Either<String, Buffer>) -> napi::Result<HashMap<
     only_convars: false,
     huffman_lookup_table: &vec![],
     order_by_steamid: false,
+    parse_economy: false,
+    parse_visibility: false,
+    parse_ticks_with_events: false,
+    parse_shot_details: false,
+    wanted_net_messages: vec![],
+    parse_string_tables: false,
   };
   let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
   let output = parse_demo(bytes, &mut parser)?;
@@ -191,6 +197,12 @@ pub fn list_game_events(path_or_buf: Either<String, Buffer>) -> napi::Result<Val
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    parse_economy: false,
+    parse_visibility: false,
+    parse_ticks_with_events: false,
+    parse_shot_details: false,
+    wanted_net_messages: vec![],
+    parse_string_tables: false,
   };
   let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
   let output = parse_demo(bytes, &mut parser)?;
@@ -223,6 +235,12 @@ pub fn parse_grenades(path_or_buf: Either<String, Buffer>) -> napi::Result<Value
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    parse_economy: false,
+    parse_visibility: false,
+    parse_ticks_with_events: false,
+    parse_shot_details: false,
+    wanted_net_messages: vec![],
+    parse_string_tables: false,
   };
   let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
   let output = parse_demo(bytes, &mut parser)?;
@@ -253,6 +271,12 @@ pub fn parse_header(path_or_buf: Either<String, Buffer>) -> napi::Result<Value>
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    parse_economy: false,
+    parse_visibility: false,
+    parse_ticks_with_events: false,
+    parse_shot_details: false,
+    wanted_net_messages: vec![],
+    parse_string_tables: false,
   };
   let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
   let output = parse_demo(bytes, &mut parser)?;
@@ -318,6 +342,12 @@ pub fn parse_event(
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    parse_economy: false,
+    parse_visibility: false,
+    parse_ticks_with_events: false,
+    parse_shot_details: false,
+    wanted_net_messages: vec![],
+    parse_string_tables: false,
   };
   let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
   let output = parse_demo(bytes, &mut parser)?;
@@ -381,6 +411,12 @@ pub fn parse_events(
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    parse_economy: false,
+    parse_visibility: false,
+    parse_ticks_with_events: false,
+    parse_shot_details: false,
+    wanted_net_messages: vec![],
+    parse_string_tables: false,
   };
   let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
   let output = parse_demo(bytes, &mut parser)?;
@@ -459,6 +495,12 @@ pub fn parse_ticks(
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: order_by_steamid,
+    parse_economy: false,
+    parse_visibility: false,
+    parse_ticks_with_events: false,
+    parse_shot_details: false,
+    wanted_net_messages: vec![],
+    parse_string_tables: false,
   };
 
   let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
@@ -532,6 +574,12 @@ pub fn parse_player_info(path_or_buf: Either<String, Buffer>) -> napi::Result<Va
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    parse_economy: false,
+    parse_visibility: false,
+    parse_ticks_with_events: false,
+    parse_shot_details: false,
+    wanted_net_messages: vec![],
+    parse_string_tables: false,
   };
   let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
   let output = parse_demo(bytes, &mut parser)?;
@@ -562,6 +610,12 @@ pub fn parse_player_skins(path_or_buf: Either<String, Buffer>) -> napi::Result<V
     only_convars: false,
     huffman_lookup_table: &huf,
     order_by_steamid: false,
+    parse_economy: false,
+    parse_visibility: false,
+    parse_ticks_with_events: false,
+    parse_shot_details: false,
+    wanted_net_messages: vec![],
+    parse_string_tables: false,
   };
   let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
   let output = parse_demo(bytes, &mut parser)?;
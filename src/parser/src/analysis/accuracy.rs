@@ -0,0 +1,236 @@
+use super::find_i32;
+use super::find_string;
+use super::find_u64;
+use super::is_warmup;
+use super::seconds_to_ticks;
+use crate::maps::WEAPON_MAX_SPEED;
+use crate::second_pass::game_events::GameEvent;
+use ahash::AHashMap;
+use std::collections::BTreeMap;
+
+// Per weapon accuracy. Every weapon_fire is paired with the player_hurt and player_death
+// events it caused: same attacker and weapon, within a few ticks of the shot.
+
+pub const ACCURACY_EVENTS: &[&str] = &["weapon_fire", "player_hurt", "player_death"];
+pub const ACCURACY_PLAYER_PROPS: &[&str] = &["team_num"];
+pub const ACCURACY_OTHER_PROPS: &[&str] = &["is_warmup_period"];
+
+// Max ticks between a shot and the damage it caused
+pub const SHOT_HIT_WINDOW_TICKS: i32 = 2;
+// A shot more than this many seconds after the previous one with the same weapon starts a new spray
+pub const SPRAY_GAP_SECONDS: f32 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shot {
+    pub tick: i32,
+    pub steamid: u64,
+    pub name: Option<String>,
+    pub weapon: String,
+    // Index of the spray this shot belongs to, counted per player
+    pub spray: i32,
+    pub first_bullet: bool,
+    pub hit_victims: Vec<u64>,
+    pub headshot: bool,
+    pub damage: i32,
+    pub killed_victims: Vec<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeaponAccuracy {
+    pub steamid: u64,
+    pub name: Option<String>,
+    pub weapon: String,
+    pub shots: i32,
+    pub hits: i32,
+    pub headshot_hits: i32,
+    pub damage: i32,
+    pub first_bullet_shots: i32,
+    pub first_bullet_hits: i32,
+    pub spray_transfer_kills: i32,
+}
+
+// weapon_fire has "weapon_ak47" while player_hurt/player_death have "ak47"
+fn normalize_weapon(weapon: &str) -> String {
+    weapon.strip_prefix("weapon_").unwrap_or(weapon).to_string()
+}
+
+fn is_firearm(weapon: &str) -> bool {
+    WEAPON_MAX_SPEED.contains_key(format!("weapon_{weapon}").as_str())
+}
+
+// Index of the latest shot by this player with this weapon that could have caused damage at tick
+fn find_shot(shots: &[Shot], by_player: &AHashMap<(u64, String), Vec<usize>>, steamid: u64, weapon: &str, tick: i32) -> Option<usize> {
+    by_player
+        .get(&(steamid, weapon.to_string()))?
+        .iter()
+        .rev()
+        .find(|idx| shots[**idx].tick <= tick && tick - shots[**idx].tick <= SHOT_HIT_WINDOW_TICKS)
+        .copied()
+}
+
+pub fn shots_from_events(events: &[GameEvent], tick_interval: f32) -> Vec<Shot> {
+    let spray_gap_ticks = seconds_to_ticks(SPRAY_GAP_SECONDS, tick_interval);
+    let mut shots: Vec<Shot> = vec![];
+    let mut by_player: AHashMap<(u64, String), Vec<usize>> = AHashMap::default();
+    let mut last_shot: AHashMap<u64, (i32, String, i32)> = AHashMap::default();
+    let mut sorted: Vec<&GameEvent> = events.iter().filter(|e| !is_warmup(e)).collect();
+    sorted.sort_by_key(|e| e.tick);
+
+    for event in sorted {
+        match event.name.as_str() {
+            "weapon_fire" => {
+                let (steamid, weapon) = match (find_u64(event, "user_steamid"), find_string(event, "weapon")) {
+                    (Some(steamid), Some(weapon)) => (steamid, normalize_weapon(&weapon)),
+                    _ => continue,
+                };
+                if !is_firearm(&weapon) {
+                    continue;
+                }
+                let (spray, first_bullet) = match last_shot.get(&steamid) {
+                    Some((tick, last_weapon, spray)) if *last_weapon == weapon && event.tick - tick <= spray_gap_ticks => (*spray, false),
+                    Some((_, _, spray)) => (spray + 1, true),
+                    None => (0, true),
+                };
+                last_shot.insert(steamid, (event.tick, weapon.clone(), spray));
+                by_player.entry((steamid, weapon.clone())).or_default().push(shots.len());
+                shots.push(Shot {
+                    tick: event.tick,
+                    steamid,
+                    name: find_string(event, "user_name"),
+                    weapon,
+                    spray,
+                    first_bullet,
+                    hit_victims: vec![],
+                    headshot: false,
+                    damage: 0,
+                    killed_victims: vec![],
+                });
+            }
+            "player_hurt" | "player_death" => {
+                let (attacker, victim, weapon) = match (
+                    find_u64(event, "attacker_steamid"),
+                    find_u64(event, "user_steamid"),
+                    find_string(event, "weapon"),
+                ) {
+                    (Some(attacker), Some(victim), Some(weapon)) if attacker != victim => (attacker, victim, normalize_weapon(&weapon)),
+                    _ => continue,
+                };
+                let idx = match find_shot(&shots, &by_player, attacker, &weapon, event.tick) {
+                    Some(idx) => idx,
+                    None => continue,
+                };
+                let shot = &mut shots[idx];
+                if event.name == "player_death" {
+                    shot.killed_victims.push(victim);
+                    continue;
+                }
+                shot.hit_victims.push(victim);
+                shot.damage += find_i32(event, "dmg_health").unwrap_or(0);
+                shot.headshot |= find_string(event, "hitgroup").as_deref() == Some("head");
+            }
+            _ => {}
+        }
+    }
+    shots
+}
+
+pub fn weapon_accuracy(shots: &[Shot]) -> Vec<WeaponAccuracy> {
+    let mut stats: BTreeMap<(u64, String), WeaponAccuracy> = BTreeMap::default();
+    // Victims already killed in each spray
+    let mut spray_kills: AHashMap<(u64, i32), Vec<u64>> = AHashMap::default();
+    for shot in shots {
+        let entry = stats.entry((shot.steamid, shot.weapon.clone())).or_insert_with(|| WeaponAccuracy {
+            steamid: shot.steamid,
+            name: None,
+            weapon: shot.weapon.clone(),
+            shots: 0,
+            hits: 0,
+            headshot_hits: 0,
+            damage: 0,
+            first_bullet_shots: 0,
+            first_bullet_hits: 0,
+            spray_transfer_kills: 0,
+        });
+        if shot.name.is_some() {
+            entry.name = shot.name.clone();
+        }
+        let hit = !shot.hit_victims.is_empty();
+        entry.shots += 1;
+        entry.hits += hit as i32;
+        entry.headshot_hits += shot.headshot as i32;
+        entry.damage += shot.damage;
+        if shot.first_bullet {
+            entry.first_bullet_shots += 1;
+            entry.first_bullet_hits += hit as i32;
+        }
+        let killed = spray_kills.entry((shot.steamid, shot.spray)).or_default();
+        for victim in &shot.killed_victims {
+            if killed.iter().any(|v| v != victim) {
+                entry.spray_transfer_kills += 1;
+            }
+            killed.push(*victim);
+        }
+    }
+    stats.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_helpers::event;
+    use crate::analysis::test_helpers::hurt;
+    use crate::second_pass::game_events::EventField;
+    use crate::second_pass::variants::Variant;
+
+    fn hit(name: &str, tick: i32, victim: &str, hitgroup: &str) -> GameEvent {
+        let mut hit = hurt(name, tick, victim, 3, "ak47", 27);
+        hit.fields.push(EventField {
+            name: "hitgroup".to_string(),
+            data: Some(Variant::String(hitgroup.to_string())),
+        });
+        hit
+    }
+
+    fn fire(tick: i32) -> GameEvent {
+        event(
+            "weapon_fire",
+            tick,
+            vec![
+                ("user_steamid", Variant::String("1".to_string())),
+                ("weapon", Variant::String("weapon_ak47".to_string())),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_weapon_accuracy() {
+        let events = vec![
+            fire(100),
+            hit("player_hurt", 100, "2", "head"),
+            hit("player_death", 100, "2", "head"),
+            fire(108),
+            fire(116),
+            hit("player_hurt", 117, "3", "chest"),
+            hit("player_death", 117, "3", "chest"),
+            // New spray
+            fire(300),
+            // Grenades are not counted
+            event(
+                "weapon_fire",
+                310,
+                vec![
+                    ("user_steamid", Variant::String("1".to_string())),
+                    ("weapon", Variant::String("weapon_hegrenade".to_string())),
+                ],
+            ),
+        ];
+        let shots = shots_from_events(&events, 1.0 / 64.0);
+        assert_eq!(shots.len(), 4);
+        let stats = weapon_accuracy(&shots);
+        assert_eq!(stats.len(), 1);
+        let ak = &stats[0];
+        assert_eq!((ak.shots, ak.hits, ak.headshot_hits, ak.damage), (4, 2, 1, 54));
+        assert_eq!((ak.first_bullet_shots, ak.first_bullet_hits), (2, 1));
+        assert_eq!(ak.spray_transfer_kills, 1);
+    }
+}
@@ -0,0 +1,419 @@
+use super::find_string;
+use super::find_u64;
+use super::is_warmup;
+use crate::first_pass::prop_controller::PropInfo;
+use crate::first_pass::prop_controller::PITCH_ID;
+use crate::first_pass::prop_controller::PLAYER_X_ID;
+use crate::first_pass::prop_controller::PLAYER_Y_ID;
+use crate::first_pass::prop_controller::PLAYER_Z_ID;
+use crate::first_pass::prop_controller::STEAMID_ID;
+use crate::first_pass::prop_controller::TICK_ID;
+use crate::first_pass::prop_controller::USERCMD_INPUT_HISTORY_BASEID;
+use crate::first_pass::prop_controller::YAW_ID;
+use crate::second_pass::collect_data::PLAYER_EYE_HEIGHT;
+use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::variants::InputHistory;
+use crate::second_pass::variants::PropColumn;
+use crate::second_pass::variants::VarVec;
+use crate::second_pass::visibility::VisibilityRecord;
+use ahash::AHashMap;
+
+// Aim analytics built from the usercmd input history. One engagement is one visibility
+// interval (observer spotted an enemy target), annotated with how far the crosshair was
+// from the target's head when it was spotted, how long until the first shot and the
+// biggest flick made in between.
+
+pub const AIM_EVENTS: &[&str] = &["weapon_fire"];
+pub const AIM_PLAYER_PROPS: &[&str] = &["X", "Y", "Z", "pitch", "yaw", "team_num", "usercmd_input_history"];
+pub const AIM_OTHER_PROPS: &[&str] = &["is_warmup_period"];
+
+// View needs to turn at least this much per tick for it to count as part of a flick
+pub const FLICK_MIN_DEGREES_PER_TICK: f32 = 3.0;
+// Smaller total turns are not reported as flicks
+pub const FLICK_MIN_DEGREES: f32 = 10.0;
+// Shots with these don't count as reacting to an enemy
+const NON_AIM_WEAPONS: &[&str] = &[
+    "weapon_knife",
+    "weapon_hegrenade",
+    "weapon_flashbang",
+    "weapon_smokegrenade",
+    "weapon_molotov",
+    "weapon_incgrenade",
+    "weapon_decoy",
+    "weapon_c4",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AimSample {
+    pub tick: i32,
+    pub steamid: u64,
+    pub team_num: Option<i32>,
+    pub position: [f32; 3],
+    pub pitch: f32,
+    pub yaw: f32,
+    pub input_history: Vec<InputHistory>,
+}
+
+// Change of view between two consecutive subtick inputs
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewDelta {
+    pub tick: i32,
+    pub render_tick_count: i32,
+    pub render_tick_fraction: f32,
+    pub delta_pitch: f32,
+    pub delta_yaw: f32,
+    pub magnitude: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flick {
+    pub start_tick: i32,
+    pub end_tick: i32,
+    pub magnitude: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Engagement {
+    pub observer_steamid: u64,
+    pub observer_name: Option<String>,
+    pub target_steamid: u64,
+    pub target_name: Option<String>,
+    pub spotted_tick: i32,
+    pub lost_tick: i32,
+    // Angle in degrees between the observers view and the targets head at spotted_tick
+    pub crosshair_distance: Option<f32>,
+    pub first_shot_tick: Option<i32>,
+    // Seconds from spotting to the first shot
+    pub reaction_time: Option<f32>,
+    // Largest flick between spotting and the first shot (or losing sight)
+    pub flick_magnitude: Option<f32>,
+}
+
+// Builds per player per tick samples out of the tick dataframe. Needs AIM_PLAYER_PROPS.
+pub fn aim_samples(df: &AHashMap<u32, PropColumn>, prop_infos: &[PropInfo]) -> AHashMap<u64, Vec<AimSample>> {
+    let f32_col = |id: u32| match df.get(&id).and_then(|col| col.data.as_ref()) {
+        Some(VarVec::F32(data)) => Some(data),
+        _ => None,
+    };
+    let team_col = prop_infos
+        .iter()
+        .find(|info| info.prop_friendly_name == "team_num")
+        .and_then(|info| df.get(&info.id))
+        .and_then(|col| col.data.as_ref());
+    let team_at = |idx: usize| match team_col {
+        Some(VarVec::I32(v)) => v.get(idx).copied().flatten(),
+        Some(VarVec::U32(v)) => v.get(idx).copied().flatten().map(|t| t as i32),
+        _ => None,
+    };
+    let history_col = match df.get(&USERCMD_INPUT_HISTORY_BASEID).and_then(|col| col.data.as_ref()) {
+        Some(VarVec::InputHistory(data)) => Some(data),
+        _ => None,
+    };
+    let mut samples: AHashMap<u64, Vec<AimSample>> = AHashMap::default();
+    let (ticks, steamids) = match (
+        df.get(&TICK_ID).and_then(|c| c.data.as_ref()),
+        df.get(&STEAMID_ID).and_then(|c| c.data.as_ref()),
+    ) {
+        (Some(VarVec::I32(ticks)), Some(VarVec::U64(steamids))) => (ticks, steamids),
+        _ => return samples,
+    };
+    let (xs, ys, zs, pitches, yaws) = match (
+        f32_col(PLAYER_X_ID),
+        f32_col(PLAYER_Y_ID),
+        f32_col(PLAYER_Z_ID),
+        f32_col(PITCH_ID),
+        f32_col(YAW_ID),
+    ) {
+        (Some(xs), Some(ys), Some(zs), Some(pitches), Some(yaws)) => (xs, ys, zs, pitches, yaws),
+        _ => return samples,
+    };
+    for (idx, tick) in ticks.iter().enumerate() {
+        let sample = || -> Option<AimSample> {
+            Some(AimSample {
+                tick: (*tick)?,
+                steamid: steamids.get(idx).copied().flatten()?,
+                team_num: team_at(idx),
+                position: [
+                    xs.get(idx).copied().flatten()?,
+                    ys.get(idx).copied().flatten()?,
+                    zs.get(idx).copied().flatten()?,
+                ],
+                pitch: pitches.get(idx).copied().flatten()?,
+                yaw: yaws.get(idx).copied().flatten()?,
+                input_history: history_col.and_then(|h| h.get(idx)).cloned().unwrap_or_default(),
+            })
+        };
+        if let Some(sample) = sample() {
+            samples.entry(sample.steamid).or_default().push(sample);
+        }
+    }
+    for player_samples in samples.values_mut() {
+        player_samples.sort_by_key(|s| s.tick);
+        player_samples.dedup_by_key(|s| s.tick);
+    }
+    samples
+}
+
+fn normalize_angle(angle: f32) -> f32 {
+    let mut angle = angle % 360.0;
+    if angle > 180.0 {
+        angle -= 360.0;
+    } else if angle < -180.0 {
+        angle += 360.0;
+    }
+    angle
+}
+
+// View-angle deltas between consecutive subtick inputs of one player (samples sorted by tick).
+// The same input can be repeated in the history of consecutive usercmds, so entries are
+// deduplicated on their render tick.
+pub fn view_deltas(samples: &[AimSample]) -> Vec<ViewDelta> {
+    let mut deltas = vec![];
+    let mut prev: Option<&InputHistory> = None;
+    for sample in samples {
+        for input in &sample.input_history {
+            if let Some(p) = prev {
+                let newer = (input.render_tick_count, input.render_tick_fraction) > (p.render_tick_count, p.render_tick_fraction);
+                if !newer {
+                    continue;
+                }
+                let delta_pitch = input.x - p.x;
+                let delta_yaw = normalize_angle(input.y - p.y);
+                deltas.push(ViewDelta {
+                    tick: sample.tick,
+                    render_tick_count: input.render_tick_count,
+                    render_tick_fraction: input.render_tick_fraction,
+                    delta_pitch,
+                    delta_yaw,
+                    magnitude: (delta_pitch * delta_pitch + delta_yaw * delta_yaw).sqrt(),
+                });
+            }
+            prev = Some(input);
+        }
+    }
+    deltas
+}
+
+// A flick is a run of consecutive ticks where the view turns fast, adding up to a large turn.
+pub fn flicks(deltas: &[ViewDelta]) -> Vec<Flick> {
+    let mut per_tick: Vec<(i32, f32)> = vec![];
+    for delta in deltas {
+        match per_tick.last_mut() {
+            Some((tick, magnitude)) if *tick == delta.tick => *magnitude += delta.magnitude,
+            _ => per_tick.push((delta.tick, delta.magnitude)),
+        }
+    }
+    let mut flicks = vec![];
+    let mut current: Option<Flick> = None;
+    for (tick, magnitude) in per_tick {
+        let fast = magnitude >= FLICK_MIN_DEGREES_PER_TICK;
+        current = match current {
+            Some(mut flick) if fast && tick == flick.end_tick + 1 => {
+                flick.end_tick = tick;
+                flick.magnitude += magnitude;
+                Some(flick)
+            }
+            previous => {
+                if let Some(flick) = previous {
+                    if flick.magnitude >= FLICK_MIN_DEGREES {
+                        flicks.push(flick);
+                    }
+                }
+                if fast {
+                    Some(Flick {
+                        start_tick: tick,
+                        end_tick: tick,
+                        magnitude,
+                    })
+                } else {
+                    None
+                }
+            }
+        };
+    }
+    if let Some(flick) = current {
+        if flick.magnitude >= FLICK_MIN_DEGREES {
+            flicks.push(flick);
+        }
+    }
+    flicks
+}
+
+// Angle in degrees between the view direction (pitch, yaw) from eye and the direction to head
+pub fn crosshair_distance(eye: [f32; 3], pitch: f32, yaw: f32, head: [f32; 3]) -> f32 {
+    let dx = head[0] - eye[0];
+    let dy = head[1] - eye[1];
+    let dz = head[2] - eye[2];
+    let (pitch, yaw) = (pitch.to_radians(), yaw.to_radians());
+    let forward = [pitch.cos() * yaw.cos(), pitch.cos() * yaw.sin(), -pitch.sin()];
+    let len = (dx * dx + dy * dy + dz * dz).sqrt();
+    if len == 0.0 {
+        return 0.0;
+    }
+    let dot = (forward[0] * dx + forward[1] * dy + forward[2] * dz) / len;
+    dot.clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+fn sample_at(samples: &[AimSample], tick: i32) -> Option<&AimSample> {
+    match samples.binary_search_by_key(&tick, |s| s.tick) {
+        Ok(idx) => samples.get(idx),
+        Err(0) => None,
+        Err(idx) => samples.get(idx - 1),
+    }
+}
+
+fn is_aim_weapon(weapon: &str) -> bool {
+    !NON_AIM_WEAPONS.iter().any(|w| weapon.starts_with(w))
+}
+
+pub fn engagements(samples: &AHashMap<u64, Vec<AimSample>>, visibility: &[VisibilityRecord], events: &[GameEvent], tick_interval: f32) -> Vec<Engagement> {
+    let mut shots: AHashMap<u64, Vec<i32>> = AHashMap::default();
+    for event in events.iter().filter(|e| e.name == "weapon_fire" && !is_warmup(e)) {
+        if let Some(weapon) = find_string(event, "weapon") {
+            if !is_aim_weapon(&weapon) {
+                continue;
+            }
+        }
+        if let Some(steamid) = find_u64(event, "user_steamid") {
+            shots.entry(steamid).or_default().push(event.tick);
+        }
+    }
+    for ticks in shots.values_mut() {
+        ticks.sort();
+    }
+    let flicks_per_player: AHashMap<u64, Vec<Flick>> = samples.iter().map(|(steamid, s)| (*steamid, flicks(&view_deltas(s)))).collect();
+    let empty = vec![];
+
+    let mut engagements = vec![];
+    for record in visibility {
+        let observer_samples = samples.get(&record.observer_steamid).unwrap_or(&empty);
+        let target_samples = samples.get(&record.target_steamid).unwrap_or(&empty);
+        let observer = sample_at(observer_samples, record.start_tick);
+        let target = sample_at(target_samples, record.start_tick);
+        // Teammates spot each other too
+        if let (Some(o), Some(t)) = (observer, target) {
+            if o.team_num.is_some() && o.team_num == t.team_num {
+                continue;
+            }
+        }
+        let crosshair_distance = match (observer, target) {
+            (Some(o), Some(t)) => {
+                let eye = [o.position[0], o.position[1], o.position[2] + PLAYER_EYE_HEIGHT];
+                let head = [t.position[0], t.position[1], t.position[2] + PLAYER_EYE_HEIGHT];
+                Some(crosshair_distance(eye, o.pitch, o.yaw, head))
+            }
+            _ => None,
+        };
+        let first_shot_tick = shots
+            .get(&record.observer_steamid)
+            .and_then(|ticks| ticks.iter().find(|t| **t >= record.start_tick && **t <= record.end_tick))
+            .copied();
+        let window_end = first_shot_tick.unwrap_or(record.end_tick);
+        let flick_magnitude = flicks_per_player
+            .get(&record.observer_steamid)
+            .unwrap_or(&vec![])
+            .iter()
+            .filter(|f| f.end_tick >= record.start_tick && f.start_tick <= window_end)
+            .map(|f| f.magnitude)
+            .fold(None, |acc: Option<f32>, m| Some(acc.map_or(m, |a| a.max(m))));
+
+        engagements.push(Engagement {
+            observer_steamid: record.observer_steamid,
+            observer_name: record.observer_name.clone(),
+            target_steamid: record.target_steamid,
+            target_name: record.target_name.clone(),
+            spotted_tick: record.start_tick,
+            lost_tick: record.end_tick,
+            crosshair_distance,
+            first_shot_tick,
+            reaction_time: first_shot_tick.map(|t| (t - record.start_tick) as f32 * tick_interval),
+            flick_magnitude,
+        });
+    }
+    engagements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_helpers::event;
+    use crate::second_pass::variants::Variant;
+
+    fn input(render_tick_count: i32, x: f32, y: f32) -> InputHistory {
+        InputHistory {
+            x,
+            y,
+            z: 0.0,
+            render_tick_count,
+            render_tick_fraction: 0.0,
+            player_tick_count: render_tick_count,
+            player_tick_fraction: 0.0,
+        }
+    }
+
+    fn sample(tick: i32, position: [f32; 3], pitch: f32, yaw: f32, input_history: Vec<InputHistory>) -> AimSample {
+        AimSample {
+            tick,
+            steamid: 1,
+            team_num: Some(2),
+            position,
+            pitch,
+            yaw,
+            input_history,
+        }
+    }
+
+    #[test]
+    fn test_view_deltas_and_flicks() {
+        let samples = vec![
+            sample(1, [0.0; 3], 0.0, 0.0, vec![input(1, 0.0, 170.0)]),
+            // Repeated entry from the previous usercmd is skipped, yaw wraps around
+            sample(2, [0.0; 3], 0.0, 0.0, vec![input(1, 0.0, 170.0), input(2, 0.0, -175.0)]),
+            sample(3, [0.0; 3], 0.0, 0.0, vec![input(3, 0.0, -165.0)]),
+            sample(4, [0.0; 3], 0.0, 0.0, vec![input(4, 0.0, -164.0)]),
+        ];
+        let deltas = view_deltas(&samples);
+        assert_eq!(deltas.len(), 3);
+        assert!((deltas[0].delta_yaw - 15.0).abs() < 1e-3);
+        let flicks = flicks(&deltas);
+        assert_eq!(flicks.len(), 1);
+        assert_eq!((flicks[0].start_tick, flicks[0].end_tick), (2, 3));
+        assert!((flicks[0].magnitude - 25.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_engagements() {
+        let target = AimSample {
+            steamid: 2,
+            team_num: Some(3),
+            ..sample(0, [0.0, 100.0, 0.0], 0.0, 0.0, vec![])
+        };
+        let samples = AHashMap::from_iter([(1, vec![sample(0, [0.0; 3], 0.0, 90.0, vec![])]), (2, vec![target])]);
+        let visibility = vec![VisibilityRecord {
+            observer_steamid: 1,
+            observer_name: None,
+            target_steamid: 2,
+            target_name: None,
+            start_tick: 100,
+            end_tick: 300,
+        }];
+        let shot = event(
+            "weapon_fire",
+            132,
+            vec![("user_steamid", Variant::U64(1)), ("weapon", Variant::String("weapon_ak47".to_string()))],
+        );
+        let result = engagements(&samples, &visibility, &[shot], 1.0 / 64.0);
+        assert_eq!(result.len(), 1);
+        assert!(result[0].crosshair_distance.unwrap() < 1e-3);
+        assert_eq!(result[0].first_shot_tick, Some(132));
+        assert_eq!(result[0].reaction_time, Some(0.5));
+    }
+
+    #[test]
+    fn test_crosshair_distance() {
+        assert!(crosshair_distance([0.0; 3], 0.0, 90.0, [0.0, 100.0, 0.0]) < 1e-3);
+        assert!((crosshair_distance([0.0; 3], 0.0, 90.0, [100.0, 0.0, 0.0]) - 90.0).abs() < 1e-3);
+        // Positive pitch is looking down
+        assert!(crosshair_distance([0.0; 3], 45.0, 0.0, [100.0, 0.0, -100.0]) < 1e-3);
+    }
+}
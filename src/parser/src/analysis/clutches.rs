@@ -0,0 +1,191 @@
+use crate::analysis::rounds::RoundRecord;
+use crate::analysis::trades::Kill;
+use crate::first_pass::prop_controller::PropInfo;
+use crate::first_pass::prop_controller::IS_ALIVE_ID;
+use crate::first_pass::prop_controller::NAME_ID;
+use crate::first_pass::prop_controller::STEAMID_ID;
+use crate::first_pass::prop_controller::TICK_ID;
+use crate::second_pass::variants::PropColumn;
+use crate::second_pass::variants::VarVec;
+use ahash::AHashMap;
+
+// Clutch (1vX) detection. Who is alive is read from the "is_alive" and "team_num" props
+// on the ticks of the player_death events (the tick data already reflects the death).
+// A clutch starts when a death leaves a team with a single player alive while the other
+// team still has players alive.
+
+pub const CLUTCHES_EVENTS: &[&str] = &["round_start", "round_freeze_end", "round_end", "round_officially_ended", "player_death"];
+pub const CLUTCHES_PLAYER_PROPS: &[&str] = &["team_num"];
+pub const CLUTCHES_OTHER_PROPS: &[&str] = &["team_rounds_total", "team_clan_name", "overtime_playing", "is_warmup_period"];
+// Props to parse on the death ticks
+pub const CLUTCHES_TICK_PROPS: &[&str] = &["is_alive", "team_num"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AliveState {
+    pub tick: i32,
+    pub steamid: u64,
+    pub name: Option<String>,
+    pub team_num: i32,
+    pub is_alive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clutch {
+    pub round: i32,
+    pub start_tick: i32,
+    pub steamid: u64,
+    pub name: Option<String>,
+    pub side: String,
+    pub opponents: i32,
+    pub kills: i32,
+    pub won: Option<bool>,
+}
+
+fn side_name(team_num: i32) -> &'static str {
+    match team_num {
+        2 => "T",
+        _ => "CT",
+    }
+}
+
+pub fn alive_states(df: &AHashMap<u32, PropColumn>, prop_infos: &[PropInfo]) -> Vec<AliveState> {
+    let col = |id: u32| df.get(&id).and_then(|col| col.data.as_ref());
+    let team_col = prop_infos
+        .iter()
+        .find(|info| info.prop_friendly_name == "team_num")
+        .and_then(|info| col(info.id));
+    let team_at = |idx: usize| match team_col {
+        Some(VarVec::I32(v)) => v.get(idx).copied().flatten(),
+        Some(VarVec::U32(v)) => v.get(idx).copied().flatten().map(|t| t as i32),
+        _ => None,
+    };
+    let name_at = |idx: usize| match col(NAME_ID) {
+        Some(VarVec::String(v)) => v.get(idx).cloned().flatten(),
+        _ => None,
+    };
+    let (ticks, steamids, alive) = match (col(TICK_ID), col(STEAMID_ID), col(IS_ALIVE_ID)) {
+        (Some(VarVec::I32(ticks)), Some(VarVec::U64(steamids)), Some(VarVec::Bool(alive))) => (ticks, steamids, alive),
+        _ => return vec![],
+    };
+    let mut states: Vec<AliveState> = ticks
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, tick)| {
+            Some(AliveState {
+                tick: (*tick)?,
+                steamid: steamids.get(idx).copied().flatten()?,
+                name: name_at(idx),
+                team_num: team_at(idx)?,
+                is_alive: alive.get(idx).copied().flatten()?,
+            })
+        })
+        .collect();
+    states.sort_by_key(|s| s.tick);
+    states
+}
+
+// Alive players per team on the given tick
+fn alive_at(states: &[AliveState], tick: i32) -> AHashMap<i32, Vec<&AliveState>> {
+    let mut alive: AHashMap<i32, Vec<&AliveState>> = AHashMap::default();
+    for state in states.iter().filter(|s| s.tick == tick && s.is_alive) {
+        alive.entry(state.team_num).or_default().push(state);
+    }
+    alive
+}
+
+pub fn clutches(rounds: &[RoundRecord], states: &[AliveState], kills: &[Kill]) -> Vec<Clutch> {
+    let mut out = vec![];
+    for round in rounds {
+        let end_tick = round.end_tick.unwrap_or(i32::MAX);
+        let mut clutch: Option<Clutch> = None;
+
+        for kill in kills.iter().filter(|k| k.round == round.round && k.tick <= end_tick) {
+            if let Some(clutch) = clutch.as_mut() {
+                if kill.attacker == Some(clutch.steamid) && kill.is_enemy_kill() {
+                    clutch.kills += 1;
+                }
+                continue;
+            }
+            let alive = alive_at(states, kill.tick);
+            for (team, opponent) in [(2, 3), (3, 2)] {
+                let n_opponents = alive.get(&opponent).map_or(0, |p| p.len());
+                let clutcher = match alive.get(&team).map(|p| p.as_slice()) {
+                    Some([clutcher]) => *clutcher,
+                    _ => continue,
+                };
+                if n_opponents == 0 {
+                    continue;
+                }
+                clutch = Some(Clutch {
+                    round: round.round,
+                    start_tick: kill.tick,
+                    steamid: clutcher.steamid,
+                    name: clutcher.name.clone(),
+                    side: side_name(team).to_string(),
+                    opponents: n_opponents as i32,
+                    kills: 0,
+                    won: None,
+                });
+                break;
+            }
+        }
+        if let Some(mut clutch) = clutch {
+            clutch.won = match round.winner.as_deref() {
+                Some("T") | Some("CT") => Some(round.winner.as_deref() == Some(clutch.side.as_str())),
+                _ => None,
+            };
+            out.push(clutch);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_helpers::kill;
+
+    fn alive(tick: i32, steamid: u64, team_num: i32, is_alive: bool) -> AliveState {
+        AliveState {
+            tick,
+            steamid,
+            name: None,
+            team_num,
+            is_alive,
+        }
+    }
+
+    #[test]
+    fn test_clutches() {
+        let rounds = vec![RoundRecord {
+            round: 1,
+            start_tick: Some(0),
+            freeze_end_tick: Some(100),
+            end_tick: Some(5000),
+            officially_ended_tick: None,
+            winner: Some("T".to_string()),
+            reason: None,
+            t_score: None,
+            ct_score: None,
+            t_name: None,
+            ct_name: None,
+            is_overtime: false,
+        }];
+        // Player 1 is left alone against three after the first death
+        let t = [1, 2];
+        let ct = [10, 11, 12];
+        let mut states = vec![];
+        for (tick, dead) in [(200, vec![2]), (300, vec![2, 10]), (400, vec![2, 10, 11]), (500, vec![2, 10, 11, 12])] {
+            states.extend(t.iter().map(|s| alive(tick, *s, 2, !dead.contains(s))));
+            states.extend(ct.iter().map(|s| alive(tick, *s, 3, !dead.contains(s))));
+        }
+        let kills = vec![kill(200, 10, 3, 2, 2), kill(300, 1, 2, 10, 3), kill(400, 1, 2, 11, 3), kill(500, 1, 2, 12, 3)];
+        let result = clutches(&rounds, &states, &kills);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].steamid, 1);
+        assert_eq!(result[0].start_tick, 200);
+        assert_eq!(result[0].opponents, 3);
+        assert_eq!(result[0].kills, 3);
+        assert_eq!(result[0].won, Some(true));
+    }
+}
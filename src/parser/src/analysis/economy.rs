@@ -0,0 +1,225 @@
+use super::find_bool;
+use super::find_i32;
+use super::find_u64;
+use crate::analysis::rounds::RoundRecord;
+use crate::second_pass::economy::EconomySnapshot;
+use crate::second_pass::game_events::GameEvent;
+use ahash::AHashMap;
+use ahash::AHashSet;
+use std::collections::BTreeMap;
+
+// Team economy per round. Built from the freeze end snapshots collected during parsing,
+// the item_purchase events (with was_sold) and the rounds table.
+// Spend during the freeze period is the drop in money from the start of the round, so items
+// refunded during the freeze don't count. Buys after the freeze come from item_purchase.
+// Pistol rounds are round 1 and the first round after the teams switch sides. Overtime halves
+// start with overtime money, so they are classified like any other round.
+
+pub const ECONOMY_EVENTS: &[&str] = &["round_start", "round_freeze_end", "round_end", "round_officially_ended", "item_purchase"];
+
+// Team equipment value thresholds used for the buy classification
+pub const ECO_MAX_EQUIPMENT_VALUE: i32 = 5000;
+pub const FULL_BUY_MIN_EQUIPMENT_VALUE: i32 = 20000;
+// A team that spends at least this share of its starting money on a non-full buy is forcing
+pub const FORCE_BUY_MIN_SPEND_RATIO: f32 = 0.8;
+
+const LOSS_BONUS_BASE: i32 = 1400;
+const LOSS_BONUS_INCREMENT: i32 = 500;
+const LOSS_BONUS_MAX_LEVEL: i32 = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EconomyRecord {
+    pub round: i32,
+    pub side: String,
+    pub tick: i32,
+    pub start_money: i32,
+    pub spend: i32,
+    pub equipment_value: i32,
+    pub loss_bonus_level: Option<i32>,
+    pub loss_bonus: Option<i32>,
+    pub buy_type: String,
+}
+
+pub fn economy_from_snapshots(rounds: &[RoundRecord], snapshots: &[EconomySnapshot], events: &[GameEvent]) -> Vec<EconomyRecord> {
+    // The freeze can end multiple times for the same round (restarts), only the last one counts.
+    let mut last_freeze_end: AHashMap<i32, i32> = AHashMap::default();
+    for snap in snapshots {
+        if let Some(round) = snap.round {
+            let tick = last_freeze_end.entry(round).or_insert(snap.tick);
+            *tick = (*tick).max(snap.tick);
+        }
+    }
+    let purchases: Vec<&GameEvent> = events.iter().filter(|e| e.name == "item_purchase").collect();
+    let side_switches = side_switch_rounds(snapshots, &last_freeze_end);
+
+    let mut records = vec![];
+    for round in rounds {
+        let freeze_end_tick = match last_freeze_end.get(&round.round) {
+            Some(t) => *t,
+            None => continue,
+        };
+        let round_snaps: Vec<&EconomySnapshot> = snapshots.iter().filter(|s| s.round == Some(round.round) && s.tick == freeze_end_tick).collect();
+        let next_start = rounds.iter().filter_map(|r| r.start_tick).filter(|t| Some(*t) > round.start_tick).min();
+        let round_end = next_start.or(round.officially_ended_tick).unwrap_or(i32::MAX);
+        let round_start = round.start_tick.unwrap_or(i32::MIN);
+
+        for (team_num, side) in [(2, "T"), (3, "CT")] {
+            let team_snaps: Vec<&&EconomySnapshot> = round_snaps.iter().filter(|s| s.team_num == Some(team_num)).collect();
+            if team_snaps.is_empty() {
+                continue;
+            }
+            let steamids: AHashSet<u64> = team_snaps.iter().filter_map(|s| s.steamid).collect();
+            let start_money = team_snaps.iter().filter_map(|s| s.start_balance).sum();
+            let equipment_value = team_snaps.iter().filter_map(|s| s.equipment_value).sum();
+            let freeze_spend: i32 = team_snaps.iter().filter_map(|s| Some((s.start_balance? - s.balance?).max(0))).sum();
+            let late_spend: i32 = purchases
+                .iter()
+                .filter(|e| e.tick > freeze_end_tick && e.tick >= round_start && e.tick < round_end)
+                .filter(|e| find_bool(e, "was_sold") != Some(true))
+                .filter(|e| find_u64(e, "steamid").is_some_and(|s| steamids.contains(&s)))
+                .filter_map(|e| find_i32(e, "cost"))
+                .sum();
+            let spend = freeze_spend + late_spend;
+            let losing_streak = match side {
+                "T" => team_snaps.iter().find_map(|s| s.t_losing_streak),
+                _ => team_snaps.iter().find_map(|s| s.ct_losing_streak),
+            };
+            let loss_bonus_level = losing_streak.map(|l| l.clamp(0, LOSS_BONUS_MAX_LEVEL));
+            let is_pistol = round.round == 1 || (side_switches.contains(&round.round) && !round.is_overtime);
+            records.push(EconomyRecord {
+                round: round.round,
+                side: side.to_string(),
+                tick: freeze_end_tick,
+                start_money,
+                spend,
+                equipment_value,
+                loss_bonus_level,
+                loss_bonus: loss_bonus_level.map(|l| LOSS_BONUS_BASE + l * LOSS_BONUS_INCREMENT),
+                buy_type: classify_buy(is_pistol, equipment_value, start_money, spend).to_string(),
+            });
+        }
+    }
+    records
+}
+
+// Rounds where most of the players are on the other side compared to the round before
+fn side_switch_rounds(snapshots: &[EconomySnapshot], last_freeze_end: &AHashMap<i32, i32>) -> AHashSet<i32> {
+    let mut sides: BTreeMap<i32, AHashMap<u64, u32>> = BTreeMap::default();
+    for snap in snapshots {
+        if let (Some(round), Some(steamid), Some(team_num)) = (snap.round, snap.steamid, snap.team_num) {
+            if last_freeze_end.get(&round) == Some(&snap.tick) && (team_num == 2 || team_num == 3) {
+                sides.entry(round).or_default().insert(steamid, team_num);
+            }
+        }
+    }
+    let mut switches = AHashSet::default();
+    for ((_, prev), (round, current)) in sides.iter().zip(sides.iter().skip(1)) {
+        let stayed: Vec<bool> = current.iter().filter_map(|(steamid, team)| prev.get(steamid).map(|p| p == team)).collect();
+        let n_switched = stayed.iter().filter(|stayed| !**stayed).count();
+        if n_switched * 2 > stayed.len() {
+            switches.insert(*round);
+        }
+    }
+    switches
+}
+
+pub fn classify_buy(is_pistol: bool, equipment_value: i32, start_money: i32, spend: i32) -> &'static str {
+    if is_pistol {
+        return "pistol";
+    }
+    if equipment_value < ECO_MAX_EQUIPMENT_VALUE {
+        return "eco";
+    }
+    if equipment_value >= FULL_BUY_MIN_EQUIPMENT_VALUE {
+        return "full";
+    }
+    if start_money > 0 && spend as f32 >= start_money as f32 * FORCE_BUY_MIN_SPEND_RATIO {
+        return "force";
+    }
+    "half"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_helpers::event;
+    use crate::second_pass::variants::Variant;
+
+    #[test]
+    fn test_classify_buy() {
+        assert_eq!(classify_buy(true, 4000, 4000, 3000), "pistol");
+        assert_eq!(classify_buy(false, 3500, 9000, 500), "eco");
+        assert_eq!(classify_buy(false, 12000, 11000, 10000), "force");
+        assert_eq!(classify_buy(false, 14000, 30000, 9000), "half");
+        assert_eq!(classify_buy(false, 24000, 40000, 20000), "full");
+    }
+
+    fn round(round: i32, start_tick: i32, is_overtime: bool) -> RoundRecord {
+        let mut record = RoundRecord::new(round);
+        record.start_tick = Some(start_tick);
+        record.is_overtime = is_overtime;
+        record
+    }
+
+    fn snapshot(round: i32, tick: i32, steamid: u64, team_num: u32, start_balance: i32, balance: i32) -> EconomySnapshot {
+        EconomySnapshot {
+            tick,
+            round: Some(round),
+            steamid: Some(steamid),
+            name: None,
+            team_num: Some(team_num),
+            start_balance: Some(start_balance),
+            balance: Some(balance),
+            equipment_value: Some(start_balance - balance),
+            t_losing_streak: None,
+            ct_losing_streak: None,
+        }
+    }
+
+    fn purchase(tick: i32, steamid: u64, cost: i32, was_sold: bool) -> GameEvent {
+        event(
+            "item_purchase",
+            tick,
+            vec![
+                ("steamid", Variant::U64(steamid)),
+                ("cost", Variant::I32(cost)),
+                ("was_sold", Variant::Bool(was_sold)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_economy_from_snapshots() {
+        // MR2 so that the sides switch before round 3, round 5 is the first overtime round after a switch
+        let rounds = vec![
+            round(1, 0, false),
+            round(2, 1000, false),
+            round(3, 2000, false),
+            round(4, 3000, false),
+            round(5, 4000, true),
+        ];
+        let mut snapshots = vec![];
+        for (r, switched) in [(1, false), (2, false), (3, true), (4, true), (5, false)] {
+            let freeze_end = (r - 1) * 1000 + 100;
+            let (team_a, team_b) = if switched { (3, 2) } else { (2, 3) };
+            snapshots.push(snapshot(r, freeze_end, 1, team_a, 16000, 11000));
+            snapshots.push(snapshot(r, freeze_end, 2, team_b, 16000, 16000));
+        }
+        // Refunded during the freeze, which the balance already accounts for
+        let events = vec![purchase(1050, 1, 3000, true), purchase(1200, 1, 500, false), purchase(1300, 1, 200, true)];
+        let economy = economy_from_snapshots(&rounds, &snapshots, &events);
+
+        let buy_types: Vec<(i32, &str, &str)> = economy.iter().map(|e| (e.round, e.side.as_str(), e.buy_type.as_str())).collect();
+        assert!(buy_types.contains(&(1, "T", "pistol")));
+        assert!(buy_types.contains(&(2, "T", "half")));
+        assert!(buy_types.contains(&(3, "CT", "pistol")));
+        assert!(buy_types.contains(&(4, "CT", "half")));
+        assert!(!buy_types.contains(&(5, "T", "pistol")));
+
+        // 5000 spent during the freeze and 500 after it, the sold item doesn't count
+        let t_round_2 = economy.iter().find(|e| e.round == 2 && e.side == "T").unwrap();
+        assert_eq!(t_round_2.spend, 5500);
+        let ct_round_2 = economy.iter().find(|e| e.round == 2 && e.side == "CT").unwrap();
+        assert_eq!(ct_round_2.spend, 0);
+    }
+}
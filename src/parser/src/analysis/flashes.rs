@@ -0,0 +1,276 @@
+use super::find_bool;
+use super::find_f32;
+use super::find_i32;
+use super::find_string;
+use super::find_u64;
+use super::is_warmup;
+use super::seconds_to_ticks;
+use crate::second_pass::game_events::GameEvent;
+use ahash::AHashMap;
+use std::collections::BTreeMap;
+use std::collections::VecDeque;
+
+// Flashbang effectiveness. Every player_blind is linked to the flashbang_detonate of the
+// same grenade entity and to the throw (weapon_fire with a flashbang) it came from.
+
+pub const FLASHES_EVENTS: &[&str] = &["weapon_fire", "flashbang_detonate", "player_blind", "player_death"];
+pub const FLASHES_PLAYER_PROPS: &[&str] = &["team_num"];
+pub const FLASHES_OTHER_PROPS: &[&str] = &["is_warmup_period"];
+
+// Throws that have not detonated after this many seconds are dropped (fuse is ~1.6s)
+const MAX_FLASH_FLIGHT_SECONDS: f32 = 10.0;
+// Max ticks between a detonation and the blinds it caused
+const BLIND_MATCH_WINDOW_TICKS: i32 = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlashDetonation {
+    pub tick: i32,
+    pub entity_id: Option<i32>,
+    pub throw_tick: Option<i32>,
+    pub thrower_steamid: Option<u64>,
+    pub thrower_name: Option<String>,
+    pub thrower_team: Option<i32>,
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+    pub z: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlashBlind {
+    pub tick: i32,
+    pub entity_id: Option<i32>,
+    pub detonate_tick: Option<i32>,
+    pub throw_tick: Option<i32>,
+    pub thrower_steamid: Option<u64>,
+    pub thrower_name: Option<String>,
+    pub thrower_team: Option<i32>,
+    pub victim_steamid: u64,
+    pub victim_name: Option<String>,
+    pub victim_team: Option<i32>,
+    pub blind_duration: f32,
+    pub enemy: bool,
+    pub self_flash: bool,
+    // Victim died to the throwers team before the blind wore off
+    pub killed_while_blind: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlashStats {
+    pub steamid: u64,
+    pub name: Option<String>,
+    pub thrown: i32,
+    pub enemies_blinded: i32,
+    pub teammates_blinded: i32,
+    pub enemy_blind_duration: f32,
+    pub team_blind_duration: f32,
+    pub blinded_enemies_killed: i32,
+    pub flash_assists: i32,
+}
+
+pub fn flash_detonations(events: &[GameEvent], tick_interval: f32) -> Vec<FlashDetonation> {
+    let max_flight_ticks = seconds_to_ticks(MAX_FLASH_FLIGHT_SECONDS, tick_interval);
+    let mut throws: AHashMap<u64, VecDeque<i32>> = AHashMap::default();
+    let mut detonations = vec![];
+    for event in events.iter().filter(|e| !is_warmup(e)) {
+        match event.name.as_str() {
+            "weapon_fire" if find_string(event, "weapon").as_deref() == Some("weapon_flashbang") => {
+                if let Some(steamid) = find_u64(event, "user_steamid") {
+                    throws.entry(steamid).or_default().push_back(event.tick);
+                }
+            }
+            "flashbang_detonate" => {
+                let thrower = find_u64(event, "user_steamid");
+                // Oldest throw of this player that is still in the air
+                let throw_tick = thrower.and_then(|steamid| throws.get_mut(&steamid)).and_then(|pending| {
+                    while let Some(tick) = pending.pop_front() {
+                        if tick <= event.tick && event.tick - tick <= max_flight_ticks {
+                            return Some(tick);
+                        }
+                    }
+                    None
+                });
+                detonations.push(FlashDetonation {
+                    tick: event.tick,
+                    entity_id: find_i32(event, "entityid"),
+                    throw_tick,
+                    thrower_steamid: thrower,
+                    thrower_name: find_string(event, "user_name"),
+                    thrower_team: find_i32(event, "user_team_num"),
+                    x: find_f32(event, "x"),
+                    y: find_f32(event, "y"),
+                    z: find_f32(event, "z"),
+                });
+            }
+            _ => {}
+        }
+    }
+    detonations
+}
+
+pub fn flash_blinds(events: &[GameEvent], detonations: &[FlashDetonation], tick_interval: f32) -> Vec<FlashBlind> {
+    let deaths: Vec<&GameEvent> = events.iter().filter(|e| e.name == "player_death" && !is_warmup(e)).collect();
+    let mut blinds = vec![];
+    for event in events.iter().filter(|e| e.name == "player_blind" && !is_warmup(e)) {
+        let victim_steamid = match find_u64(event, "user_steamid") {
+            Some(steamid) => steamid,
+            None => continue,
+        };
+        let entity_id = find_i32(event, "entityid");
+        let detonation = detonations
+            .iter()
+            .filter(|d| d.entity_id == entity_id && (d.tick - event.tick).abs() <= BLIND_MATCH_WINDOW_TICKS)
+            .min_by_key(|d| (d.tick - event.tick).abs());
+        let thrower_steamid = find_u64(event, "attacker_steamid").or(detonation.and_then(|d| d.thrower_steamid));
+        let thrower_team = find_i32(event, "attacker_team_num").or(detonation.and_then(|d| d.thrower_team));
+        let victim_team = find_i32(event, "user_team_num");
+        let blind_duration = find_f32(event, "blind_duration").unwrap_or(0.0);
+        let blind_end = event.tick + seconds_to_ticks(blind_duration, tick_interval);
+        let killed_while_blind = deaths.iter().any(|death| {
+            death.tick >= event.tick
+                && death.tick <= blind_end
+                && find_u64(death, "user_steamid") == Some(victim_steamid)
+                && thrower_team.is_some()
+                && find_i32(death, "attacker_team_num") == thrower_team
+        });
+        let self_flash = thrower_steamid == Some(victim_steamid);
+        blinds.push(FlashBlind {
+            tick: event.tick,
+            entity_id,
+            detonate_tick: detonation.map(|d| d.tick),
+            throw_tick: detonation.and_then(|d| d.throw_tick),
+            thrower_steamid,
+            thrower_name: find_string(event, "attacker_name").or(detonation.and_then(|d| d.thrower_name.clone())),
+            thrower_team,
+            victim_steamid,
+            victim_name: find_string(event, "user_name"),
+            victim_team,
+            blind_duration,
+            enemy: !self_flash && thrower_team.is_some() && victim_team.is_some() && thrower_team != victim_team,
+            self_flash,
+            killed_while_blind,
+        });
+    }
+    blinds
+}
+
+fn stats_entry<'a>(stats: &'a mut BTreeMap<u64, FlashStats>, steamid: u64, name: &Option<String>) -> &'a mut FlashStats {
+    let entry = stats.entry(steamid).or_insert_with(|| FlashStats {
+        steamid,
+        name: None,
+        thrown: 0,
+        enemies_blinded: 0,
+        teammates_blinded: 0,
+        enemy_blind_duration: 0.0,
+        team_blind_duration: 0.0,
+        blinded_enemies_killed: 0,
+        flash_assists: 0,
+    });
+    if name.is_some() {
+        entry.name = name.clone();
+    }
+    entry
+}
+
+pub fn flash_stats(events: &[GameEvent], detonations: &[FlashDetonation], blinds: &[FlashBlind]) -> Vec<FlashStats> {
+    let mut stats: BTreeMap<u64, FlashStats> = BTreeMap::default();
+    for detonation in detonations {
+        if let Some(steamid) = detonation.thrower_steamid {
+            stats_entry(&mut stats, steamid, &detonation.thrower_name).thrown += 1;
+        }
+    }
+    for blind in blinds {
+        let steamid = match blind.thrower_steamid {
+            Some(steamid) => steamid,
+            None => continue,
+        };
+        let entry = stats_entry(&mut stats, steamid, &blind.thrower_name);
+        if blind.enemy {
+            entry.enemies_blinded += 1;
+            entry.enemy_blind_duration += blind.blind_duration;
+            entry.blinded_enemies_killed += blind.killed_while_blind as i32;
+        } else if !blind.self_flash {
+            entry.teammates_blinded += 1;
+            entry.team_blind_duration += blind.blind_duration;
+        }
+    }
+    for event in events.iter().filter(|e| e.name == "player_death" && !is_warmup(e)) {
+        if find_bool(event, "assistedflash") == Some(true) {
+            if let Some(steamid) = find_u64(event, "assister_steamid") {
+                stats_entry(&mut stats, steamid, &find_string(event, "assister_name")).flash_assists += 1;
+            }
+        }
+    }
+    stats.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_helpers::event;
+    use crate::second_pass::variants::Variant;
+
+    fn steamid(s: &str) -> Variant {
+        Variant::String(s.to_string())
+    }
+
+    fn blind(tick: i32, victim: &str, victim_team: i32) -> GameEvent {
+        event(
+            "player_blind",
+            tick,
+            vec![
+                ("user_steamid", steamid(victim)),
+                ("user_team_num", Variant::I32(victim_team)),
+                ("attacker_steamid", steamid("1")),
+                ("attacker_team_num", Variant::I32(2)),
+                ("entityid", Variant::I32(150)),
+                ("blind_duration", Variant::F32(2.0)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_flash_attribution() {
+        let events = vec![
+            event(
+                "weapon_fire",
+                100,
+                vec![("user_steamid", steamid("1")), ("weapon", steamid("weapon_flashbang"))],
+            ),
+            event(
+                "flashbang_detonate",
+                200,
+                vec![
+                    ("user_steamid", steamid("1")),
+                    ("user_team_num", Variant::I32(2)),
+                    ("entityid", Variant::I32(150)),
+                ],
+            ),
+            blind(200, "2", 3),
+            blind(200, "3", 2),
+            blind(200, "1", 2),
+            event(
+                "player_death",
+                250,
+                vec![
+                    ("user_steamid", steamid("2")),
+                    ("attacker_team_num", Variant::I32(2)),
+                    ("assister_steamid", steamid("1")),
+                    ("assistedflash", Variant::Bool(true)),
+                ],
+            ),
+        ];
+        let detonations = flash_detonations(&events, 1.0 / 64.0);
+        assert_eq!(detonations[0].throw_tick, Some(100));
+        let blinds = flash_blinds(&events, &detonations, 1.0 / 64.0);
+        assert_eq!(blinds.len(), 3);
+        assert!(blinds[0].enemy && blinds[0].killed_while_blind);
+        assert_eq!(blinds[0].detonate_tick, Some(200));
+        assert!(!blinds[1].enemy && !blinds[1].killed_while_blind);
+        assert!(blinds[2].self_flash && !blinds[2].enemy);
+
+        let stats = flash_stats(&events, &detonations, &blinds);
+        assert_eq!(stats.len(), 1);
+        assert_eq!((stats[0].thrown, stats[0].enemies_blinded, stats[0].teammates_blinded), (1, 1, 1));
+        assert_eq!((stats[0].blinded_enemies_killed, stats[0].flash_assists), (1, 1));
+    }
+}
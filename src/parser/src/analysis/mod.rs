@@ -0,0 +1,69 @@
+pub mod accuracy;
+pub mod aim;
+pub mod clutches;
+pub mod economy;
+pub mod flashes;
+pub mod player_stats;
+pub mod rounds;
+#[cfg(test)]
+mod test_helpers;
+pub mod trades;
+pub mod utility_damage;
+
+use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::variants::Variant;
+
+// Converts a duration into ticks of the demo (tick_interval comes from the header)
+pub fn seconds_to_ticks(seconds: f32, tick_interval: f32) -> i32 {
+    (seconds / tick_interval).round() as i32
+}
+
+// Helpers for reading fields out of already parsed game events.
+
+pub(crate) fn find_field<'a>(event: &'a GameEvent, name: &str) -> Option<&'a Variant> {
+    event.fields.iter().find(|f| f.name == name).and_then(|f| f.data.as_ref())
+}
+
+pub(crate) fn find_i32(event: &GameEvent, name: &str) -> Option<i32> {
+    match find_field(event, name) {
+        Some(Variant::I32(v)) => Some(*v),
+        Some(Variant::U32(v)) => Some(*v as i32),
+        Some(Variant::I16(v)) => Some(*v as i32),
+        Some(Variant::U8(v)) => Some(*v as i32),
+        _ => None,
+    }
+}
+
+pub(crate) fn find_f32(event: &GameEvent, name: &str) -> Option<f32> {
+    match find_field(event, name) {
+        Some(Variant::F32(v)) => Some(*v),
+        _ => None,
+    }
+}
+
+pub(crate) fn find_u64(event: &GameEvent, name: &str) -> Option<u64> {
+    match find_field(event, name) {
+        Some(Variant::U64(v)) => Some(*v),
+        Some(Variant::String(s)) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+pub(crate) fn find_bool(event: &GameEvent, name: &str) -> Option<bool> {
+    match find_field(event, name) {
+        Some(Variant::Bool(b)) => Some(*b),
+        _ => None,
+    }
+}
+
+pub(crate) fn find_string(event: &GameEvent, name: &str) -> Option<String> {
+    match find_field(event, name) {
+        Some(Variant::String(s)) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+// Needs "is_warmup_period" as a wanted other prop
+pub(crate) fn is_warmup(event: &GameEvent) -> bool {
+    find_bool(event, "is_warmup_period") == Some(true)
+}
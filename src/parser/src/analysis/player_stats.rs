@@ -0,0 +1,329 @@
+use super::find_i32;
+use super::find_string;
+use super::find_u64;
+use super::is_warmup;
+use super::seconds_to_ticks;
+use crate::analysis::rounds::round_for_tick;
+use crate::analysis::rounds::round_rosters;
+use crate::analysis::rounds::rounds_from_events;
+use crate::analysis::trades::find_trade;
+use crate::analysis::trades::kills_from_events;
+use crate::analysis::trades::TRADE_WINDOW_SECONDS;
+use crate::second_pass::game_events::GameEvent;
+use ahash::AHashMap;
+use std::collections::BTreeMap;
+
+// Player performance stats per round and per match computed from
+// player_spawn, player_death and player_hurt events.
+
+pub const PLAYER_STATS_EVENTS: &[&str] = &[
+    "round_start",
+    "round_freeze_end",
+    "round_end",
+    "round_officially_ended",
+    "player_spawn",
+    "player_death",
+    "player_hurt",
+];
+pub const PLAYER_STATS_PLAYER_PROPS: &[&str] = &["team_num"];
+pub const PLAYER_STATS_OTHER_PROPS: &[&str] = &["team_rounds_total", "team_clan_name", "overtime_playing", "is_warmup_period"];
+
+const UTILITY_WEAPONS: &[&str] = &["hegrenade", "molotov", "incgrenade", "inferno"];
+const STARTING_HEALTH: i32 = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerRoundStats {
+    pub round: i32,
+    pub steamid: u64,
+    pub name: Option<String>,
+    pub team_num: Option<i32>,
+    pub kills: i32,
+    pub deaths: i32,
+    pub assists: i32,
+    pub flash_assists: i32,
+    pub headshot_kills: i32,
+    pub damage: i32,
+    pub utility_damage: i32,
+    pub opening_kill: bool,
+    pub opening_death: bool,
+    pub traded_death: bool,
+    pub kast: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerStats {
+    pub steamid: u64,
+    pub name: Option<String>,
+    pub rounds: i32,
+    pub kills: i32,
+    pub deaths: i32,
+    pub assists: i32,
+    pub flash_assists: i32,
+    pub headshot_kills: i32,
+    pub damage: i32,
+    pub adr: f32,
+    pub kast: f32,
+    pub opening_kills: i32,
+    pub opening_deaths: i32,
+    pub traded_deaths: i32,
+    pub utility_damage: i32,
+    // Number of rounds with exactly 1, 2, 3, 4 and 5 kills
+    pub multi_kills: [i32; 5],
+    pub rating: f32,
+}
+
+fn round_entry(stats: &mut BTreeMap<(i32, u64), PlayerRoundStats>, round: i32, steamid: u64) -> &mut PlayerRoundStats {
+    stats.entry((round, steamid)).or_insert_with(|| PlayerRoundStats {
+        round,
+        steamid,
+        name: None,
+        team_num: None,
+        kills: 0,
+        deaths: 0,
+        assists: 0,
+        flash_assists: 0,
+        headshot_kills: 0,
+        damage: 0,
+        utility_damage: 0,
+        opening_kill: false,
+        opening_death: false,
+        traded_death: false,
+        kast: false,
+    })
+}
+
+fn set_identity(entry: &mut PlayerRoundStats, name: Option<String>, team_num: Option<i32>) {
+    if entry.name.is_none() {
+        entry.name = name;
+    }
+    if entry.team_num.is_none() {
+        entry.team_num = team_num;
+    }
+}
+
+pub fn player_round_stats(events: &[GameEvent], tick_interval: f32) -> Vec<PlayerRoundStats> {
+    let trade_window_ticks = seconds_to_ticks(TRADE_WINDOW_SECONDS, tick_interval);
+    let rounds = rounds_from_events(events);
+    let kills = kills_from_events(events, &rounds);
+    let mut stats: BTreeMap<(i32, u64), PlayerRoundStats> = BTreeMap::default();
+
+    // Everyone who spawned in a round played it
+    for player in round_rosters(events, &rounds) {
+        set_identity(round_entry(&mut stats, player.round, player.steamid), player.name, Some(player.team_num));
+    }
+
+    // Damage, capped at the health the victim had left
+    let mut health_left: AHashMap<(i32, u64), i32> = AHashMap::default();
+    let mut hurts: Vec<&GameEvent> = events.iter().filter(|e| e.name == "player_hurt" && !is_warmup(e)).collect();
+    hurts.sort_by_key(|e| e.tick);
+    for event in hurts {
+        let (round, victim) = match (round_for_tick(&rounds, event.tick), find_u64(event, "user_steamid")) {
+            (Some(r), Some(v)) => (r, v),
+            _ => continue,
+        };
+        let dmg = find_i32(event, "dmg_health").unwrap_or(0);
+        let prev_health = health_left.entry((round, victim)).or_insert(STARTING_HEALTH);
+        let capped = dmg.min(*prev_health).max(0);
+        *prev_health = find_i32(event, "health").unwrap_or(*prev_health - capped);
+
+        let victim_team = find_i32(event, "user_team_num");
+        set_identity(round_entry(&mut stats, round, victim), find_string(event, "user_name"), victim_team);
+        let attacker = match find_u64(event, "attacker_steamid") {
+            Some(a) if a != victim => a,
+            _ => continue,
+        };
+        let attacker_team = find_i32(event, "attacker_team_num");
+        if attacker_team == victim_team {
+            continue;
+        }
+        let entry = round_entry(&mut stats, round, attacker);
+        set_identity(entry, find_string(event, "attacker_name"), attacker_team);
+        entry.damage += capped;
+        if let Some(weapon) = find_string(event, "weapon") {
+            if UTILITY_WEAPONS.contains(&weapon.as_str()) {
+                entry.utility_damage += capped;
+            }
+        }
+    }
+
+    let mut opening_kill_seen: Vec<i32> = vec![];
+    for kill in &kills {
+        // Suicides and team kills don't open the round
+        let is_opening = kill.is_enemy_kill() && !opening_kill_seen.contains(&kill.round);
+        if is_opening {
+            opening_kill_seen.push(kill.round);
+        }
+        let traded = find_trade(kill, &kills, trade_window_ticks).is_some();
+        let victim = round_entry(&mut stats, kill.round, kill.victim);
+        set_identity(victim, kill.victim_name.clone(), kill.victim_team);
+        victim.deaths += 1;
+        victim.traded_death |= traded;
+        victim.opening_death |= is_opening;
+
+        if let (true, Some(attacker)) = (kill.is_enemy_kill(), kill.attacker) {
+            let entry = round_entry(&mut stats, kill.round, attacker);
+            set_identity(entry, kill.attacker_name.clone(), kill.attacker_team);
+            entry.kills += 1;
+            entry.opening_kill |= is_opening;
+            if kill.headshot {
+                entry.headshot_kills += 1;
+            }
+        }
+        if let Some(assister) = kill.assister {
+            let entry = round_entry(&mut stats, kill.round, assister);
+            if kill.flash_assist {
+                entry.flash_assists += 1;
+            } else {
+                entry.assists += 1;
+            }
+        }
+    }
+
+    for entry in stats.values_mut() {
+        entry.kast = entry.kills > 0 || entry.assists > 0 || entry.flash_assists > 0 || entry.deaths == 0 || entry.traded_death;
+    }
+    stats.into_values().collect()
+}
+
+pub fn player_stats(round_stats: &[PlayerRoundStats]) -> Vec<PlayerStats> {
+    let mut per_player: BTreeMap<u64, Vec<&PlayerRoundStats>> = BTreeMap::default();
+    for rs in round_stats {
+        per_player.entry(rs.steamid).or_default().push(rs);
+    }
+    let mut out = vec![];
+    for (steamid, rounds) in per_player {
+        let n_rounds = rounds.len() as i32;
+        let sum = |f: fn(&PlayerRoundStats) -> i32| rounds.iter().map(|r| f(r)).sum::<i32>();
+        let kills = sum(|r| r.kills);
+        let deaths = sum(|r| r.deaths);
+        let damage = sum(|r| r.damage);
+        let mut multi_kills = [0; 5];
+        for r in &rounds {
+            if r.kills > 0 {
+                multi_kills[(r.kills.min(5) - 1) as usize] += 1;
+            }
+        }
+        out.push(PlayerStats {
+            steamid,
+            name: rounds.iter().rev().find_map(|r| r.name.clone()),
+            rounds: n_rounds,
+            kills,
+            deaths,
+            assists: sum(|r| r.assists),
+            flash_assists: sum(|r| r.flash_assists),
+            headshot_kills: sum(|r| r.headshot_kills),
+            damage,
+            adr: damage as f32 / n_rounds as f32,
+            kast: sum(|r| r.kast as i32) as f32 / n_rounds as f32 * 100.0,
+            opening_kills: sum(|r| r.opening_kill as i32),
+            opening_deaths: sum(|r| r.opening_death as i32),
+            traded_deaths: sum(|r| r.traded_death as i32),
+            utility_damage: sum(|r| r.utility_damage),
+            multi_kills,
+            rating: hltv_rating(n_rounds, kills, deaths, &multi_kills),
+        });
+    }
+    out
+}
+
+// HLTV rating 1.0
+// https://www.hltv.org/news/15023/introducing-the-hltvorg-rating
+pub fn hltv_rating(rounds: i32, kills: i32, deaths: i32, multi_kills: &[i32; 5]) -> f32 {
+    if rounds == 0 {
+        return 0.0;
+    }
+    let rounds = rounds as f32;
+    let kill_rating = kills as f32 / rounds / 0.679;
+    let survival_rating = (rounds - deaths as f32) / rounds / 0.317;
+    let multi_kill_score: i32 = multi_kills.iter().enumerate().map(|(idx, n)| ((idx + 1) * (idx + 1)) as i32 * n).sum();
+    let multi_kill_rating = multi_kill_score as f32 / rounds / 1.277;
+    (kill_rating + 0.7 * survival_rating + multi_kill_rating) / 2.7
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_helpers::event;
+    use crate::analysis::test_helpers::hurt;
+    use crate::second_pass::game_events::EventField;
+    use crate::second_pass::variants::Variant;
+
+    fn death(tick: i32, attacker: u64, attacker_team: i32, victim: u64, victim_team: i32) -> GameEvent {
+        event(
+            "player_death",
+            tick,
+            vec![
+                ("attacker_steamid", Variant::U64(attacker)),
+                ("attacker_team_num", Variant::I32(attacker_team)),
+                ("user_steamid", Variant::U64(victim)),
+                ("user_team_num", Variant::I32(victim_team)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_opening_duel_ignores_suicides_and_team_kills() {
+        let events = vec![
+            event("round_start", 0, vec![("round", Variant::I32(1))]),
+            // Suicide
+            death(100, 1, 2, 1, 2),
+            // Team kill
+            death(200, 2, 2, 3, 2),
+            death(300, 4, 3, 5, 2),
+        ];
+        let stats = player_round_stats(&events, 1.0 / 64.0);
+        let opening: Vec<(u64, bool, bool)> = stats
+            .iter()
+            .filter(|s| s.opening_kill || s.opening_death)
+            .map(|s| (s.steamid, s.opening_kill, s.opening_death))
+            .collect();
+        assert_eq!(opening, vec![(4, true, false), (5, false, true)]);
+    }
+
+    // player_hurt by player 1 leaving the victim at health
+    fn hurt_to(tick: i32, victim: &str, dmg: i32, health: i32) -> GameEvent {
+        let mut hurt = hurt("player_hurt", tick, victim, 3, "ak47", dmg);
+        hurt.fields.push(EventField {
+            name: "health".to_string(),
+            data: Some(Variant::I32(health)),
+        });
+        hurt
+    }
+
+    #[test]
+    fn test_adr_kast_and_multi_kills() {
+        let events = vec![
+            event("round_start", 0, vec![("round", Variant::I32(1))]),
+            hurt_to(100, "10", 80, 20),
+            // Overkill, only the 20 health left counts
+            hurt_to(110, "10", 90, 0),
+            death(110, 1, 2, 10, 3),
+            death(200, 1, 2, 11, 3),
+            // Player 2 dies but is traded by player 1
+            death(300, 12, 3, 2, 2),
+            death(400, 1, 2, 12, 3),
+            event("round_start", 1000, vec![("round", Variant::I32(2))]),
+            hurt_to(1100, "12", 50, 50),
+            death(1200, 1, 2, 12, 3),
+        ];
+        let round_stats = player_round_stats(&events, 1.0 / 64.0);
+        let player_2 = round_stats.iter().find(|s| s.steamid == 2).unwrap();
+        assert!(player_2.traded_death && player_2.kast);
+
+        let stats = player_stats(&round_stats);
+        let player_1 = stats.iter().find(|s| s.steamid == 1).unwrap();
+        assert_eq!((player_1.rounds, player_1.damage, player_1.adr), (2, 150, 75.0));
+        assert_eq!(player_1.kills, 4);
+        // Round 1 with three kills, round 2 with one
+        assert_eq!(player_1.multi_kills, [1, 0, 1, 0, 0]);
+        assert_eq!(player_1.kast, 100.0);
+    }
+
+    #[test]
+    fn test_hltv_rating() {
+        // Exactly average: 0.679 kpr, 0.317 survival, 1.277 multi kill rating
+        let rating = hltv_rating(1000, 679, 683, &[1277, 0, 0, 0, 0]);
+        assert!((rating - 1.0).abs() < 0.001);
+        assert_eq!(hltv_rating(0, 0, 0, &[0; 5]), 0.0);
+    }
+}
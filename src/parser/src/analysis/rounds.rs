@@ -0,0 +1,190 @@
+use super::find_i32;
+use super::find_string;
+use super::find_u64;
+use super::is_warmup;
+use crate::second_pass::game_events::GameEvent;
+use std::collections::BTreeMap;
+
+// Builds one row per round from the (custom) round events.
+// The events need to be parsed with ROUND_EVENTS and ROUND_OTHER_PROPS as wanted events/props.
+
+pub const ROUND_EVENTS: &[&str] = &["round_start", "round_freeze_end", "round_end", "round_officially_ended"];
+pub const ROUND_OTHER_PROPS: &[&str] = &["team_rounds_total", "team_clan_name", "overtime_playing"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundRecord {
+    pub round: i32,
+    pub start_tick: Option<i32>,
+    pub freeze_end_tick: Option<i32>,
+    pub end_tick: Option<i32>,
+    pub officially_ended_tick: Option<i32>,
+    pub winner: Option<String>,
+    pub reason: Option<String>,
+    pub t_score: Option<i32>,
+    pub ct_score: Option<i32>,
+    pub t_name: Option<String>,
+    pub ct_name: Option<String>,
+    pub is_overtime: bool,
+}
+
+impl RoundRecord {
+    pub(crate) fn new(round: i32) -> Self {
+        RoundRecord {
+            round,
+            start_tick: None,
+            freeze_end_tick: None,
+            end_tick: None,
+            officially_ended_tick: None,
+            winner: None,
+            reason: None,
+            t_score: None,
+            ct_score: None,
+            t_name: None,
+            ct_name: None,
+            is_overtime: false,
+        }
+    }
+}
+
+pub fn rounds_from_events(events: &[GameEvent]) -> Vec<RoundRecord> {
+    let mut events: Vec<&GameEvent> = events.iter().filter(|e| ROUND_EVENTS.contains(&e.name.as_str())).collect();
+    events.sort_by_key(|e| e.tick);
+
+    let mut rounds: BTreeMap<i32, RoundRecord> = BTreeMap::default();
+    let mut current_round: Option<i32> = None;
+
+    for event in events {
+        match event.name.as_str() {
+            "round_start" => {
+                let round = match find_i32(event, "round") {
+                    Some(r) => r,
+                    None => continue,
+                };
+                // Round restarts (warmup, match medic etc.) start the round over
+                let mut record = RoundRecord::new(round);
+                record.start_tick = Some(event.tick);
+                rounds.insert(round, record);
+                current_round = Some(round);
+            }
+            "round_freeze_end" => {
+                if let Some(record) = current_round.and_then(|r| rounds.get_mut(&r)) {
+                    if record.end_tick.is_none() {
+                        record.freeze_end_tick = Some(event.tick);
+                    }
+                }
+            }
+            "round_end" => {
+                // "round" in round_end is derived from m_nRoundEndCount, prefer the round we saw start
+                let round = match current_round.or(find_i32(event, "round")) {
+                    Some(r) => r,
+                    None => continue,
+                };
+                let record = rounds.entry(round).or_insert_with(|| RoundRecord::new(round));
+                record.end_tick = Some(event.tick);
+                record.winner = find_string(event, "winner");
+                record.reason = find_string(event, "reason");
+                record.t_score = find_i32(event, "t_team_rounds_total");
+                record.ct_score = find_i32(event, "ct_team_rounds_total");
+                record.t_name = find_string(event, "t_team_clan_name");
+                record.ct_name = find_string(event, "ct_team_clan_name");
+                record.is_overtime = find_i32(event, "overtime_playing").unwrap_or(0) > 0;
+                current_round = Some(round);
+            }
+            "round_officially_ended" => {
+                if let Some(record) = rounds.values_mut().rev().find(|r| r.end_tick.is_some()) {
+                    if record.officially_ended_tick.is_none() {
+                        record.officially_ended_tick = Some(event.tick);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    rounds.into_values().collect()
+}
+
+// Round the tick belongs to. Rounds last from their start until the next round starts.
+pub fn round_for_tick(rounds: &[RoundRecord], tick: i32) -> Option<i32> {
+    rounds
+        .iter()
+        .filter(|r| r.start_tick.is_some_and(|start| start <= tick))
+        .max_by_key(|r| r.start_tick)
+        .map(|r| r.round)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RosterEntry {
+    pub round: i32,
+    pub steamid: u64,
+    pub name: Option<String>,
+    pub team_num: i32,
+}
+
+// Players that spawned on T or CT in each round, from player_spawn events with "team_num" as a player prop.
+pub fn round_rosters(events: &[GameEvent], rounds: &[RoundRecord]) -> Vec<RosterEntry> {
+    let mut rosters: BTreeMap<(i32, u64), RosterEntry> = BTreeMap::default();
+    for event in events.iter().filter(|e| e.name == "player_spawn" && !is_warmup(e)) {
+        let (round, steamid, team_num) = match (
+            round_for_tick(rounds, event.tick),
+            find_u64(event, "user_steamid"),
+            find_i32(event, "user_team_num"),
+        ) {
+            (Some(r), Some(s), Some(t)) => (r, s, t),
+            _ => continue,
+        };
+        // Spectators and unassigned
+        if team_num < 2 {
+            continue;
+        }
+        rosters.insert(
+            (round, steamid),
+            RosterEntry {
+                round,
+                steamid,
+                name: find_string(event, "user_name"),
+                team_num,
+            },
+        );
+    }
+    rosters.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_helpers::event;
+    use crate::second_pass::variants::Variant;
+
+    #[test]
+    fn test_rounds_from_events() {
+        let events = vec![
+            event("round_start", 100, vec![("round", Variant::I32(1))]),
+            // Restart after warmup
+            event("round_start", 200, vec![("round", Variant::I32(1))]),
+            event("round_freeze_end", 1480, vec![]),
+            event(
+                "round_end",
+                5000,
+                vec![
+                    ("round", Variant::U32(1)),
+                    ("winner", Variant::String("CT".to_string())),
+                    ("reason", Variant::String("t_killed".to_string())),
+                    ("t_team_rounds_total", Variant::I32(0)),
+                    ("ct_team_rounds_total", Variant::I32(1)),
+                ],
+            ),
+            event("round_officially_ended", 5448, vec![]),
+            event("round_start", 5448, vec![("round", Variant::I32(2))]),
+        ];
+        let rounds = rounds_from_events(&events);
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[0].start_tick, Some(200));
+        assert_eq!(rounds[0].freeze_end_tick, Some(1480));
+        assert_eq!(rounds[0].end_tick, Some(5000));
+        assert_eq!(rounds[0].officially_ended_tick, Some(5448));
+        assert_eq!(rounds[0].winner, Some("CT".to_string()));
+        assert_eq!(rounds[0].ct_score, Some(1));
+        assert_eq!(rounds[1].start_tick, Some(5448));
+        assert_eq!(rounds[1].end_tick, None);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::analysis::trades::Kill;
+use crate::second_pass::game_events::EventField;
+use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::variants::Variant;
+
+// Fixtures shared by the analysis tests
+
+pub(crate) fn event(name: &str, tick: i32, fields: Vec<(&str, Variant)>) -> GameEvent {
+    GameEvent {
+        name: name.to_string(),
+        tick,
+        fields: fields
+            .into_iter()
+            .map(|(name, data)| EventField {
+                name: name.to_string(),
+                data: Some(data),
+            })
+            .collect(),
+    }
+}
+
+// player_hurt / player_death caused by player "1" on team 2
+pub(crate) fn hurt(name: &str, tick: i32, victim: &str, victim_team: i32, weapon: &str, dmg: i32) -> GameEvent {
+    event(
+        name,
+        tick,
+        vec![
+            ("attacker_steamid", Variant::String("1".to_string())),
+            ("attacker_team_num", Variant::I32(2)),
+            ("user_steamid", Variant::String(victim.to_string())),
+            ("user_team_num", Variant::I32(victim_team)),
+            ("weapon", Variant::String(weapon.to_string())),
+            ("dmg_health", Variant::I32(dmg)),
+        ],
+    )
+}
+
+pub(crate) fn kill(tick: i32, attacker: u64, attacker_team: i32, victim: u64, victim_team: i32) -> Kill {
+    Kill {
+        tick,
+        round: 1,
+        attacker: Some(attacker),
+        attacker_name: None,
+        attacker_team: Some(attacker_team),
+        victim,
+        victim_name: None,
+        victim_team: Some(victim_team),
+        assister: None,
+        flash_assist: false,
+        headshot: false,
+        weapon: None,
+    }
+}
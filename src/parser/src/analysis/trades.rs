@@ -0,0 +1,135 @@
+use super::find_bool;
+use super::find_i32;
+use super::find_string;
+use super::find_u64;
+use super::is_warmup;
+use crate::analysis::rounds::round_for_tick;
+use crate::analysis::rounds::RoundRecord;
+use crate::second_pass::game_events::GameEvent;
+
+// Kills (player_death events mapped onto the rounds table) and trade kills.
+// A trade kill is when a killer dies to a teammate of their victim shortly after.
+
+pub const TRADES_EVENTS: &[&str] = &["round_start", "round_freeze_end", "round_end", "round_officially_ended", "player_death"];
+pub const TRADES_PLAYER_PROPS: &[&str] = &["team_num"];
+pub const TRADES_OTHER_PROPS: &[&str] = &["team_rounds_total", "team_clan_name", "overtime_playing", "is_warmup_period"];
+
+// Default window for a death to count as traded
+pub const TRADE_WINDOW_SECONDS: f32 = 5.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kill {
+    pub tick: i32,
+    pub round: i32,
+    pub attacker: Option<u64>,
+    pub attacker_name: Option<String>,
+    pub attacker_team: Option<i32>,
+    pub victim: u64,
+    pub victim_name: Option<String>,
+    pub victim_team: Option<i32>,
+    pub assister: Option<u64>,
+    pub flash_assist: bool,
+    pub headshot: bool,
+    pub weapon: Option<String>,
+}
+
+impl Kill {
+    // Excludes suicides, world damage and team kills
+    pub fn is_enemy_kill(&self) -> bool {
+        self.attacker.is_some() && self.attacker != Some(self.victim) && self.attacker_team != self.victim_team
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TradeKill {
+    pub round: i32,
+    pub tick: i32,
+    // Player who got the trade kill
+    pub steamid: Option<u64>,
+    pub name: Option<String>,
+    // Killer that was traded
+    pub traded_steamid: Option<u64>,
+    pub traded_name: Option<String>,
+    // Teammate whose death was avenged
+    pub victim_steamid: u64,
+    pub victim_name: Option<String>,
+    pub victim_death_tick: i32,
+    pub ticks_after_death: i32,
+}
+
+pub fn kills_from_events(events: &[GameEvent], rounds: &[RoundRecord]) -> Vec<Kill> {
+    let mut kills: Vec<Kill> = events
+        .iter()
+        .filter(|e| e.name == "player_death" && !is_warmup(e))
+        .filter_map(|e| {
+            Some(Kill {
+                tick: e.tick,
+                round: round_for_tick(rounds, e.tick)?,
+                attacker: find_u64(e, "attacker_steamid"),
+                attacker_name: find_string(e, "attacker_name"),
+                attacker_team: find_i32(e, "attacker_team_num"),
+                victim: find_u64(e, "user_steamid")?,
+                victim_name: find_string(e, "user_name"),
+                victim_team: find_i32(e, "user_team_num"),
+                assister: find_u64(e, "assister_steamid"),
+                flash_assist: find_bool(e, "assistedflash").unwrap_or(false),
+                headshot: find_bool(e, "headshot").unwrap_or(false),
+                weapon: find_string(e, "weapon"),
+            })
+        })
+        .collect();
+    kills.sort_by_key(|k| k.tick);
+    kills
+}
+
+// The kill that avenges the victim of "kill", if any
+pub fn find_trade<'a>(kill: &Kill, kills: &'a [Kill], window_ticks: i32) -> Option<&'a Kill> {
+    if !kill.is_enemy_kill() {
+        return None;
+    }
+    let attacker = kill.attacker?;
+    kills
+        .iter()
+        .find(|k| k.victim == attacker && k.is_enemy_kill() && k.attacker_team == kill.victim_team && k.tick >= kill.tick && k.tick - kill.tick <= window_ticks)
+}
+
+pub fn trade_kills(kills: &[Kill], window_ticks: i32) -> Vec<TradeKill> {
+    kills
+        .iter()
+        .filter_map(|kill| {
+            let trade = find_trade(kill, kills, window_ticks)?;
+            Some(TradeKill {
+                round: trade.round,
+                tick: trade.tick,
+                steamid: trade.attacker,
+                name: trade.attacker_name.clone(),
+                traded_steamid: kill.attacker,
+                traded_name: kill.attacker_name.clone(),
+                victim_steamid: kill.victim,
+                victim_name: kill.victim_name.clone(),
+                victim_death_tick: kill.tick,
+                ticks_after_death: trade.tick - kill.tick,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::seconds_to_ticks;
+    use crate::analysis::test_helpers::kill;
+
+    #[test]
+    fn test_find_trade() {
+        let kills = vec![kill(100, 1, 2, 10, 3), kill(200, 11, 3, 1, 2), kill(1000, 2, 2, 11, 3)];
+        let window = seconds_to_ticks(TRADE_WINDOW_SECONDS, 1.0 / 64.0);
+        // Player 11 avenged player 10 within the window
+        assert_eq!(find_trade(&kills[0], &kills, window), Some(&kills[1]));
+        // Player 11 was killed too late to count as a trade
+        assert_eq!(find_trade(&kills[1], &kills, window), None);
+        // A wider window catches it
+        assert_eq!(find_trade(&kills[1], &kills, 1000), Some(&kills[2]));
+        assert_eq!(trade_kills(&kills, window).len(), 1);
+    }
+}
@@ -0,0 +1,372 @@
+use super::find_f32;
+use super::find_i32;
+use super::find_string;
+use super::find_u64;
+use super::is_warmup;
+use super::seconds_to_ticks;
+use crate::second_pass::collect_data::ProjectileRecord;
+use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::grenade_areas::InfernoRecord;
+use ahash::AHashMap;
+use ahash::AHashSet;
+
+// Utility damage per grenade. HE damage is linked to the hegrenade_detonate of the same
+// thrower and molotov/incendiary damage to the inferno entity (inferno_startburn) of the
+// thrower that was burning at the time. Damage events don't say which grenade did the
+// damage, so when the thrower has several active (two molotovs burning at once) the one
+// closest to the victim gets it: the fires of the inferno on that tick, or the detonation
+// point. Throws come from the projectile records.
+
+pub const UTILITY_DAMAGE_EVENTS: &[&str] = &["hegrenade_detonate", "inferno_startburn", "inferno_expire", "player_hurt", "player_death"];
+pub const UTILITY_DAMAGE_PLAYER_PROPS: &[&str] = &["team_num", "X", "Y", "Z"];
+pub const UTILITY_DAMAGE_OTHER_PROPS: &[&str] = &["is_warmup_period"];
+
+// Max ticks between a HE detonation and the damage it caused
+const HE_DAMAGE_WINDOW_TICKS: i32 = 2;
+// The projectile entity disappears right before the HE detonates or the inferno starts
+const PROJECTILE_END_WINDOW_TICKS: i32 = 4;
+// Projectile records of the same entity further apart than this many seconds are separate throws
+const THROW_GAP_SECONDS: f32 = 1.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrenadeThrow {
+    pub entity_id: i32,
+    pub grenade_type: String,
+    pub steamid: Option<u64>,
+    pub name: Option<String>,
+    pub throw_tick: i32,
+    pub last_tick: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrenadeDamage {
+    // "hegrenade" or "inferno"
+    pub grenade_type: String,
+    pub entity_id: Option<i32>,
+    pub thrower_steamid: Option<u64>,
+    pub thrower_name: Option<String>,
+    pub thrower_team: Option<i32>,
+    pub throw_tick: Option<i32>,
+    pub detonate_tick: i32,
+    // Tick the inferno expired, same as detonate_tick for HEs
+    pub end_tick: Option<i32>,
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+    pub z: Option<f32>,
+    pub damage: i32,
+    pub team_damage: i32,
+    pub enemies_hit: i32,
+    pub teammates_hit: i32,
+    pub kills: i32,
+}
+
+// Projectile records are one row per projectile per tick
+pub fn grenade_throws(projectiles: &[ProjectileRecord], tick_interval: f32) -> Vec<GrenadeThrow> {
+    let throw_gap_ticks = seconds_to_ticks(THROW_GAP_SECONDS, tick_interval);
+    let mut records: Vec<&ProjectileRecord> = projectiles.iter().filter(|p| p.entity_id.is_some() && p.tick.is_some()).collect();
+    records.sort_by_key(|p| (p.entity_id, p.tick));
+    let mut throws: Vec<GrenadeThrow> = vec![];
+    for record in records {
+        let (entity_id, tick) = (record.entity_id.unwrap_or(0), record.tick.unwrap_or(0));
+        let grenade_type = record.grenade_type.clone().unwrap_or_default();
+        if let Some(last) = throws.last_mut() {
+            if last.entity_id == entity_id && last.grenade_type == grenade_type && tick - last.last_tick <= throw_gap_ticks {
+                last.last_tick = tick;
+                continue;
+            }
+        }
+        throws.push(GrenadeThrow {
+            entity_id,
+            grenade_type,
+            steamid: record.steamid,
+            name: record.name.clone(),
+            throw_tick: tick,
+            last_tick: tick,
+        });
+    }
+    throws
+}
+
+fn new_grenade(grenade_type: &str, event: &GameEvent) -> GrenadeDamage {
+    GrenadeDamage {
+        grenade_type: grenade_type.to_string(),
+        entity_id: find_i32(event, "entityid"),
+        thrower_steamid: find_u64(event, "user_steamid"),
+        thrower_name: find_string(event, "user_name"),
+        thrower_team: find_i32(event, "user_team_num"),
+        throw_tick: None,
+        detonate_tick: event.tick,
+        end_tick: None,
+        x: find_f32(event, "x"),
+        y: find_f32(event, "y"),
+        z: find_f32(event, "z"),
+        damage: 0,
+        team_damage: 0,
+        enemies_hit: 0,
+        teammates_hit: 0,
+        kills: 0,
+    }
+}
+
+// Fire positions per (inferno entity id, tick)
+fn fires_by_tick(infernos: &[InfernoRecord]) -> AHashMap<(i32, i32), Vec<[f32; 3]>> {
+    let mut fires: AHashMap<(i32, i32), Vec<[f32; 3]>> = AHashMap::default();
+    for record in infernos {
+        if let (Some(entity_id), Some(tick), Some(x), Some(y), Some(z)) = (record.entity_id, record.tick, record.x, record.y, record.z) {
+            fires.entry((entity_id, tick)).or_default().push([x, y, z]);
+        }
+    }
+    fires
+}
+
+fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+// How far the victim was from the grenade: the closest fire of the inferno or the detonation point
+fn grenade_distance(grenade: &GrenadeDamage, fires: &AHashMap<(i32, i32), Vec<[f32; 3]>>, tick: i32, victim: [f32; 3]) -> f32 {
+    let fire_distance = grenade
+        .entity_id
+        .and_then(|entity_id| fires.get(&(entity_id, tick)))
+        .and_then(|positions| positions.iter().map(|p| distance(*p, victim)).min_by(|a, b| a.total_cmp(b)));
+    match (fire_distance, grenade.x, grenade.y, grenade.z) {
+        (Some(d), _, _, _) => d,
+        (None, Some(x), Some(y), Some(z)) => distance([x, y, z], victim),
+        _ => f32::MAX,
+    }
+}
+
+fn find_grenade(
+    grenades: &[GrenadeDamage],
+    fires: &AHashMap<(i32, i32), Vec<[f32; 3]>>,
+    weapon: &str,
+    attacker: u64,
+    tick: i32,
+    victim: Option<[f32; 3]>,
+) -> Option<usize> {
+    let candidates: Vec<(usize, &GrenadeDamage)> = grenades
+        .iter()
+        .enumerate()
+        .filter(|(_, g)| g.thrower_steamid == Some(attacker))
+        .filter(|(_, g)| match weapon {
+            "hegrenade" => g.grenade_type == "hegrenade" && (g.detonate_tick - tick).abs() <= HE_DAMAGE_WINDOW_TICKS,
+            "inferno" => g.grenade_type == "inferno" && g.detonate_tick <= tick && g.end_tick.is_none_or(|end| tick <= end),
+            _ => false,
+        })
+        .collect();
+    if let (Some(victim), true) = (victim, candidates.len() > 1) {
+        return candidates
+            .iter()
+            .min_by(|a, b| grenade_distance(a.1, fires, tick, victim).total_cmp(&grenade_distance(b.1, fires, tick, victim)))
+            .map(|(idx, _)| *idx);
+    }
+    match weapon {
+        "hegrenade" => candidates.iter().min_by_key(|(_, g)| (g.detonate_tick - tick).abs()).map(|(idx, _)| *idx),
+        _ => candidates.iter().max_by_key(|(_, g)| g.detonate_tick).map(|(idx, _)| *idx),
+    }
+}
+
+pub fn utility_damage(events: &[GameEvent], throws: &[GrenadeThrow], infernos: &[InfernoRecord]) -> Vec<GrenadeDamage> {
+    let events: Vec<&GameEvent> = events.iter().filter(|e| !is_warmup(e)).collect();
+    let mut grenades: Vec<GrenadeDamage> = vec![];
+    for event in &events {
+        match event.name.as_str() {
+            "hegrenade_detonate" => {
+                let mut grenade = new_grenade("hegrenade", event);
+                grenade.end_tick = Some(event.tick);
+                grenade.throw_tick = throws
+                    .iter()
+                    .find(|t| {
+                        Some(t.entity_id) == grenade.entity_id
+                            && t.grenade_type == "he_grenade"
+                            && t.throw_tick <= event.tick
+                            && event.tick - t.last_tick <= PROJECTILE_END_WINDOW_TICKS
+                    })
+                    .map(|t| t.throw_tick);
+                grenades.push(grenade);
+            }
+            "inferno_startburn" => {
+                let mut grenade = new_grenade("inferno", event);
+                // The inferno entity knows its owner even when the event does not
+                if let Some(record) = infernos
+                    .iter()
+                    .find(|r| r.entity_id == grenade.entity_id && r.tick.is_some_and(|t| t >= event.tick))
+                {
+                    grenade.thrower_steamid = grenade.thrower_steamid.or(record.steamid);
+                    grenade.thrower_name = grenade.thrower_name.clone().or(record.name.clone());
+                }
+                let throw = throws
+                    .iter()
+                    .filter(|t| {
+                        (t.grenade_type == "molotov" || t.grenade_type == "incendiary_grenade")
+                            && t.steamid.is_some()
+                            && t.steamid == grenade.thrower_steamid
+                            && t.last_tick <= event.tick
+                            && event.tick - t.last_tick <= PROJECTILE_END_WINDOW_TICKS
+                    })
+                    .max_by_key(|t| t.last_tick);
+                grenade.throw_tick = throw.map(|t| t.throw_tick);
+                grenades.push(grenade);
+            }
+            "inferno_expire" => {
+                let entity_id = find_i32(event, "entityid");
+                if let Some(grenade) = grenades
+                    .iter_mut()
+                    .rev()
+                    .find(|g| g.grenade_type == "inferno" && g.entity_id == entity_id && g.end_tick.is_none())
+                {
+                    grenade.end_tick = Some(event.tick);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let fires = fires_by_tick(infernos);
+    let mut hit: AHashSet<(usize, u64)> = AHashSet::default();
+    for event in &events {
+        if event.name != "player_hurt" && event.name != "player_death" {
+            continue;
+        }
+        let (attacker, victim, weapon) = match (
+            find_u64(event, "attacker_steamid"),
+            find_u64(event, "user_steamid"),
+            find_string(event, "weapon"),
+        ) {
+            (Some(attacker), Some(victim), Some(weapon)) if attacker != victim => (attacker, victim, weapon),
+            _ => continue,
+        };
+        let victim_pos = match (find_f32(event, "user_X"), find_f32(event, "user_Y"), find_f32(event, "user_Z")) {
+            (Some(x), Some(y), Some(z)) => Some([x, y, z]),
+            _ => None,
+        };
+        let idx = match find_grenade(&grenades, &fires, &weapon, attacker, event.tick, victim_pos) {
+            Some(idx) => idx,
+            None => continue,
+        };
+        let attacker_team = find_i32(event, "attacker_team_num");
+        let grenade = &mut grenades[idx];
+        if grenade.thrower_team.is_none() {
+            grenade.thrower_team = attacker_team;
+        }
+        let team_hit = attacker_team.is_some() && attacker_team == find_i32(event, "user_team_num");
+        if event.name == "player_death" {
+            grenade.kills += !team_hit as i32;
+            continue;
+        }
+        let dmg = find_i32(event, "dmg_health").unwrap_or(0);
+        let first_hit = hit.insert((idx, victim));
+        if team_hit {
+            grenade.team_damage += dmg;
+            grenade.teammates_hit += first_hit as i32;
+        } else {
+            grenade.damage += dmg;
+            grenade.enemies_hit += first_hit as i32;
+        }
+    }
+    grenades
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_helpers::event;
+    use crate::analysis::test_helpers::hurt;
+    use crate::second_pass::game_events::EventField;
+    use crate::second_pass::variants::Variant;
+
+    fn projectile(entity_id: i32, tick: i32, grenade_type: &str) -> ProjectileRecord {
+        ProjectileRecord {
+            steamid: Some(1),
+            name: None,
+            x: None,
+            y: None,
+            z: None,
+            tick: Some(tick),
+            grenade_type: Some(grenade_type.to_string()),
+            entity_id: Some(entity_id),
+        }
+    }
+
+    #[test]
+    fn test_utility_damage() {
+        let projectiles: Vec<ProjectileRecord> = (10..=20)
+            .map(|t| projectile(150, t, "he_grenade"))
+            .chain((30..=40).map(|t| projectile(151, t, "molotov")))
+            .collect();
+        let throws = grenade_throws(&projectiles, 1.0 / 64.0);
+        assert_eq!(throws.len(), 2);
+        let thrower = || vec![("user_steamid", Variant::String("1".to_string())), ("user_team_num", Variant::I32(2))];
+        let mut detonate = thrower();
+        detonate.push(("entityid", Variant::I32(150)));
+        let mut startburn = thrower();
+        startburn.push(("entityid", Variant::I32(300)));
+        let events = vec![
+            event("hegrenade_detonate", 21, detonate),
+            hurt("player_hurt", 21, "2", 3, "hegrenade", 40),
+            hurt("player_hurt", 21, "3", 2, "hegrenade", 10),
+            event("inferno_startburn", 42, startburn),
+            hurt("player_hurt", 50, "2", 3, "inferno", 8),
+            hurt("player_hurt", 60, "2", 3, "inferno", 8),
+            hurt("player_death", 60, "2", 3, "inferno", 0),
+            event("inferno_expire", 400, vec![("entityid", Variant::I32(300))]),
+            hurt("player_hurt", 500, "2", 3, "inferno", 8),
+        ];
+        let grenades = utility_damage(&events, &throws, &[]);
+        assert_eq!(grenades.len(), 2);
+        let he = &grenades[0];
+        assert_eq!(
+            (he.throw_tick, he.damage, he.team_damage, he.enemies_hit, he.teammates_hit),
+            (Some(10), 40, 10, 1, 1)
+        );
+        let molotov = &grenades[1];
+        assert_eq!((molotov.throw_tick, molotov.end_tick), (Some(30), Some(400)));
+        assert_eq!((molotov.damage, molotov.enemies_hit, molotov.kills), (16, 1, 1));
+    }
+
+    #[test]
+    fn test_damage_goes_to_the_closest_inferno() {
+        // Two fires burning at once from the same thrower, the second one is a CT incendiary
+        let projectiles: Vec<ProjectileRecord> = (30..=40)
+            .map(|t| projectile(151, t, "molotov"))
+            .chain((33..=45).map(|t| projectile(152, t, "incendiary_grenade")))
+            .collect();
+        let throws = grenade_throws(&projectiles, 1.0 / 64.0);
+        let startburn = |entity_id: i32, x: f32| {
+            vec![
+                ("user_steamid", Variant::String("1".to_string())),
+                ("entityid", Variant::I32(entity_id)),
+                ("x", Variant::F32(x)),
+                ("y", Variant::F32(0.0)),
+                ("z", Variant::F32(0.0)),
+            ]
+        };
+        let fire = |entity_id: i32, x: f32| InfernoRecord {
+            entity_id: Some(entity_id),
+            tick: Some(50),
+            steamid: Some(1),
+            name: None,
+            fire_idx: Some(0),
+            x: Some(x),
+            y: Some(0.0),
+            z: Some(0.0),
+        };
+        let infernos = vec![fire(300, 0.0), fire(301, 1000.0)];
+        let mut near_second = hurt("player_hurt", 50, "2", 3, "inferno", 8);
+        for (axis, value) in [("user_X", 990.0), ("user_Y", 0.0), ("user_Z", 0.0)] {
+            near_second.fields.push(EventField {
+                name: axis.to_string(),
+                data: Some(Variant::F32(value)),
+            });
+        }
+        let events = vec![
+            event("inferno_startburn", 41, startburn(300, 0.0)),
+            event("inferno_startburn", 46, startburn(301, 1000.0)),
+            near_second,
+        ];
+        let grenades = utility_damage(&events, &throws, &infernos);
+        assert_eq!(grenades.len(), 2);
+        assert_eq!((grenades[0].throw_tick, grenades[0].damage), (Some(30), 0));
+        assert_eq!((grenades[1].throw_tick, grenades[1].damage), (Some(33), 8));
+    }
+}
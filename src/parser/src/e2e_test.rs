@@ -313,6 +313,12 @@ pub fn _create_ge_tests() {
         only_convars: false,
         huffman_lookup_table: &huf,
         order_by_steamid: false,
+        parse_economy: false,
+        parse_visibility: false,
+        parse_ticks_with_events: false,
+        parse_shot_details: false,
+        wanted_net_messages: vec![],
+        parse_string_tables: false,
         wanted_prop_states: AHashMap::default(),
     };
 
@@ -687,6 +693,12 @@ pub fn _create_tests() {
         only_convars: false,
         huffman_lookup_table: &huf,
         order_by_steamid: false,
+        parse_economy: false,
+        parse_visibility: false,
+        parse_ticks_with_events: false,
+        parse_shot_details: false,
+        wanted_net_messages: vec![],
+        parse_string_tables: false,
         wanted_prop_states: AHashMap::default(),
     };
 
@@ -1057,6 +1069,12 @@ fn create_data() -> (DemoOutput, PropController, BTreeMap<String, Vec<GameEvent>
         only_convars: false,
         huffman_lookup_table: &huf,
         order_by_steamid: false,
+        parse_economy: false,
+        parse_visibility: false,
+        parse_ticks_with_events: false,
+        parse_shot_details: false,
+        wanted_net_messages: vec![],
+        parse_string_tables: false,
         wanted_prop_states: AHashMap::default(),
     };
 
@@ -1082,6 +1100,12 @@ fn create_data() -> (DemoOutput, PropController, BTreeMap<String, Vec<GameEvent>
         only_convars: false,
         huffman_lookup_table: &huf,
         order_by_steamid: false,
+        parse_economy: false,
+        parse_visibility: false,
+        parse_ticks_with_events: false,
+        parse_shot_details: false,
+        wanted_net_messages: vec![],
+        parse_string_tables: false,
         wanted_prop_states: AHashMap::default(),
     };
     let mut ds = Parser::new(settings, crate::parse_demo::ParsingMode::ForceSingleThreaded);
@@ -1192,6 +1216,12 @@ mod tests {
             only_convars: false,
             huffman_lookup_table: &huf,
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
             wanted_prop_states: AHashMap::default(),
         };
         let mut ds = Parser::new(settings, crate::parse_demo::ParsingMode::ForceSingleThreaded);
@@ -1204,6 +1234,48 @@ mod tests {
         assert_eq!(steamids.data, Some(VarVec::U64(vec![Some(76561198244754626), Some(76561198244754626)])));
     }
 
+    #[test]
+    fn test_ticks_with_events() {
+        let huf = create_huffman_lookup_table();
+        let file = File::open("test_demo.dem").unwrap();
+        let mmap = unsafe { MmapOptions::new().map(&file).unwrap() };
+
+        for parse_ticks_with_events in [false, true] {
+            let settings = ParserInputs {
+                wanted_players: vec![76561198244754626],
+                real_name_to_og_name: AHashMap::default(),
+                wanted_player_props: vec!["X".to_string()],
+                wanted_events: vec!["player_death".to_string()],
+                wanted_other_props: vec![],
+                parse_ents: true,
+                wanted_ticks: vec![10000, 10001],
+                parse_projectiles: false,
+                only_header: false,
+                count_props: false,
+                only_convars: false,
+                huffman_lookup_table: &huf,
+                order_by_steamid: false,
+                parse_economy: false,
+                parse_visibility: false,
+                parse_ticks_with_events,
+                parse_shot_details: false,
+                wanted_net_messages: vec![],
+                parse_string_tables: false,
+                wanted_prop_states: AHashMap::default(),
+            };
+            let mut ds = Parser::new(settings, crate::parse_demo::ParsingMode::ForceSingleThreaded);
+            let output = ds.parse_demo(&mmap).unwrap();
+            assert!(!output.game_events.is_empty());
+
+            let ticks = output.df.get(&TICK_ID).and_then(|col| col.data.clone());
+            if parse_ticks_with_events {
+                assert_eq!(ticks, Some(VarVec::I32(vec![Some(10000), Some(10001)])));
+            } else {
+                assert!(!matches!(ticks, Some(VarVec::I32(v)) if !v.is_empty()));
+            }
+        }
+    }
+
     #[test]
     fn CEconItemAttribute_m_nRefundableCurrency() {
         let prop = (
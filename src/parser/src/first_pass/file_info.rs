@@ -0,0 +1,133 @@
+use crate::first_pass::parser::HEADER_ENDS_AT_BYTE;
+use crate::first_pass::read_bits::read_varint;
+use crate::first_pass::read_bits::DemoParserError;
+use crate::maps::demo_cmd_type_from_int;
+use csgoproto::CDemoFileInfo;
+use csgoproto::EDemoCommands;
+use prost::Message;
+use snap::raw::Decoder as SnapDecoder;
+
+// CDemoFileInfo is written after DEM_Stop. Bytes 8..12 of the short header hold the offset of
+// its frame, so it can be read without going trough the rest of the demo. The CS specific part
+// of the game info only has the round start ticks (no match id).
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemoFileInfo {
+    pub playback_time: Option<f32>,
+    pub playback_ticks: Option<i32>,
+    pub playback_frames: Option<i32>,
+    // CS specific game info
+    pub round_start_ticks: Vec<i32>,
+}
+
+pub fn parse_file_info(demo_bytes: &[u8]) -> Result<DemoFileInfo, DemoParserError> {
+    if demo_bytes.len() < HEADER_ENDS_AT_BYTE {
+        return Err(DemoParserError::OutOfBytesError);
+    }
+    let mut ptr = match demo_bytes[8..12].try_into() {
+        Ok(arr) => u32::from_le_bytes(arr) as usize,
+        Err(_) => return Err(DemoParserError::OutOfBytesError),
+    };
+    // Usually points straight at the fileinfo frame, but walk forward in case something comes before it
+    loop {
+        let cmd = read_varint(demo_bytes, &mut ptr)?;
+        let _tick = read_varint(demo_bytes, &mut ptr)?;
+        let size = read_varint(demo_bytes, &mut ptr)? as usize;
+        let bytes = match demo_bytes.get(ptr..ptr + size) {
+            Some(bytes) => bytes,
+            None => return Err(DemoParserError::OutOfBytesError),
+        };
+        ptr += size;
+        if demo_cmd_type_from_int((cmd & !64) as i32)? != EDemoCommands::DemFileInfo {
+            continue;
+        }
+        let decompressed;
+        let bytes = match (cmd & 64) == 64 {
+            true => {
+                decompressed = match SnapDecoder::new().decompress_vec(bytes) {
+                    Ok(bytes) => bytes,
+                    Err(e) => return Err(DemoParserError::DecompressionFailure(format!("{}", e))),
+                };
+                &decompressed[..]
+            }
+            false => bytes,
+        };
+        let info = match CDemoFileInfo::decode(bytes) {
+            Ok(info) => info,
+            Err(_) => return Err(DemoParserError::MalformedMessage),
+        };
+        return Ok(DemoFileInfo {
+            playback_time: info.playback_time,
+            playback_ticks: info.playback_ticks,
+            playback_frames: info.playback_frames,
+            round_start_ticks: info
+                .game_info
+                .and_then(|game_info| game_info.cs)
+                .map(|cs| cs.round_start_ticks)
+                .unwrap_or_default(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csgoproto::c_game_info::CcsGameInfo;
+    use csgoproto::CGameInfo;
+    use snap::raw::Encoder as SnapEncoder;
+
+    fn frame(cmd: u32, tick: u32, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![];
+        prost::encoding::encode_varint(cmd as u64, &mut bytes);
+        prost::encoding::encode_varint(tick as u64, &mut bytes);
+        prost::encoding::encode_varint(payload.len() as u64, &mut bytes);
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+    // Short header, some filler standing in for the demo, then DEM_Stop and the fileinfo frame
+    fn demo_with_file_info(compressed: bool) -> Vec<u8> {
+        let info = CDemoFileInfo {
+            playback_time: Some(1800.5),
+            playback_ticks: Some(115232),
+            playback_frames: Some(57600),
+            game_info: Some(CGameInfo {
+                dota: None,
+                cs: Some(CcsGameInfo {
+                    round_start_ticks: vec![100, 5000],
+                }),
+            }),
+        };
+        let mut cmd = EDemoCommands::DemFileInfo as u32;
+        let mut payload = info.encode_to_vec();
+        if compressed {
+            cmd |= 64;
+            payload = SnapEncoder::new().compress_vec(&payload).unwrap();
+        }
+        let mut bytes = b"PBDEMS2\0".to_vec();
+        let offset = (HEADER_ENDS_AT_BYTE + 32) as u32;
+        bytes.extend_from_slice(&offset.to_le_bytes());
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&[0; 32]);
+        bytes.extend(frame(EDemoCommands::DemStop as u32, 115232, &[]));
+        bytes.extend(frame(cmd, 115232, &payload));
+        bytes
+    }
+
+    #[test]
+    fn test_parse_file_info() {
+        for compressed in [false, true] {
+            let info = parse_file_info(&demo_with_file_info(compressed)).unwrap();
+            assert_eq!(info.playback_time, Some(1800.5));
+            assert_eq!(info.playback_ticks, Some(115232));
+            assert_eq!(info.playback_frames, Some(57600));
+            assert_eq!(info.round_start_ticks, vec![100, 5000]);
+        }
+    }
+    #[test]
+    fn test_parse_file_info_truncated() {
+        let mut bytes = demo_with_file_info(false);
+        bytes.truncate(bytes.len() - 4);
+        assert!(parse_file_info(&bytes).is_err());
+        assert!(parse_file_info(&bytes[..8]).is_err());
+    }
+}
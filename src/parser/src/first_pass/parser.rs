@@ -1,3 +1,4 @@
+use crate::first_pass::file_info::parse_file_info;
 use crate::first_pass::parser_settings::FirstPassParser;
 use crate::first_pass::parser_settings::ParserInputs;
 use crate::first_pass::prop_controller::PropController;
@@ -22,6 +23,7 @@ use csgoproto::CDemoFullPacket;
 use csgoproto::CDemoPacket;
 use csgoproto::CDemoSendTables;
 use csgoproto::CsvcMsgGameEventList;
+use csgoproto::CsvcMsgServerInfo;
 use csgoproto::EDemoCommands;
 use prost::Message;
 use snap::raw::decompress_len;
@@ -30,6 +32,8 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 pub const HEADER_ENDS_AT_BYTE: usize = 16;
+// Used until svc_ServerInfo tells the real one
+pub const DEFAULT_TICK_INTERVAL: f32 = 1.0 / 64.0;
 
 pub struct ParserThreadInput<'a> {
     pub offset: usize,
@@ -66,6 +70,7 @@ pub struct FirstPassOutput<'a> {
     pub wanted_players: AHashSet<u64>,
     pub header: AHashMap<String, String>,
     pub order_by_steamid: bool,
+    pub tick_interval: f32,
 }
 #[derive(Debug)]
 pub struct Frame {
@@ -99,7 +104,11 @@ impl<'a> FirstPassParser<'a> {
                 EDemoCommands::DemClassInfo => self.parse_class_info(bytes)?,
                 EDemoCommands::DemSignonPacket => self.parse_packet(bytes)?,
                 EDemoCommands::DemFullPacket => self.parse_full_packet(bytes, &frame)?,
-                EDemoCommands::DemStop => break,
+                EDemoCommands::DemStop => {
+                    self.insert_playback_time();
+                    self.insert_file_info(demo_bytes);
+                    break;
+                }
                 _ => {}
             };
         }
@@ -183,6 +192,7 @@ impl<'a> FirstPassParser<'a> {
         Ok(FirstPassOutput {
             order_by_steamid: self.order_by_steamid,
             header: self.header.clone(),
+            tick_interval: self.tick_interval,
             fullpacket_offsets: self.fullpacket_offsets.clone(),
             settings: &self.settings,
             baselines: self.baselines.clone(),
@@ -266,6 +276,7 @@ impl<'a> FirstPassParser<'a> {
             let ok = match NetMessageType::from(msg_type as i32) {
                 GE_Source1LegacyGameEventList => self.parse_game_event_list(&msg_bytes),
                 svc_CreateStringTable => self.parse_create_stringtable(&msg_bytes),
+                svc_ServerInfo => self.parse_server_info(&msg_bytes),
                 svc_UpdateStringTable => self.update_string_table(&msg_bytes),
                 svc_ClearAllStringTables => self.clear_stringtables(),
                 _ => Ok(()),
@@ -302,6 +313,45 @@ impl<'a> FirstPassParser<'a> {
         self.header.insert("addons".to_string(), header.addons().to_string());
         Ok(())
     }
+    pub fn parse_server_info(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
+        let server_info = match CsvcMsgServerInfo::decode(bytes) {
+            Ok(info) => info,
+            Err(_) => return Err(DemoParserError::MalformedMessage),
+        };
+        if server_info.tick_interval() > 0.0 {
+            self.tick_interval = server_info.tick_interval();
+        }
+        self.header.insert("tickrate".to_string(), (1.0 / self.tick_interval).round().to_string());
+        self.header.insert("max_clients".to_string(), server_info.max_clients().to_string());
+        self.header.insert("is_hltv".to_string(), server_info.is_hltv().to_string());
+        // In pov demos the slot of the player recording the demo
+        if !server_info.is_hltv() {
+            self.header.insert("player_slot".to_string(), server_info.player_slot().to_string());
+        }
+        Ok(())
+    }
+    // The tick of the DEM_Stop frame is the last tick of the demo
+    fn insert_playback_time(&mut self) {
+        self.header.insert("playback_ticks".to_string(), self.tick.to_string());
+        let playback_time = self.tick as f32 * self.tick_interval;
+        self.header.insert("playback_time".to_string(), playback_time.to_string());
+    }
+    // Fileinfo has the numbers the demo was written with, prefer those when it can be read
+    fn insert_file_info(&mut self, demo_bytes: &[u8]) {
+        let info = match parse_file_info(demo_bytes) {
+            Ok(info) => info,
+            Err(_) => return,
+        };
+        if let Some(playback_time) = info.playback_time {
+            self.header.insert("playback_time".to_string(), playback_time.to_string());
+        }
+        if let Some(playback_ticks) = info.playback_ticks {
+            self.header.insert("playback_ticks".to_string(), playback_ticks.to_string());
+        }
+        if let Some(playback_frames) = info.playback_frames {
+            self.header.insert("playback_frames".to_string(), playback_frames.to_string());
+        }
+    }
     fn handle_short_header(&mut self, file_len: usize, bytes: &[u8]) -> Result<(), DemoParserError> {
         if bytes.len() < 16 {
             return Err(DemoParserError::OutOfBytesError);
@@ -333,11 +383,7 @@ impl<'a> FirstPassParser<'a> {
                 100.0 - (file_len as f32 / file_length_expected as f32 * 100.0),
             )));
         }
-        // seems to be byte offset to where DEM_END command happens. After that comes Spawngroups and fileinfo. odd...
-        let _no_clue_what_this_is = match bytes[8..12].try_into() {
-            Err(_) => return Err(DemoParserError::OutOfBytesError),
-            Ok(arr) => i32::from_le_bytes(arr),
-        };
+        // bytes[8..12] is the byte offset of the fileinfo frame that comes after DEM_Stop, see file_info.rs
         self.ptr = HEADER_ENDS_AT_BYTE;
         Ok(())
     }
@@ -1,5 +1,6 @@
 use super::sendtables::Serializer;
 use super::stringtables::StringTable;
+use crate::first_pass::parser::DEFAULT_TICK_INTERVAL;
 use crate::first_pass::prop_controller::PropController;
 use crate::first_pass::prop_controller::PropInfo;
 use crate::first_pass::read_bits::DemoParserError;
@@ -38,6 +39,19 @@ pub struct ParserInputs<'a> {
     pub only_convars: bool,
     pub huffman_lookup_table: &'a Vec<(u8, u8)>,
     pub order_by_steamid: bool,
+    // Snapshot the players' money and equipment at every freeze end, see DemoOutput::economy_snapshots
+    pub parse_economy: bool,
+    // Track m_bSpottedByMask into visibility intervals, see DemoOutput::visibility
+    pub parse_visibility: bool,
+    // Collect the wanted props every (wanted) tick even when events are wanted,
+    // by default only the events are collected then
+    pub parse_ticks_with_events: bool,
+    // Add the subtick timing, view angles and position of the shot to weapon_fire events.
+    // These come from the usercmds, which are only decoded when this is set.
+    pub parse_shot_details: bool,
+    pub wanted_net_messages: Vec<String>,
+    // Record every created/changed string table entry, see DemoOutput::string_table_records
+    pub parse_string_tables: bool,
 }
 
 pub struct FirstPassParser<'a> {
@@ -46,6 +60,7 @@ pub struct FirstPassParser<'a> {
     pub fullpacket_offsets: Vec<usize>,
     pub ptr: usize,
     pub tick: i32,
+    pub tick_interval: f32,
     pub huf: &'a Vec<(u8, u8)>,
     pub settings: &'a ParserInputs<'a>,
     pub serializers: AHashMap<String, Serializer>,
@@ -97,7 +112,7 @@ impl<'a> FirstPassParser<'a> {
             sendtable_message: None,
             needs_velocity: needs_velocity(&inputs.wanted_player_props),
             added_temp_props: vec![],
-            is_multithreadable: check_multithreadability(&inputs.wanted_player_props),
+            is_multithreadable: check_multithreadability(&inputs.wanted_player_props) && check_multithreadability(&inputs.wanted_other_props),
             stringtable_players: BTreeMap::default(),
             only_header: inputs.only_header,
             ge_list_set: false,
@@ -121,6 +136,7 @@ impl<'a> FirstPassParser<'a> {
             ptr: 0,
             baselines: AHashMap::default(),
             tick: 0,
+            tick_interval: DEFAULT_TICK_INTERVAL,
             huf: &inputs.huffman_lookup_table,
             qf_mapper: QfMapper {
                 idx: 0,
@@ -49,6 +49,11 @@ pub const ITEM_PURCHASE_COST: u32 = 400000000;
 pub const ITEM_PURCHASE_HANDLE: u32 = 500000000;
 pub const ITEM_PURCHASE_NEW_DEF_IDX: u32 = 600000000;
 pub const FLATTENED_VEC_MAX_LEN: u32 = 100000;
+pub const INFERNO_FIRE_POSITIONS_BASEID: u32 = 700000000;
+pub const INFERNO_FIRE_IS_BURNING_BASEID: u32 = 700001000;
+pub const INFERNO_MAX_FIRES: u32 = 64;
+// Length of CSmokeGrenadeProjectile.m_VoxelFrameData, byte idx is at SMOKE_VOXEL_DATA_BASEID + idx + 1
+pub const SMOKE_VOXEL_DATA_BASEID: u32 = 800000000;
 
 pub const USERCMD_VIEWANGLE_X: u32 = 100000022;
 pub const USERCMD_VIEWANGLE_Y: u32 = 100000023;
@@ -73,6 +78,27 @@ pub const USERCMD_ATTACK_START_HISTORY_INDEX_1: u32 = 100000040;
 pub const USERCMD_ATTACK_START_HISTORY_INDEX_2: u32 = 100000041;
 pub const USERCMD_ATTACK_START_HISTORY_INDEX_3: u32 = 100000042;
 
+pub const IN_SMOKE_ID: u32 = 100000043;
+pub const IN_FIRE_ID: u32 = 100000044;
+pub const FLASH_REMAINING_SECONDS_ID: u32 = 100000045;
+pub const SMOKED_LINE_TO_ENEMY_ID: u32 = 100000046;
+pub const RADAR_X_ID: u32 = 100000047;
+pub const RADAR_Y_ID: u32 = 100000048;
+pub const ROUND_ID: u32 = 100000049;
+pub const ROUND_TIME_ID: u32 = 100000050;
+pub const TIME_SINCE_FREEZE_END_ID: u32 = 100000051;
+pub const TIME_SINCE_BOMB_PLANT_ID: u32 = 100000052;
+pub const IS_WARMUP_ID: u32 = 100000053;
+pub const SECONDS_ID: u32 = 100000054;
+
+pub const ROUND_TIMING_PROPS: &[(&str, u32)] = &[
+    ("round", ROUND_ID),
+    ("round_time", ROUND_TIME_ID),
+    ("time_since_freeze_end", TIME_SINCE_FREEZE_END_ID),
+    ("time_since_bomb_plant", TIME_SINCE_BOMB_PLANT_ID),
+    ("is_warmup", IS_WARMUP_ID),
+];
+
 pub const USERCMD_INPUT_HISTORY_BASEID: u32 = 100001000;
 pub const INPUT_HISTORY_X_OFFSET: u32 = 0;
 pub const INPUT_HISTORY_Y_OFFSET: u32 = 1;
@@ -243,6 +269,31 @@ impl PropController {
                 is_player_prop: false,
             });
         }
+        for (wanted, is_player_prop) in [(&self.wanted_player_props, true), (&self.wanted_other_props, false)] {
+            if wanted.contains(&"seconds".to_string()) {
+                self.prop_infos.push(PropInfo {
+                    id: SECONDS_ID,
+                    prop_type: PropType::GameTime,
+                    prop_name: "seconds".to_string(),
+                    prop_friendly_name: "seconds".to_string(),
+                    is_player_prop,
+                });
+            }
+        }
+        // Can be both player and non-player props, same as game_time
+        for (name, id) in ROUND_TIMING_PROPS {
+            for (wanted, is_player_prop) in [(&self.wanted_player_props, true), (&self.wanted_other_props, false)] {
+                if wanted.contains(&name.to_string()) {
+                    self.prop_infos.push(PropInfo {
+                        id: *id,
+                        prop_type: PropType::RoundTiming,
+                        prop_name: name.to_string(),
+                        prop_friendly_name: name.to_string(),
+                        is_player_prop,
+                    });
+                }
+            }
+        }
         self.prop_infos.push(PropInfo {
             id: TICK_ID,
             prop_type: PropType::Tick,
@@ -387,6 +438,15 @@ impl PropController {
         if full_name == "CCSPlayerPawn.CCSPlayer_BuyServices.SellbackPurchaseEntry_t.m_hItem" {
             f.prop_id = ITEM_PURCHASE_HANDLE as u32;
         }
+        if full_name == "CInferno.m_firePositions" {
+            f.prop_id = INFERNO_FIRE_POSITIONS_BASEID;
+        }
+        if full_name == "CInferno.m_bFireIsBurning" {
+            f.prop_id = INFERNO_FIRE_IS_BURNING_BASEID;
+        }
+        if full_name == "CSmokeGrenadeProjectile.m_VoxelFrameData" {
+            f.prop_id = SMOKE_VOXEL_DATA_BASEID;
+        }
         if prop_name.contains("CEconItemAttribute.m_iRawValue32") {
             f.prop_id = WEAPON_SKIN_ID as u32;
         }
@@ -409,12 +469,24 @@ impl PropController {
                 "m_OriginalOwnerXuidLow" => self.special_ids.orig_own_low = Some(id),
                 "m_OriginalOwnerXuidHigh" => self.special_ids.orig_own_high = Some(id),
                 "m_szCustomName" => self.special_ids.custom_name = Some(id),
+                "m_fireCount" => self.special_ids.inferno_fire_count = Some(id),
+                "m_bDidSmokeEffect" => self.special_ids.smoke_did_effect = Some(id),
+                "m_nSmokeEffectTickBegin" => self.special_ids.smoke_effect_tick_begin = Some(id),
+                "m_vSmokeDetonationPos" => self.special_ids.smoke_detonation_pos = Some(id),
+                "m_nVoxelUpdate" => self.special_ids.smoke_voxel_update = Some(id),
                 _ => {}
             };
         } else {
             match name {
                 "CCSGameRulesProxy.CCSGameRules.m_nRoundStartCount" => self.special_ids.round_start_count = Some(id),
                 "CCSGameRulesProxy.CCSGameRules.m_nRoundEndCount" => self.special_ids.round_end_count = Some(id),
+                "CCSGameRulesProxy.CCSGameRules.m_bFreezePeriod" => self.special_ids.freeze_period = Some(id),
+                "CCSGameRulesProxy.CCSGameRules.m_bWarmupPeriod" => self.special_ids.warmup_period = Some(id),
+                "CCSGameRulesProxy.CCSGameRules.m_bBombPlanted" => self.special_ids.bomb_planted = Some(id),
+                "CCSGameRulesProxy.CCSGameRules.m_bTerroristTimeOutActive" => self.special_ids.terrorist_timeout = Some(id),
+                "CCSGameRulesProxy.CCSGameRules.m_bCTTimeOutActive" => self.special_ids.ct_timeout = Some(id),
+                "CCSGameRulesProxy.CCSGameRules.m_bTechnicalTimeOut" => self.special_ids.technical_timeout = Some(id),
+                "CCSGameRulesProxy.CCSGameRules.m_bMatchWaitingForResume" => self.special_ids.match_waiting_for_resume = Some(id),
                 "CCSGameRulesProxy.CCSGameRules.m_nMatchEndCount" => self.special_ids.match_end_count = Some(id),
                 "CCSGameRulesProxy.CCSGameRules.m_eRoundWinReason" => self.special_ids.round_win_reason = Some(id),
                 "CCSGameRulesProxy.CCSGameRules.m_totalRoundsPlayed" => self.special_ids.total_rounds_played = Some(id),
@@ -438,6 +510,11 @@ impl PropController {
                 "CCSPlayerController.m_nPawnCharacterDefIndex" => self.special_ids.agent_skin_idx = Some(id),
                 "CCSPlayerPawn.m_bInBuyZone" => self.special_ids.in_buy_zone = Some(id),
                 "CCSPlayerPawn.m_hGroundEntity" => self.special_ids.is_airborn = Some(id),
+                "CCSPlayerPawn.m_flFlashDuration" => self.special_ids.flash_duration = Some(id),
+                "CCSPlayerPawn.m_bSpottedByMask" => self.special_ids.spotted_by_mask = Some(id),
+                "CCSPlayerPawn.CCSPlayer_MovementServices.m_bDucked" => self.special_ids.is_ducked = Some(id),
+                "CCSPlayerPawn.CCSPlayer_MovementServices.m_bDucking" => self.special_ids.is_ducking = Some(id),
+                "CCSPlayerPawn.m_bIsWalking" => self.special_ids.is_walking = Some(id),
                 _ => {}
             };
         }
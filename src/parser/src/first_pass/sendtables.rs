@@ -4,12 +4,15 @@ use crate::first_pass::parser_settings::needs_velocity;
 use crate::first_pass::parser_settings::FirstPassParser;
 use crate::first_pass::prop_controller::PropController;
 use crate::first_pass::prop_controller::FLATTENED_VEC_MAX_LEN;
+use crate::first_pass::prop_controller::INFERNO_FIRE_IS_BURNING_BASEID;
+use crate::first_pass::prop_controller::INFERNO_FIRE_POSITIONS_BASEID;
 use crate::first_pass::prop_controller::ITEM_PURCHASE_COST;
 use crate::first_pass::prop_controller::ITEM_PURCHASE_COUNT;
 use crate::first_pass::prop_controller::ITEM_PURCHASE_DEF_IDX;
 use crate::first_pass::prop_controller::ITEM_PURCHASE_HANDLE;
 use crate::first_pass::prop_controller::ITEM_PURCHASE_NEW_DEF_IDX;
 use crate::first_pass::prop_controller::MY_WEAPONS_OFFSET;
+use crate::first_pass::prop_controller::SMOKE_VOXEL_DATA_BASEID;
 use crate::first_pass::prop_controller::WEAPON_SKIN_ID;
 use crate::maps::BASETYPE_DECODERS;
 use crate::second_pass::decoder::Decoder;
@@ -490,6 +493,14 @@ pub fn get_propinfo(field: &Field, path: &FieldPath) -> Option<FieldInfo> {
     if fi.prop_id == WEAPON_SKIN_ID {
         fi.prop_id = WEAPON_SKIN_ID + path.path[1] as u32;
     }
+    // Inferno fire arrays: CInferno.m_firePositions[idx] => INFERNO_FIRE_POSITIONS_BASEID + idx
+    if fi.prop_id == INFERNO_FIRE_POSITIONS_BASEID || fi.prop_id == INFERNO_FIRE_IS_BURNING_BASEID {
+        fi.prop_id += path.path[1] as u32;
+    }
+    // Smoke voxel bytes: the vector length stays at the base id, byte idx goes to SMOKE_VOXEL_DATA_BASEID + idx + 1
+    if fi.prop_id == SMOKE_VOXEL_DATA_BASEID && path.last > 0 {
+        fi.prop_id += path.path[1] as u32 + 1;
+    }
     if path.path[1] != 1 {
         if fi.prop_id >= ITEM_PURCHASE_COUNT && fi.prop_id < ITEM_PURCHASE_COUNT + FLATTENED_VEC_MAX_LEN {
             fi.prop_id = ITEM_PURCHASE_COUNT + path.path[2] as u32;
@@ -762,3 +773,53 @@ pub struct FieldType {
     pub count: Option<i32>,
     pub element_type: Option<Box<FieldType>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value_field(prop_id: u32) -> Field {
+        Field::Value(ValueField {
+            decoder: UnsignedDecoder,
+            name: "".to_string(),
+            should_parse: true,
+            prop_id,
+            full_name: "".to_string(),
+        })
+    }
+
+    fn path(path: &[i32]) -> FieldPath {
+        let mut fp = FieldPath {
+            path: [0; 7],
+            last: path.len() - 1,
+        };
+        fp.path[..path.len()].copy_from_slice(path);
+        fp
+    }
+
+    #[test]
+    fn test_flatten_inferno_fires() {
+        let field = value_field(INFERNO_FIRE_POSITIONS_BASEID);
+        let fi = get_propinfo(&field, &path(&[12, 7])).unwrap();
+        assert_eq!(fi.prop_id, INFERNO_FIRE_POSITIONS_BASEID + 7);
+        let field = value_field(INFERNO_FIRE_IS_BURNING_BASEID);
+        let fi = get_propinfo(&field, &path(&[13, 0])).unwrap();
+        assert_eq!(fi.prop_id, INFERNO_FIRE_IS_BURNING_BASEID);
+    }
+
+    #[test]
+    fn test_flatten_smoke_voxel_data() {
+        let field = Field::Vector(VectorField {
+            field_enum: Box::new(value_field(SMOKE_VOXEL_DATA_BASEID)),
+            decoder: UnsignedDecoder,
+        });
+        // Length of the vector
+        let fi = get_propinfo(&field, &path(&[20])).unwrap();
+        assert_eq!(fi.prop_id, SMOKE_VOXEL_DATA_BASEID);
+        // Bytes are shifted by one so they don't collide with the length
+        let fi = get_propinfo(&field, &path(&[20, 0])).unwrap();
+        assert_eq!(fi.prop_id, SMOKE_VOXEL_DATA_BASEID + 1);
+        let fi = get_propinfo(&field, &path(&[20, 41])).unwrap();
+        assert_eq!(fi.prop_id, SMOKE_VOXEL_DATA_BASEID + 42);
+    }
+}
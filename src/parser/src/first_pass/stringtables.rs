@@ -1,6 +1,7 @@
 use super::read_bits::{Bitreader, DemoParserError};
 use crate::first_pass::parser_settings::FirstPassParser;
 use crate::second_pass::parser_settings::SecondPassParser;
+use csgoproto::c_demo_string_tables::ItemsT;
 use csgoproto::CMsgPlayerInfo;
 use csgoproto::CsvcMsgCreateStringTable;
 use csgoproto::CsvcMsgUpdateStringTable;
@@ -9,11 +10,11 @@ use snap::raw::Decoder;
 
 #[derive(Clone, Debug)]
 pub struct StringTable {
-    name: String,
+    pub name: String,
     user_data_size: i32,
     user_data_fixed: bool,
-    #[allow(dead_code)]
-    data: Vec<StringTableEntry>,
+    // Current entries of the table
+    pub data: Vec<StringTableEntry>,
     flags: i32,
     var_bit_counts: bool,
 }
@@ -23,6 +24,15 @@ pub struct StringTableEntry {
     pub key: String,
     pub value: Vec<u8>,
 }
+// One created/changed entry of a table at a tick
+#[derive(Clone, Debug)]
+pub struct StringTableRecord {
+    pub tick: i32,
+    pub table_name: String,
+    pub idx: i32,
+    pub key: String,
+    pub value: Vec<u8>,
+}
 #[derive(Clone, Debug)]
 pub struct UserInfo {
     pub steamid: u64,
@@ -31,55 +41,25 @@ pub struct UserInfo {
     pub is_hltv: bool,
 }
 
-impl<'a> FirstPassParser<'a> {
-    pub fn update_string_table(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
-        let table = CsvcMsgUpdateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
-
-        let st = self.string_tables.get(table.table_id() as usize).ok_or(DemoParserError::StringTableNotFound)?;
-        self.parse_string_table(
-            table.string_data().to_vec(),
-            table.num_changed_entries(),
-            st.name.clone(),
-            st.user_data_fixed,
-            st.user_data_size,
-            st.flags,
-            st.var_bit_counts,
-        )?;
-        Ok(())
-    }
-
-    pub fn parse_create_stringtable(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
-        let table = CsvcMsgCreateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
-
-        if !(table.name() == "instancebaseline" || table.name() == "userinfo") {
-            return Ok(());
+impl StringTable {
+    pub fn new(table: &CsvcMsgCreateStringTable) -> Self {
+        StringTable {
+            name: table.name().to_string(),
+            user_data_size: table.user_data_size(),
+            user_data_fixed: table.user_data_fixed_size(),
+            data: vec![],
+            flags: table.flags(),
+            var_bit_counts: table.using_varint_bitcounts(),
         }
-        let bytes = match table.data_compressed() {
-            true => snap::raw::Decoder::new().decompress_vec(table.string_data()).map_err(|_| DemoParserError::MalformedMessage)?,
-            false => table.string_data().to_vec(),
-        };
-        self.parse_string_table(
-            bytes,
-            table.num_entries(),
-            table.name().to_string(),
-            table.user_data_fixed_size(),
-            table.user_data_size(),
-            table.flags(),
-            table.using_varint_bitcounts(),
-        )?;
-        Ok(())
     }
-    pub fn parse_string_table(
-        &mut self,
-        bytes: Vec<u8>,
-        n_updates: i32,
-        name: String,
-        udf: bool,
-        user_data_size: i32,
-        flags: i32,
-        variant_bit_count: bool,
-    ) -> Result<(), DemoParserError> {
-        let mut bitreader = Bitreader::new(&bytes);
+    // Decodes created/changed entries and merges them into the table. Entries that only change
+    // the value get their key from the existing entry.
+    //
+    // Every entry is: index (1 bit "next one" or a varint delta), has-key bit + key, has-value bit + value.
+    // The has-value bit is there whether or not the entry has a key, value-only updates need it
+    // to be read too or the rest of the message goes out of sync.
+    pub fn parse_entries(&mut self, bytes: &[u8], n_updates: i32) -> Result<Vec<StringTableEntry>, DemoParserError> {
+        let mut bitreader = Bitreader::new(bytes);
         let mut idx = -1;
         let mut keys: Vec<String> = vec![];
         let mut items = vec![];
@@ -123,64 +103,138 @@ impl<'a> FirstPassParser<'a> {
                     keys.remove(0);
                 }
                 keys.push(key.clone());
-                // Does the entry have a value
-                if bitreader.read_boolean()? {
-                    let bits: u32;
-                    let mut is_compressed = false;
+            }
+            // Does the entry have a value
+            if bitreader.read_boolean()? {
+                let bits: u32;
+                let mut is_compressed = false;
 
-                    match udf {
-                        true => bits = user_data_size as u32,
-                        false => {
-                            if (flags & 0x1) != 0 {
-                                is_compressed = bitreader.read_boolean()?;
-                            }
-                            if variant_bit_count {
-                                bits = bitreader.read_u_bit_var()? * 8;
-                            } else {
-                                bits = bitreader.read_nbits(17)? * 8;
-                            }
-                        }
-                    }
-                    value = bitreader.read_n_bytes((bits.checked_div(8).unwrap_or(0)) as usize)?;
-                    value = if is_compressed {
-                        match Decoder::new().decompress_vec(&value) {
-                            Ok(bytes) => bytes,
-                            Err(_) => return Err(DemoParserError::MalformedMessage),
+                match self.user_data_fixed {
+                    true => bits = self.user_data_size as u32,
+                    false => {
+                        if (self.flags & 0x1) != 0 {
+                            is_compressed = bitreader.read_boolean()?;
                         }
-                    } else {
-                        value
-                    };
-                }
-                if name == "userinfo" {
-                    if let Ok(player) = parse_userinfo(&value) {
-                        if player.steamid != 0 {
-                            self.stringtable_players.insert(player.userid, player);
+                        if self.var_bit_counts {
+                            bits = bitreader.read_u_bit_var()? * 8;
+                        } else {
+                            bits = bitreader.read_nbits(17)? * 8;
                         }
                     }
                 }
-                if name == "instancebaseline" {
-                    match key.parse::<u32>() {
-                        Ok(cls_id) => self.baselines.insert(cls_id, value.clone()),
-                        Err(_e) => None,
-                    };
+                value = bitreader.read_n_bytes((bits.checked_div(8).unwrap_or(0)) as usize)?;
+                value = if is_compressed {
+                    match Decoder::new().decompress_vec(&value) {
+                        Ok(bytes) => bytes,
+                        Err(_) => return Err(DemoParserError::MalformedMessage),
+                    }
+                } else {
+                    value
+                };
+            }
+            let entry = StringTableEntry { idx, key, value };
+            items.push(self.insert_entry(entry));
+        }
+        Ok(items)
+    }
+    // Fullpackets carry a snapshot of the entries. The table itself is kept (the snapshot doesn't
+    // have the flags needed to decode later updates), only its entries are replaced.
+    pub fn replace_entries(&mut self, items: &[ItemsT]) {
+        self.data = items
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| StringTableEntry {
+                idx: idx as i32,
+                key: item.str().to_string(),
+                value: item.data().to_vec(),
+            })
+            .collect();
+    }
+    pub fn parse_created(&mut self, table: &CsvcMsgCreateStringTable, wanted: bool) -> Result<Vec<StringTableEntry>, DemoParserError> {
+        self.decode_if_needed(wanted, |st| {
+            let bytes = match table.data_compressed() {
+                true => Decoder::new()
+                    .decompress_vec(table.string_data())
+                    .map_err(|_| DemoParserError::MalformedMessage)?,
+                false => table.string_data().to_vec(),
+            };
+            st.parse_entries(&bytes, table.num_entries())
+        })
+    }
+    pub fn parse_updated(&mut self, table: &CsvcMsgUpdateStringTable, wanted: bool) -> Result<Vec<StringTableEntry>, DemoParserError> {
+        self.decode_if_needed(wanted, |st| st.parse_entries(table.string_data(), table.num_changed_entries()))
+    }
+    // instancebaseline and userinfo are needed to parse the demo, failing to decode them is an error.
+    // Other tables are only decoded when asked for, and one this decoder can't handle is skipped.
+    fn decode_if_needed<F>(&mut self, wanted: bool, decode: F) -> Result<Vec<StringTableEntry>, DemoParserError>
+    where
+        F: FnOnce(&mut Self) -> Result<Vec<StringTableEntry>, DemoParserError>,
+    {
+        match self.name.as_str() {
+            "instancebaseline" | "userinfo" => decode(self),
+            _ if wanted => Ok(decode(self).unwrap_or_default()),
+            _ => Ok(vec![]),
+        }
+    }
+    fn insert_entry(&mut self, mut entry: StringTableEntry) -> StringTableEntry {
+        // Entries are usually stored in index order
+        let position = match self.data.get(entry.idx as usize) {
+            Some(old) if old.idx == entry.idx => Some(entry.idx as usize),
+            _ => self.data.iter().position(|old| old.idx == entry.idx),
+        };
+        match position {
+            Some(position) => {
+                if entry.key.is_empty() {
+                    entry.key = self.data[position].key.clone();
                 }
-                items.push(StringTableEntry {
-                    idx,
-                    key,
-                    value,
-                });
+                self.data[position] = entry.clone();
             }
+            None => self.data.push(entry.clone()),
         }
-        self.string_tables.push(StringTable {
-            data: items,
-            name,
-            user_data_size,
-            user_data_fixed: udf,
-            flags,
-            var_bit_counts: variant_bit_count,
-        });
+        entry
+    }
+}
+
+impl<'a> FirstPassParser<'a> {
+    pub fn update_string_table(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
+        let table = CsvcMsgUpdateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
+
+        let st = self
+            .string_tables
+            .get_mut(table.table_id() as usize)
+            .ok_or(DemoParserError::StringTableNotFound)?;
+        let entries = st.parse_updated(&table, self.settings.parse_string_tables)?;
+        let name = st.name.clone();
+        self.handle_string_table_entries(&name, &entries);
+        Ok(())
+    }
+
+    pub fn parse_create_stringtable(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
+        let table = CsvcMsgCreateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
+        // Every table is kept so that the indices match the table ids in updates
+        let mut st = StringTable::new(&table);
+        let entries = st.parse_created(&table, self.settings.parse_string_tables)?;
+        self.string_tables.push(st);
+        self.handle_string_table_entries(table.name(), &entries);
         Ok(())
     }
+    fn handle_string_table_entries(&mut self, name: &str, entries: &[StringTableEntry]) {
+        for entry in entries {
+            if name == "userinfo" {
+                if let Ok(player) = parse_userinfo(&entry.value) {
+                    if player.steamid != 0 {
+                        self.stringtable_players.insert(player.userid, player);
+                    }
+                }
+            }
+            if name == "instancebaseline" {
+                match entry.key.parse::<u32>() {
+                    Ok(cls_id) => self.baselines.insert(cls_id, entry.value.clone()),
+                    Err(_e) => None,
+                };
+            }
+        }
+    }
 }
 pub fn parse_userinfo(bytes: &[u8]) -> Result<UserInfo, DemoParserError> {
     let player = CMsgPlayerInfo::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
@@ -194,149 +248,184 @@ pub fn parse_userinfo(bytes: &[u8]) -> Result<UserInfo, DemoParserError> {
 
 impl<'a> SecondPassParser<'a> {
     pub fn update_string_table(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
-        let table = CsvcMsgUpdateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?; 
-        match self.string_tables.get(table.table_id() as usize) {
-            Some(st) => self.parse_string_table(
-                table.string_data().to_vec(),
-                table.num_changed_entries(),
-                st.name.clone(),
-                st.user_data_fixed,
-                st.user_data_size,
-                st.flags,
-                st.var_bit_counts,
-            )?,
-            None => {
-                return Ok(());
-            }
-        }
+        let table = CsvcMsgUpdateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
+        let st = match self.string_tables.get_mut(table.table_id() as usize) {
+            Some(st) => st,
+            None => return Ok(()),
+        };
+        let entries = st.parse_updated(&table, self.parse_string_tables)?;
+        let name = st.name.clone();
+        self.handle_string_table_entries(&name, entries);
         Ok(())
     }
     pub fn parse_create_stringtable(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         let table = CsvcMsgCreateStringTable::decode(bytes).map_err(|_| DemoParserError::MalformedMessage)?;
-        let bytes = match table.data_compressed() {
-            true => snap::raw::Decoder::new().decompress_vec(table.string_data()).map_err(|_| DemoParserError::MalformedMessage)?,
-            false => table.string_data().to_vec(),
-        };
-        self.parse_string_table(
-            bytes,
-            table.num_entries(),
-            table.name().to_string(),
-            table.user_data_fixed_size(),
-            table.user_data_size(),
-            table.flags(),
-            table.using_varint_bitcounts(),
-        )?;
+        let mut st = StringTable::new(&table);
+        let entries = st.parse_created(&table, self.parse_string_tables)?;
+        self.string_tables.push(st);
+        self.handle_string_table_entries(table.name(), entries);
         Ok(())
     }
-    pub fn parse_string_table(
-        &mut self,
-        bytes: Vec<u8>,
-        n_updates: i32,
-        name: String,
-        udf: bool,
-        user_data_size: i32,
-        flags: i32,
-        variant_bit_count: bool,
-    ) -> Result<(), DemoParserError> {
-        let mut bitreader = Bitreader::new(&bytes);
-        let mut idx = -1;
-        let mut keys: Vec<String> = vec![];
-        let mut items = vec![];
-
-        for _upd in 0..n_updates {
-            let mut key = "".to_owned();
-            let mut value = vec![];
-
-            // Increment index
-            match bitreader.read_boolean()? {
-                true => idx += 1,
-                false => idx += (bitreader.read_varint()? + 1) as i32,
-            };
-            // Does the value have a key
-            if bitreader.read_boolean()? {
-                // Should we refer back to history (similar to LZ77)
-                match bitreader.read_boolean()? {
-                    // If no history then just read the data as one string
-                    false => key = key.to_owned() + &bitreader.read_string()?,
-                    // Refer to history
-                    true => {
-                        // How far into history we should look
-                        let position = bitreader.read_nbits(5)?;
-                        // How many bytes in a row, starting from distance ago, should be copied
-                        let length = bitreader.read_nbits(5)?;
-
-                        if position >= keys.len() as u32 {
-                            key = key.to_owned() + &bitreader.read_string()?;
-                        } else {
-                            let s = &keys[position as usize];
-                            if length > s.len() as u32 {
-                                key = key.to_owned() + &s + &bitreader.read_string()?;
-                            } else {
-                                key = key.to_owned() + &s[0..length as usize] + &bitreader.read_string()?;
-                            }
-                        }
+    fn handle_string_table_entries(&mut self, name: &str, entries: Vec<StringTableEntry>) {
+        for entry in entries {
+            if name == "userinfo" {
+                if let Ok(player) = parse_userinfo(&entry.value) {
+                    if player.steamid != 0 {
+                        self.stringtable_players.insert(player.userid, player);
                     }
                 }
-                if keys.len() >= 32 {
-                    keys.remove(0);
-                }
-                keys.push(key.clone());
-                // Does the entry have a value
-                if bitreader.read_boolean()? {
-                    let bits: u32;
-                    let mut is_compressed = false;
+            }
+            if name == "instancebaseline" {
+                match entry.key.parse::<u32>() {
+                    Ok(cls_id) => self.baselines.insert(cls_id, entry.value.clone()),
+                    Err(_e) => None,
+                };
+            }
+            if self.parse_string_tables {
+                self.string_table_records.push(StringTableRecord {
+                    tick: self.tick,
+                    table_name: name.to_string(),
+                    idx: entry.idx,
+                    key: entry.key,
+                    value: entry.value,
+                });
+            }
+        }
+    }
+}
 
-                    match udf {
-                        true => bits = user_data_size as u32,
-                        false => {
-                            if (flags & 0x1) != 0 {
-                                is_compressed = bitreader.read_boolean()?;
-                            }
-                            if variant_bit_count {
-                                bits = bitreader.read_u_bit_var()? * 8;
-                            } else {
-                                bits = bitreader.read_nbits(17)? * 8;
-                            }
-                        }
-                    }
-                    value = bitreader.read_n_bytes((bits.checked_div(8).unwrap_or(0)) as usize)?;
-                    value = if is_compressed {
-                        match Decoder::new().decompress_vec(&value) {
-                            Ok(bytes) => bytes,
-                            Err(_) => return Err(DemoParserError::MalformedMessage),
-                        }
-                    } else {
-                        value
-                    };
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Writes bits in the order Bitreader reads them (least significant first)
+    #[derive(Default)]
+    struct BitWriter {
+        bytes: Vec<u8>,
+        n_bits: usize,
+    }
+    impl BitWriter {
+        fn write(&mut self, value: u32, n: u32) {
+            for i in 0..n {
+                if self.n_bits.is_multiple_of(8) {
+                    self.bytes.push(0);
                 }
-                if name == "userinfo" {
-                    if let Ok(player) = parse_userinfo(&value) {
-                        if player.steamid != 0 {
-                            self.stringtable_players.insert(player.userid, player);
-                        }
-                    }
+                if (value >> i) & 1 == 1 {
+                    *self.bytes.last_mut().unwrap() |= 1 << (self.n_bits % 8);
                 }
-                if name == "instancebaseline" {
-                    match key.parse::<u32>() {
-                        Ok(cls_id) => self.baselines.insert(cls_id, value.clone()),
-                        Err(_e) => None,
-                    };
+                self.n_bits += 1;
+            }
+        }
+        fn write_string(&mut self, s: &str) {
+            for b in s.bytes().chain([0]) {
+                self.write(b as u32, 8);
+            }
+        }
+        // Next index, optional key (no history) and optional value with a 17 bit byte count
+        fn write_entry(&mut self, key: Option<&str>, value: Option<&[u8]>) {
+            self.write(1, 1);
+            self.write(key.is_some() as u32, 1);
+            if let Some(key) = key {
+                self.write(0, 1);
+                self.write_string(key);
+            }
+            self.write(value.is_some() as u32, 1);
+            if let Some(value) = value {
+                self.write(value.len() as u32, 17);
+                for b in value {
+                    self.write(*b as u32, 8);
                 }
-                items.push(StringTableEntry {
-                    idx,
-                    key,
-                    value,
-                });
             }
         }
-        self.string_tables.push(StringTable {
-            data: items,
-            name,
-            user_data_size,
-            user_data_fixed: udf,
-            flags,
-            var_bit_counts: variant_bit_count,
-        });
-        Ok(())
+        fn finish(mut self) -> Vec<u8> {
+            // Bitreader reads ahead
+            self.bytes.extend([0; 8]);
+            self.bytes
+        }
+    }
+
+    fn table() -> StringTable {
+        StringTable::new(&CsvcMsgCreateStringTable {
+            name: Some("modelprecache".to_string()),
+            user_data_fixed_size: Some(false),
+            flags: Some(0),
+            using_varint_bitcounts: Some(false),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_parse_entries() {
+        let mut st = table();
+        let mut created = BitWriter::default();
+        created.write_entry(Some("models/a.vmdl"), Some(&[1, 2]));
+        created.write_entry(Some("models/b.vmdl"), None);
+        let entries = st.parse_entries(&created.finish(), 2).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[1].idx, entries[1].key.as_str()), (1, "models/b.vmdl"));
+
+        // Value only update of the first entry: the value is read even though there is no key
+        let mut update = BitWriter::default();
+        update.write_entry(None, Some(&[3]));
+        let entries = st.parse_entries(&update.finish(), 1).unwrap();
+        assert_eq!(
+            (entries[0].idx, entries[0].key.as_str(), entries[0].value.clone()),
+            (0, "models/a.vmdl", vec![3])
+        );
+        assert_eq!(st.data.len(), 2);
+        assert_eq!(st.data[0].value, vec![3]);
+    }
+    #[test]
+    fn test_updates_apply_after_fullpacket() {
+        let mut st = table();
+        let mut created = BitWriter::default();
+        created.write_entry(Some("models/a.vmdl"), Some(&[1]));
+        st.parse_entries(&created.finish(), 1).unwrap();
+
+        let snapshot = vec![
+            ItemsT {
+                str: Some("models/a.vmdl".to_string()),
+                data: Some(vec![5].into()),
+            },
+            ItemsT {
+                str: Some("models/c.vmdl".to_string()),
+                data: None,
+            },
+        ];
+        st.replace_entries(&snapshot);
+        assert_eq!(st.data.len(), 2);
+
+        // The table still decodes updates after the snapshot
+        let mut update = BitWriter::default();
+        // Skip to index 1 (delta varint)
+        update.write(0, 1);
+        update.write(1, 8);
+        update.write(0, 1);
+        update.write(1, 1);
+        update.write(1, 17);
+        update.write(7, 8);
+        let entries = st.parse_entries(&update.finish(), 1).unwrap();
+        assert_eq!(entries[0].key, "models/c.vmdl");
+        assert_eq!(st.data[1].value, vec![7]);
+    }
+    #[test]
+    fn test_only_required_tables_fail_the_parse() {
+        // A value claiming more bytes than there are
+        let mut broken = BitWriter::default();
+        broken.write(1, 1);
+        broken.write(0, 1);
+        broken.write(1, 1);
+        broken.write(100, 17);
+        let update = CsvcMsgUpdateStringTable {
+            string_data: Some(broken.finish().into()),
+            num_changed_entries: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(table().parse_updated(&update, false).unwrap().len(), 0);
+        assert_eq!(table().parse_updated(&update, true).unwrap().len(), 0);
+
+        let mut userinfo = table();
+        userinfo.name = "userinfo".to_string();
+        assert!(userinfo.parse_updated(&update, false).is_err());
     }
 }
@@ -1,6 +1,9 @@
 #[cfg(test)]
 pub mod e2e_test;
+pub mod analysis;
 pub mod first_pass;
+pub mod map_data;
 pub mod maps;
+pub mod nav;
 pub mod parse_demo;
 pub mod second_pass;
@@ -0,0 +1,93 @@
+use phf_macros::phf_map;
+
+// Radar metadata for the active duty and common maps. Values match the overview files
+// shipped with the game (resource/overviews/<map>.txt): the world position of the radar's
+// top left corner and the number of world units per radar pixel. Callout polygons are not
+// bundled, they come from the map's nav mesh (see NavMesh::callouts).
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapData {
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub scale: f32,
+    // Maps with several floors drawn as separate radars (Nuke, Vertigo)
+    pub levels: &'static [MapLevel],
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapLevel {
+    pub name: &'static str,
+    pub altitude_min: f32,
+    pub altitude_max: f32,
+}
+
+const SINGLE_LEVEL: &[MapLevel] = &[MapLevel {
+    name: "default",
+    altitude_min: f32::NEG_INFINITY,
+    altitude_max: f32::INFINITY,
+}];
+
+pub static MAP_DATA: phf::Map<&'static str, MapData> = phf_map! {
+    "de_ancient" => MapData { pos_x: -2953.0, pos_y: 2164.0, scale: 5.0, levels: SINGLE_LEVEL },
+    "de_anubis" => MapData { pos_x: -2796.0, pos_y: 3328.0, scale: 5.22, levels: SINGLE_LEVEL },
+    "de_dust2" => MapData { pos_x: -2476.0, pos_y: 3239.0, scale: 4.4, levels: SINGLE_LEVEL },
+    "de_inferno" => MapData { pos_x: -2087.0, pos_y: 3870.0, scale: 4.9, levels: SINGLE_LEVEL },
+    "de_mirage" => MapData { pos_x: -3230.0, pos_y: 1713.0, scale: 5.0, levels: SINGLE_LEVEL },
+    "de_nuke" => MapData {
+        pos_x: -3453.0,
+        pos_y: 2887.0,
+        scale: 7.0,
+        levels: &[
+            MapLevel { name: "default", altitude_min: -495.0, altitude_max: f32::INFINITY },
+            MapLevel { name: "lower", altitude_min: f32::NEG_INFINITY, altitude_max: -495.0 },
+        ],
+    },
+    "de_overpass" => MapData { pos_x: -4831.0, pos_y: 1781.0, scale: 5.2, levels: SINGLE_LEVEL },
+    "de_train" => MapData { pos_x: -2308.0, pos_y: 2078.0, scale: 4.082077, levels: SINGLE_LEVEL },
+    "de_vertigo" => MapData {
+        pos_x: -3168.0,
+        pos_y: 1762.0,
+        scale: 4.0,
+        levels: &[
+            MapLevel { name: "default", altitude_min: 11700.0, altitude_max: f32::INFINITY },
+            MapLevel { name: "lower", altitude_min: f32::NEG_INFINITY, altitude_max: 11700.0 },
+        ],
+    },
+    "cs_italy" => MapData { pos_x: -2647.0, pos_y: 2592.0, scale: 4.6, levels: SINGLE_LEVEL },
+    "cs_office" => MapData { pos_x: -1838.0, pos_y: 1858.0, scale: 4.1, levels: SINGLE_LEVEL },
+};
+
+// Accepts the "map_name" from the header. Workshop maps come with a path like "workshop/123/de_dust2".
+pub fn find_map_data(map_name: &str) -> Option<&'static MapData> {
+    let name = map_name.rsplit('/').next().unwrap_or(map_name);
+    MAP_DATA.get(name)
+}
+
+impl MapData {
+    // World coordinates to radar pixel coordinates (origin at the top left of the radar image)
+    pub fn world_to_radar(&self, x: f32, y: f32) -> [f32; 2] {
+        [(x - self.pos_x) / self.scale, (self.pos_y - y) / self.scale]
+    }
+    pub fn level_for_z(&self, z: f32) -> &'static str {
+        self.levels
+            .iter()
+            .find(|level| z >= level.altitude_min && z < level.altitude_max)
+            .map_or("default", |level| level.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_world_to_radar() {
+        let map = find_map_data("workshop/123456/de_dust2").unwrap();
+        assert_eq!(map.world_to_radar(-2476.0, 3239.0), [0.0, 0.0]);
+        assert_eq!(map.world_to_radar(-2476.0 + 440.0, 3239.0 - 880.0), [100.0, 200.0]);
+        let nuke = find_map_data("de_nuke").unwrap();
+        assert_eq!(nuke.level_for_z(-600.0), "lower");
+        assert_eq!(nuke.level_for_z(0.0), "default");
+        assert!(find_map_data("de_unknown").is_none());
+    }
+}
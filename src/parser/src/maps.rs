@@ -73,6 +73,78 @@ pub static HIT_GROUP: phf::Map<i32, &'static str> = phf_map! {
     10_i32 => "gear",
 };
 
+// CSStatType_t, the stat ids in CS_UM_PlayerStatsUpdate
+pub static PLAYER_STAT_NAMES: phf::Map<i32, &'static str> = phf_map! {
+    0_i32 => "shots_hit",
+    1_i32 => "shots_fired",
+    2_i32 => "kills",
+    3_i32 => "deaths",
+    4_i32 => "damage",
+    5_i32 => "bombs_planted",
+    6_i32 => "bombs_defused",
+    7_i32 => "playtime",
+    8_i32 => "rounds_won",
+    9_i32 => "t_rounds_won",
+    10_i32 => "ct_rounds_won",
+    11_i32 => "rounds_played",
+    12_i32 => "pistol_rounds_won",
+    13_i32 => "money_earned",
+    14_i32 => "objectives_completed",
+    15_i32 => "bombs_defused_with_kit",
+};
+
+// Ids of the end of match accolades (eaccolade in CCSUsrMsg_EndOfMatchAllPlayersData).
+// The protobufs only carry the id, the names follow the order of the #accolade_* tokens in
+// the game's resource/csgo_english.txt. Id 21 with a value of 5100 was seen as cash spent.
+pub static ACCOLADE_NAMES: phf::Map<i32, &'static str> = phf_map! {
+    0_i32 => "none",
+    1_i32 => "mvps",
+    2_i32 => "kills",
+    3_i32 => "deaths",
+    4_i32 => "assists",
+    5_i32 => "damage",
+    6_i32 => "utility_damage",
+    7_i32 => "enemies_flashed",
+    8_i32 => "unique_weapon_kills",
+    9_i32 => "burn_damage",
+    10_i32 => "headshot_percentage",
+    11_i32 => "5k_rounds",
+    12_i32 => "4k_rounds",
+    13_i32 => "3k_rounds",
+    14_i32 => "first_kills",
+    15_i32 => "clutch_kills",
+    16_i32 => "pistol_kills",
+    17_i32 => "sniper_kills",
+    18_i32 => "knife_kills",
+    19_i32 => "bomb_carrier_kills",
+    20_i32 => "kill_reward",
+    21_i32 => "cash_spent",
+    22_i32 => "objectives",
+    23_i32 => "time_alive",
+    24_i32 => "chickens_killed",
+};
+
+// vote_type in CS_UM_VoteStart/CS_UM_VotePass
+pub static VOTE_TYPES: phf::Map<i32, &'static str> = phf_map! {
+    0_i32 => "kick",
+    1_i32 => "changelevel",
+    2_i32 => "nextlevel",
+    3_i32 => "swapteams",
+    4_i32 => "scrambleteams",
+    5_i32 => "restartgame",
+    6_i32 => "surrender",
+    7_i32 => "rematch",
+    8_i32 => "continue",
+    9_i32 => "pausematch",
+    10_i32 => "unpausematch",
+    11_i32 => "loadbackup",
+    12_i32 => "endwarmup",
+    13_i32 => "starttimeout",
+    14_i32 => "endtimeout",
+    15_i32 => "readyformatch",
+    16_i32 => "notreadyformatch",
+};
+
 pub static PLAYER_COLOR: phf::Map<i32, &'static str> = phf_map! {
     0_i32 => "blue",
     1_i32 => "green",
@@ -180,6 +252,45 @@ pub static BUTTONMAP: phf::Map<&'static str, u64> = phf_map! {
     "WALK" => 1 << 18,
 };
 
+// Max movement speed (unscoped) per weapon. Shots are accurate up to 34% of this.
+pub static WEAPON_MAX_SPEED: phf::Map<&'static str, f32> = phf_map! {
+    "weapon_glock" => 240.0,
+    "weapon_hkp2000" => 240.0,
+    "weapon_usp_silencer" => 240.0,
+    "weapon_p250" => 240.0,
+    "weapon_elite" => 240.0,
+    "weapon_fiveseven" => 240.0,
+    "weapon_tec9" => 240.0,
+    "weapon_cz75a" => 240.0,
+    "weapon_deagle" => 230.0,
+    "weapon_revolver" => 220.0,
+    "weapon_mac10" => 240.0,
+    "weapon_mp9" => 240.0,
+    "weapon_mp7" => 220.0,
+    "weapon_mp5sd" => 235.0,
+    "weapon_ump45" => 230.0,
+    "weapon_p90" => 230.0,
+    "weapon_bizon" => 240.0,
+    "weapon_famas" => 220.0,
+    "weapon_galilar" => 215.0,
+    "weapon_ak47" => 215.0,
+    "weapon_m4a1" => 225.0,
+    "weapon_m4a1_silencer" => 225.0,
+    "weapon_aug" => 220.0,
+    "weapon_sg556" => 210.0,
+    "weapon_ssg08" => 230.0,
+    "weapon_awp" => 200.0,
+    "weapon_g3sg1" => 215.0,
+    "weapon_scar20" => 215.0,
+    "weapon_nova" => 220.0,
+    "weapon_xm1014" => 215.0,
+    "weapon_sawedoff" => 210.0,
+    "weapon_mag7" => 225.0,
+    "weapon_m249" => 195.0,
+    "weapon_negev" => 150.0,
+    "weapon_taser" => 220.0,
+};
+
 pub static CUSTOM_PLAYER_PROP_IDS: phf::Map<&'static str, u32> = phf_map! {
     "entity_id" => ENTITY_ID_ID,
     "user_id"=> USERID_ID,
@@ -194,6 +305,12 @@ pub static CUSTOM_PLAYER_PROP_IDS: phf::Map<&'static str, u32> = phf_map! {
     "yaw"=> YAW_ID,
     "is_alive" => IS_ALIVE_ID,
     "is_airborne" => IS_AIRBORNE_ID,
+    "in_smoke" => IN_SMOKE_ID,
+    "in_fire" => IN_FIRE_ID,
+    "flash_remaining_seconds" => FLASH_REMAINING_SECONDS_ID,
+    "smoked_line_to_enemy" => SMOKED_LINE_TO_ENEMY_ID,
+    "radar_x" => RADAR_X_ID,
+    "radar_y" => RADAR_Y_ID,
     "agent_skin" => AGENT_SKIN_ID,
     "inventory" => INVENTORY_ID,
     "inventory_as_ids" => INVENTORY_AS_IDS_ID,
@@ -649,6 +766,12 @@ pub static TYPEHM: phf::Map<&'static str, PropType> = phf_map! {
     "weapon_float" => PropType::Custom,
     "weapon_stickers" => PropType::Custom,
     "is_airborne" => PropType::Custom,
+    "in_smoke" => PropType::Custom,
+    "in_fire" => PropType::Custom,
+    "flash_remaining_seconds" => PropType::Custom,
+    "smoked_line_to_enemy" => PropType::Custom,
+    "radar_x" => PropType::Custom,
+    "radar_y" => PropType::Custom,
     // Weapon
     "m_flAnimTime" => PropType::Weapon,
     "m_flSimulationTime"=> PropType::Weapon,
@@ -868,6 +991,7 @@ pub static FRIENDLY_NAMES_MAPPING: phf::Map<&'static str, &'static str> = phf_ma
     "pitch" => "pitch",
     "yaw" => "yaw",
     "game_time" => "game_time",
+    "seconds" => "seconds",
     "inventory" => "inventory",
     "inventory_as_ids" => "inventory_as_ids",
     "aim_punch_angle" => "CCSPlayerPawn.m_aimPunchAngle",
@@ -893,6 +1017,7 @@ pub static FRIENDLY_NAMES_MAPPING: phf::Map<&'static str, &'static str> = phf_ma
     "team_clan_name" =>"CCSTeam.m_szClanTeamname",
     "is_freeze_period"=>"CCSGameRulesProxy.CCSGameRules.m_bFreezePeriod",
     "is_warmup_period"=>"CCSGameRulesProxy.CCSGameRules.m_bWarmupPeriod" ,
+    "overtime_playing"=>"CCSGameRulesProxy.CCSGameRules.m_nOvertimePlaying" ,
     "warmup_period_end"=>"CCSGameRulesProxy.CCSGameRules.m_fWarmupPeriodEnd" ,
     "warmup_period_start"=>"CCSGameRulesProxy.CCSGameRules.m_fWarmupPeriodStart" ,
     "is_terrorist_timeout"=>"CCSGameRulesProxy.CCSGameRules.m_bTerroristTimeOutActive" ,
@@ -1131,6 +1256,17 @@ pub static FRIENDLY_NAMES_MAPPING: phf::Map<&'static str, &'static str> = phf_ma
     "next_secondary_attack_tick" => "m_nNextSecondaryAttackTick",
     "next_secondary_attack_tick_ratio"=> "m_flNextSecondaryAttackTickRatio",
     "is_airborne" => "is_airborne",
+    "in_smoke" => "in_smoke",
+    "in_fire" => "in_fire",
+    "flash_remaining_seconds" => "flash_remaining_seconds",
+    "smoked_line_to_enemy" => "smoked_line_to_enemy",
+    "radar_x" => "radar_x",
+    "radar_y" => "radar_y",
+    "round" => "round",
+    "round_time" => "round_time",
+    "time_since_freeze_end" => "time_since_freeze_end",
+    "time_since_bomb_plant" => "time_since_bomb_plant",
+    "is_warmup" => "is_warmup",
 };
 
 pub static GRENADE_FRIENDLY_NAMES: phf::Map<&'static str, &'static str> = phf_map! {
@@ -1158,4 +1294,9 @@ pub static NON_MULTITHREADABLE_PROPS: phf::Set<&'static str> = phf_set! {
     "CCSPlayerPawn.CCSPlayer_MovementServices.m_bInDuckJump",
     "CCSPlayerPawn.CCSPlayer_MovementServices.m_bDucking",
     "CCSPlayerPawn.CCSPlayer_MovementServices.m_nJumpTimeMsecs",
+    "round_time",
+    "time_since_freeze_end",
+    "time_since_bomb_plant",
+    // The flash start is only seen in delta updates, not in the fullpacket a chunk starts from
+    "flash_remaining_seconds",
 };
@@ -0,0 +1,468 @@
+use crate::analysis::find_field;
+use crate::second_pass::game_events::EventField;
+use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::variants::Variant;
+use ahash::AHashMap;
+use std::collections::BTreeMap;
+use std::fmt;
+
+// Reader for CS2 navigation meshes (maps/<map>.nav, versions 30-36) and a spatial lookup
+// from world positions to nav areas. CS2 nav files don't carry place names, these are
+// learned from the pawns' last_place_name (see NavMesh::learn_places).
+
+const NAV_MAGIC: u32 = 0xFEEDFACE;
+const NAV_MIN_VERSION: u32 = 30;
+const NAV_MAX_VERSION: u32 = 36;
+// Size of the cells in the 2D lookup grid, in world units
+const GRID_CELL_SIZE: f32 = 256.0;
+// How far above an area (its floor) a position can be and still count as being on it
+const MAX_HEIGHT_ABOVE_AREA: f32 = 128.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NavError {
+    FileNotFound,
+    IncorrectMagic(u32),
+    UnsupportedVersion(u32),
+    UnexpectedEndOfFile,
+    PolygonIndexOutOfBounds(u32),
+    CornerIndexOutOfBounds(u32),
+}
+impl std::error::Error for NavError {}
+impl fmt::Display for NavError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavConnection {
+    pub area_id: u32,
+    pub edge_id: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavArea {
+    pub area_id: u32,
+    pub hull_index: u8,
+    pub dynamic_attribute_flags: u64,
+    pub corners: Vec<[f32; 3]>,
+    pub connections: Vec<NavConnection>,
+    pub ladders_above: Vec<u32>,
+    pub ladders_below: Vec<u32>,
+}
+
+impl NavArea {
+    pub fn centroid(&self) -> [f32; 3] {
+        let n = self.corners.len().max(1) as f32;
+        let mut sum = [0.0_f32; 3];
+        for corner in &self.corners {
+            for (total, v) in sum.iter_mut().zip(corner) {
+                *total += v;
+            }
+        }
+        [sum[0] / n, sum[1] / n, sum[2] / n]
+    }
+    // Even-odd rule on the xy-plane
+    pub fn contains_2d(&self, x: f32, y: f32) -> bool {
+        let mut inside = false;
+        let n = self.corners.len();
+        for i in 0..n {
+            let a = self.corners[i];
+            let b = self.corners[(i + n - 1) % n];
+            if (a[1] > y) != (b[1] > y) && x < (b[0] - a[0]) * (y - a[1]) / (b[1] - a[1]) + a[0] {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+    fn bounds_2d(&self) -> ([f32; 2], [f32; 2]) {
+        let mut min = [f32::MAX; 2];
+        let mut max = [f32::MIN; 2];
+        for corner in &self.corners {
+            min = [min[0].min(corner[0]), min[1].min(corner[1])];
+            max = [max[0].max(corner[0]), max[1].max(corner[1])];
+        }
+        (min, max)
+    }
+}
+
+// The areas of one place name ("BombsiteA", "Middle" ...) as xy polygons in world coordinates
+#[derive(Debug, Clone, PartialEq)]
+pub struct Callout {
+    pub name: String,
+    pub polygons: Vec<Vec<[f32; 2]>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NavMesh {
+    pub version: u32,
+    pub sub_version: u32,
+    pub is_analyzed: bool,
+    pub areas: Vec<NavArea>,
+    // area_id -> place name, filled by learn_places
+    pub places: AHashMap<u32, String>,
+    grid: AHashMap<(i32, i32), Vec<usize>>,
+}
+
+struct NavReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> NavReader<'a> {
+    // Counts come from the file, never reserve more items than the remaining bytes can hold
+    fn capacity(&self, count: u32, item_size: usize) -> usize {
+        (count as usize).min((self.bytes.len() - self.pos) / item_size)
+    }
+    fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], NavError> {
+        let end = self.pos + n;
+        if end > self.bytes.len() {
+            return Err(NavError::UnexpectedEndOfFile);
+        }
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+    fn read_u8(&mut self) -> Result<u8, NavError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+    fn read_u32(&mut self) -> Result<u32, NavError> {
+        Ok(u32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+    fn read_u64(&mut self) -> Result<u64, NavError> {
+        Ok(u64::from_le_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    }
+    fn read_f32(&mut self) -> Result<f32, NavError> {
+        Ok(f32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+    fn read_vector(&mut self) -> Result<[f32; 3], NavError> {
+        Ok([self.read_f32()?, self.read_f32()?, self.read_f32()?])
+    }
+}
+
+impl NavMesh {
+    pub fn from_path(path: &str) -> Result<NavMesh, NavError> {
+        let bytes = std::fs::read(path).map_err(|_| NavError::FileNotFound)?;
+        NavMesh::from_bytes(&bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<NavMesh, NavError> {
+        let mut reader = NavReader { bytes, pos: 0 };
+        let magic = reader.read_u32()?;
+        if magic != NAV_MAGIC {
+            return Err(NavError::IncorrectMagic(magic));
+        }
+        let version = reader.read_u32()?;
+        if !(NAV_MIN_VERSION..=NAV_MAX_VERSION).contains(&version) {
+            return Err(NavError::UnsupportedVersion(version));
+        }
+        let sub_version = reader.read_u32()?;
+        let is_analyzed = reader.read_u32()? & 1 == 1;
+
+        let polygons = if version >= 31 {
+            Some(NavMesh::read_polygons(&mut reader, version)?)
+        } else {
+            None
+        };
+        if version >= 32 {
+            let _unk = reader.read_u32()?;
+        }
+        if version >= 35 {
+            let _unk = reader.read_u32()?;
+        }
+        let area_count = reader.read_u32()?;
+        let mut areas = Vec::with_capacity(reader.capacity(area_count, 1));
+        for _ in 0..area_count {
+            areas.push(NavMesh::read_area(&mut reader, version, polygons.as_deref())?);
+        }
+        let mut mesh = NavMesh {
+            version,
+            sub_version,
+            is_analyzed,
+            areas,
+            places: AHashMap::default(),
+            grid: AHashMap::default(),
+        };
+        mesh.build_grid();
+        Ok(mesh)
+    }
+
+    fn read_polygons(reader: &mut NavReader, version: u32) -> Result<Vec<Vec<[f32; 3]>>, NavError> {
+        let corner_count = reader.read_u32()?;
+        let mut corners = Vec::with_capacity(reader.capacity(corner_count, 12));
+        for _ in 0..corner_count {
+            corners.push(reader.read_vector()?);
+        }
+        let polygon_count = reader.read_u32()?;
+        let mut polygons = Vec::with_capacity(reader.capacity(polygon_count, 1));
+        for _ in 0..polygon_count {
+            let n = reader.read_u8()?;
+            let mut polygon = Vec::with_capacity(reader.capacity(n as u32, 4));
+            for _ in 0..n {
+                let idx = reader.read_u32()?;
+                match corners.get(idx as usize) {
+                    Some(corner) => polygon.push(*corner),
+                    None => return Err(NavError::CornerIndexOutOfBounds(idx)),
+                }
+            }
+            if version >= 35 {
+                let _unk = reader.read_u32()?;
+            }
+            polygons.push(polygon);
+        }
+        Ok(polygons)
+    }
+
+    fn read_area(reader: &mut NavReader, version: u32, polygons: Option<&[Vec<[f32; 3]>]>) -> Result<NavArea, NavError> {
+        let area_id = reader.read_u32()?;
+        let dynamic_attribute_flags = reader.read_u64()?;
+        let hull_index = reader.read_u8()?;
+        let corners = match polygons {
+            Some(polygons) => {
+                let idx = reader.read_u32()?;
+                match polygons.get(idx as usize) {
+                    Some(polygon) => polygon.clone(),
+                    None => return Err(NavError::PolygonIndexOutOfBounds(idx)),
+                }
+            }
+            None => {
+                let n = reader.read_u32()?;
+                let mut corners = Vec::with_capacity(reader.capacity(n, 12));
+                for _ in 0..n {
+                    corners.push(reader.read_vector()?);
+                }
+                corners
+            }
+        };
+        let _almost_always_zero = reader.read_f32()?;
+
+        // One list of connections per edge of the area
+        let mut connections = vec![];
+        for _ in 0..corners.len() {
+            let n = reader.read_u32()?;
+            for _ in 0..n {
+                connections.push(NavConnection {
+                    area_id: reader.read_u32()?,
+                    edge_id: reader.read_u32()?,
+                });
+            }
+        }
+        let _legacy_hiding_spot_count = reader.read_u8()?;
+
+        let mut ladders = [vec![], vec![]];
+        for ladder_ids in ladders.iter_mut() {
+            let n = reader.read_u32()?;
+            for _ in 0..n {
+                ladder_ids.push(reader.read_u32()?);
+            }
+        }
+        // Earliest occupy time for both teams
+        let _ = reader.read_f32()?;
+        let _ = reader.read_f32()?;
+        if version <= 35 {
+            let _unk = reader.read_u32()?;
+        }
+        let unk_count = reader.read_u32()?;
+        reader.read_bytes(unk_count as usize * 4)?;
+
+        let [ladders_above, ladders_below] = ladders;
+        Ok(NavArea {
+            area_id,
+            hull_index,
+            dynamic_attribute_flags,
+            corners,
+            connections,
+            ladders_above,
+            ladders_below,
+        })
+    }
+
+    fn grid_cell(x: f32, y: f32) -> (i32, i32) {
+        ((x / GRID_CELL_SIZE).floor() as i32, (y / GRID_CELL_SIZE).floor() as i32)
+    }
+
+    fn build_grid(&mut self) {
+        for (idx, area) in self.areas.iter().enumerate() {
+            if area.corners.is_empty() {
+                continue;
+            }
+            let (min, max) = area.bounds_2d();
+            let (min_x, min_y) = NavMesh::grid_cell(min[0], min[1]);
+            let (max_x, max_y) = NavMesh::grid_cell(max[0], max[1]);
+            for cell_x in min_x..=max_x {
+                for cell_y in min_y..=max_y {
+                    self.grid.entry((cell_x, cell_y)).or_default().push(idx);
+                }
+            }
+        }
+    }
+
+    // The area the position stands on: the area containing the position on the xy-plane
+    // whose floor is closest below the position (or closest above, when below every area).
+    pub fn find_area(&self, pos: [f32; 3]) -> Option<&NavArea> {
+        let candidates = self.grid.get(&NavMesh::grid_cell(pos[0], pos[1]))?;
+        candidates
+            .iter()
+            .map(|idx| &self.areas[*idx])
+            .filter(|area| area.contains_2d(pos[0], pos[1]))
+            .filter(|area| pos[2] - area.centroid()[2] <= MAX_HEIGHT_ABOVE_AREA)
+            .min_by(|a, b| {
+                let da = (pos[2] - a.centroid()[2]).abs();
+                let db = (pos[2] - b.centroid()[2]).abs();
+                da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+            })
+    }
+
+    // Assigns every area the place name most often seen for positions inside it.
+    pub fn learn_places(&mut self, samples: &[([f32; 3], String)]) {
+        let mut votes: AHashMap<u32, AHashMap<&str, u32>> = AHashMap::default();
+        for (pos, place) in samples {
+            if place.is_empty() {
+                continue;
+            }
+            if let Some(area) = self.find_area(*pos) {
+                *votes.entry(area.area_id).or_default().entry(place.as_str()).or_default() += 1;
+            }
+        }
+        for (area_id, counts) in votes {
+            if let Some((place, _)) = counts.into_iter().max_by_key(|(place, count)| (*count, std::cmp::Reverse(*place))) {
+                self.places.insert(area_id, place.to_string());
+            }
+        }
+    }
+
+    pub fn place_name(&self, area_id: u32) -> Option<&String> {
+        self.places.get(&area_id)
+    }
+
+    // Outlines of the areas grouped by learned place name, sorted by name
+    pub fn callouts(&self) -> Vec<Callout> {
+        let mut callouts: BTreeMap<&str, Vec<Vec<[f32; 2]>>> = BTreeMap::default();
+        for area in &self.areas {
+            if let Some(place) = self.place_name(area.area_id) {
+                callouts.entry(place).or_default().push(area.corners.iter().map(|c| [c[0], c[1]]).collect());
+            }
+        }
+        callouts
+            .into_iter()
+            .map(|(name, polygons)| Callout {
+                name: name.to_string(),
+                polygons,
+            })
+            .collect()
+    }
+}
+
+// Adds <prefix>_nav_area_id, <prefix>_nav_area_X/Y/Z and <prefix>_nav_place to events for
+// every prefix that has <prefix>_X, <prefix>_Y and <prefix>_Z fields. Place names are learned
+// from the <prefix>_last_place_name fields when the events contain them.
+pub fn add_nav_fields(events: &mut [GameEvent], nav: &mut NavMesh) {
+    let mut samples = vec![];
+    for event in events.iter() {
+        for prefix in position_prefixes(event) {
+            let place = match find_field(event, &format!("{prefix}_last_place_name")) {
+                Some(Variant::String(s)) => s.clone(),
+                _ => continue,
+            };
+            if let Some(pos) = find_position(event, &prefix) {
+                samples.push((pos, place));
+            }
+        }
+    }
+    nav.learn_places(&samples);
+
+    for event in events.iter_mut() {
+        for prefix in position_prefixes(event) {
+            let area = find_position(event, &prefix).and_then(|pos| nav.find_area(pos));
+            let centroid = area.map(|a| a.centroid());
+            let fields = [
+                ("nav_area_id", area.map(|a| Variant::U32(a.area_id))),
+                ("nav_area_X", centroid.map(|c| Variant::F32(c[0]))),
+                ("nav_area_Y", centroid.map(|c| Variant::F32(c[1]))),
+                ("nav_area_Z", centroid.map(|c| Variant::F32(c[2]))),
+                ("nav_place", area.and_then(|a| nav.place_name(a.area_id)).map(|p| Variant::String(p.clone()))),
+            ];
+            for (name, data) in fields {
+                event.fields.push(EventField {
+                    name: format!("{prefix}_{name}"),
+                    data,
+                });
+            }
+        }
+    }
+}
+
+fn position_prefixes(event: &GameEvent) -> Vec<String> {
+    event
+        .fields
+        .iter()
+        .filter_map(|f| f.name.strip_suffix("_X"))
+        .filter(|prefix| !prefix.ends_with("_nav_area"))
+        .map(|prefix| prefix.to_string())
+        .collect()
+}
+
+fn find_position(event: &GameEvent, prefix: &str) -> Option<[f32; 3]> {
+    match (
+        find_field(event, &format!("{prefix}_X")),
+        find_field(event, &format!("{prefix}_Y")),
+        find_field(event, &format!("{prefix}_Z")),
+    ) {
+        (Some(Variant::F32(x)), Some(Variant::F32(y)), Some(Variant::F32(z))) => Some([*x, *y, *z]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(area_id: u32, z: f32) -> NavArea {
+        NavArea {
+            area_id,
+            hull_index: 0,
+            dynamic_attribute_flags: 0,
+            corners: vec![[0.0, 0.0, z], [100.0, 0.0, z], [100.0, 100.0, z], [0.0, 100.0, z]],
+            connections: vec![],
+            ladders_above: vec![],
+            ladders_below: vec![],
+        }
+    }
+
+    #[test]
+    fn test_find_area() {
+        let mut mesh = NavMesh {
+            version: 35,
+            sub_version: 0,
+            is_analyzed: true,
+            areas: vec![area(1, 0.0), area(2, 200.0)],
+            places: AHashMap::default(),
+            grid: AHashMap::default(),
+        };
+        mesh.build_grid();
+        assert_eq!(mesh.find_area([50.0, 50.0, 10.0]).map(|a| a.area_id), Some(1));
+        assert_eq!(mesh.find_area([50.0, 50.0, 210.0]).map(|a| a.area_id), Some(2));
+        assert_eq!(mesh.find_area([150.0, 50.0, 10.0]), None);
+
+        mesh.learn_places(&[([10.0, 10.0, 0.0], "BombsiteA".to_string()), ([20.0, 20.0, 0.0], "BombsiteA".to_string())]);
+        assert_eq!(mesh.place_name(1), Some(&"BombsiteA".to_string()));
+        assert_eq!(mesh.place_name(2), None);
+
+        let callouts = mesh.callouts();
+        assert_eq!(callouts.len(), 1);
+        assert_eq!(callouts[0].name, "BombsiteA");
+        assert_eq!(callouts[0].polygons, vec![vec![[0.0, 0.0], [100.0, 0.0], [100.0, 100.0], [0.0, 100.0]]]);
+    }
+
+    #[test]
+    fn test_rejects_other_files() {
+        assert_eq!(NavMesh::from_bytes(&[0, 0, 0, 0]).unwrap_err(), NavError::IncorrectMagic(0));
+        assert_eq!(NavMesh::from_bytes(&[0xCE, 0xFA]).unwrap_err(), NavError::UnexpectedEndOfFile);
+        // Header claiming u32::MAX corners with nothing after it
+        let mut truncated = vec![];
+        for v in [NAV_MAGIC, 35, 0, 1, u32::MAX] {
+            truncated.extend_from_slice(&v.to_le_bytes());
+        }
+        assert_eq!(NavMesh::from_bytes(&truncated).unwrap_err(), NavError::UnexpectedEndOfFile);
+    }
+}
@@ -4,12 +4,19 @@ use crate::first_pass::parser_settings::check_multithreadability;
 use crate::first_pass::parser_settings::{FirstPassParser, ParserInputs};
 use crate::first_pass::prop_controller::{PropController, NAME_ID, STEAMID_ID, TICK_ID};
 use crate::first_pass::read_bits::DemoParserError;
+use crate::first_pass::stringtables::StringTableRecord;
 use crate::second_pass::collect_data::ProjectileRecord;
+use crate::second_pass::economy::EconomySnapshot;
 use crate::second_pass::game_events::{EventField, GameEvent};
+use crate::second_pass::grenade_areas::{InfernoRecord, SmokeRecord};
+use crate::second_pass::match_interruptions::MatchInterruption;
+use crate::second_pass::net_messages::NetMessageRecord;
 use crate::second_pass::parser::SecondPassOutput;
 use crate::second_pass::parser_settings::*;
+use crate::second_pass::subtick::{add_subtick_fields, SubtickShot};
 use crate::second_pass::variants::VarVec;
 use crate::second_pass::variants::{PropColumn, Variant};
+use crate::second_pass::visibility::{merge_visibility, VisibilityRecord};
 use ahash::AHashMap;
 use ahash::AHashSet;
 use csgoproto::CsvcMsgVoiceData;
@@ -28,12 +35,25 @@ pub struct DemoOutput {
     pub game_events: Vec<GameEvent>,
     pub skins: Vec<EconItem>,
     pub item_drops: Vec<EconItem>,
+    pub player_stats_updates: Vec<PlayerStatsUpdate>,
+    pub accolades: Vec<Accolade>,
+    // Only complete with ParsingMode::ForceSingleThreaded, an interruption that ends in a later chunk keeps end_tick None
+    pub match_interruptions: Vec<MatchInterruption>,
+    pub net_messages: Vec<NetMessageRecord>,
+    pub convar_changes: Vec<ConvarChange>,
+    pub string_table_records: Vec<StringTableRecord>,
     pub chat_messages: Vec<ChatMessageRecord>,
     pub convars: AHashMap<String, String>,
     pub header: Option<AHashMap<String, String>>,
+    // Seconds per tick, from CSVCMsg_ServerInfo
+    pub tick_interval: f32,
     pub player_md: Vec<PlayerEndMetaData>,
     pub game_events_counter: AHashSet<String>,
     pub projectiles: Vec<ProjectileRecord>,
+    pub infernos: Vec<InfernoRecord>,
+    pub smokes: Vec<SmokeRecord>,
+    pub economy_snapshots: Vec<EconomySnapshot>,
+    pub visibility: Vec<VisibilityRecord>,
     pub voice_data: Vec<CsvcMsgVoiceData>,
     pub prop_controller: PropController,
     pub df_per_player: AHashMap<u64, AHashMap<u32, PropColumn>>,
@@ -61,7 +81,9 @@ impl<'a> Parser<'a> {
         let mut first_pass_parser = FirstPassParser::new(&self.input);
         let first_pass_output = first_pass_parser.parse_demo(&demo_bytes, false)?;
         if self.parsing_mode == ParsingMode::ForceMultiThreaded
-            || check_multithreadability(&self.input.wanted_player_props) && !(self.parsing_mode == ParsingMode::ForceSingleThreaded)
+            || check_multithreadability(&self.input.wanted_player_props)
+                && check_multithreadability(&self.input.wanted_other_props)
+                && !(self.parsing_mode == ParsingMode::ForceSingleThreaded)
         {
             return self.second_pass_multi_threaded(demo_bytes, first_pass_output);
         } else {
@@ -232,6 +254,22 @@ impl<'a> Parser<'a> {
             }
         }
     }
+    // Each chunk only knows the values it has seen itself, so old values are filled in again
+    // over the whole demo. The final map is the last value of every convar.
+    fn combine_convar_changes(mut changes: Vec<ConvarChange>) -> (Vec<ConvarChange>, AHashMap<String, String>) {
+        changes.sort_by_key(|change| change.tick);
+        let mut convars: AHashMap<String, String> = AHashMap::default();
+        let mut combined = vec![];
+        for mut change in changes {
+            let old_value = convars.insert(change.name.clone(), change.new_value.clone());
+            if old_value.as_ref() == Some(&change.new_value) {
+                continue;
+            }
+            change.old_value = old_value;
+            combined.push(change);
+        }
+        (combined, convars)
+    }
     fn rm_unwanted_ticks(&self, hm: &mut AHashMap<u32, PropColumn>) -> Option<AHashMap<u32, PropColumn>> {
         // Used for removing ticks when velocity is needed
         if self.input.wanted_ticks.is_empty() {
@@ -290,18 +328,36 @@ impl<'a> Parser<'a> {
             pp.insert(*steamid, combined);
         }
 
+        let mut game_events: Vec<GameEvent> = second_pass_outputs.iter().flat_map(|x| x.game_events.clone()).collect();
+        let subtick_shots: Vec<SubtickShot> = second_pass_outputs.iter().flat_map(|x| x.subtick_shots.clone()).collect();
+        if self.input.parse_shot_details {
+            add_subtick_fields(&mut game_events, &subtick_shots);
+        }
+        let (convar_changes, convars) = Parser::combine_convar_changes(second_pass_outputs.iter().flat_map(|x| x.convar_changes.clone()).collect());
+
         DemoOutput {
             prop_controller: prop_controller,
             chat_messages: second_pass_outputs.iter().flat_map(|x| x.chat_messages.clone()).collect(),
             item_drops: second_pass_outputs.iter().flat_map(|x| x.item_drops.clone()).collect(),
+            player_stats_updates: second_pass_outputs.iter().flat_map(|x| x.player_stats_updates.clone()).collect(),
+            accolades: second_pass_outputs.iter().flat_map(|x| x.accolades.clone()).collect(),
+            match_interruptions: second_pass_outputs.iter().flat_map(|x| x.match_interruptions.clone()).collect(),
+            net_messages: second_pass_outputs.iter().flat_map(|x| x.net_messages.clone()).collect(),
+            convar_changes,
+            string_table_records: second_pass_outputs.iter().flat_map(|x| x.string_table_records.clone()).collect(),
             player_md: second_pass_outputs.iter().flat_map(|x| x.player_md.clone()).collect(),
-            game_events: second_pass_outputs.iter().flat_map(|x| x.game_events.clone()).collect(),
+            game_events,
             skins: second_pass_outputs.iter().flat_map(|x| x.skins.clone()).collect(),
-            convars: second_pass_outputs.iter().flat_map(|x| x.convars.clone()).collect(),
+            convars,
             df: all_dfs_combined,
+            tick_interval: first_pass_output.tick_interval,
             header: Some(first_pass_output.header),
             game_events_counter: all_game_events,
             projectiles: second_pass_outputs.iter().flat_map(|x| x.projectiles.clone()).collect(),
+            infernos: second_pass_outputs.iter().flat_map(|x| x.infernos.clone()).collect(),
+            smokes: second_pass_outputs.iter().flat_map(|x| x.smokes.clone()).collect(),
+            economy_snapshots: second_pass_outputs.iter().flat_map(|x| x.economy_snapshots.clone()).collect(),
+            visibility: merge_visibility(second_pass_outputs.iter().flat_map(|x| x.visibility.clone()).collect()),
             voice_data: second_pass_outputs.iter().flat_map(|x| x.voice_data.clone()).collect_vec(),
             df_per_player: pp,
         }
@@ -369,3 +425,41 @@ impl SellBackHelper {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(tick: i32, name: &str, new_value: &str) -> ConvarChange {
+        ConvarChange {
+            tick,
+            name: name.to_string(),
+            old_value: None,
+            new_value: new_value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_combine_convar_changes() {
+        // The later chunk comes back first and repeats the value it saw last
+        let second_chunk = vec![change(300, "sv_cheats", "1"), change(600, "sv_cheats", "1")];
+        let first_chunk = vec![change(100, "sv_cheats", "0"), change(500, "mp_roundtime", "2")];
+        let (changes, convars) = Parser::combine_convar_changes(second_chunk.into_iter().chain(first_chunk).collect());
+
+        let changes: Vec<_> = changes
+            .iter()
+            .map(|c| (c.tick, c.name.as_str(), c.old_value.as_deref(), c.new_value.as_str()))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (100, "sv_cheats", None, "0"),
+                (300, "sv_cheats", Some("0"), "1"),
+                (500, "mp_roundtime", None, "2"),
+            ]
+        );
+        assert_eq!(convars.len(), 2);
+        assert_eq!(convars["sv_cheats"], "1");
+        assert_eq!(convars["mp_roundtime"], "2");
+    }
+}
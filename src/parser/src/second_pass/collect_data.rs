@@ -30,6 +30,7 @@ pub enum PropType {
     Steamid,
     Tick,
     GameTime,
+    RoundTiming,
 }
 
 // DONT KNOW IF THESE ARE CORRECT. SEEMS TO GIVE CORRECT VALUES
@@ -37,6 +38,11 @@ const CELL_BITS: i32 = 9;
 const MAX_COORD: f32 = (1 << 14) as f32;
 // https://github.com/markus-wa/demoinfocs-golang/blob/master/pkg/demoinfocs/constants/constants.go#L11
 const IS_AIRBORNE_CONST: u32 = 0xFFFFFF;
+// Approximate extents of the utility volumes, in game units
+const SMOKE_RADIUS: f32 = 144.0;
+const INFERNO_FIRE_RADIUS: f32 = 30.0;
+const INFERNO_FIRE_HEIGHT: f32 = 64.0;
+pub(crate) const PLAYER_EYE_HEIGHT: f32 = 64.0;
 
 #[derive(Debug, Clone)]
 pub struct ProjectileRecord {
@@ -59,13 +65,15 @@ pub enum CoordinateAxis {
 
 impl<'a> SecondPassParser<'a> {
     pub fn collect_entities(&mut self) {
-        if !self.prop_controller.event_with_velocity {
-            if !self.wanted_ticks.contains(&self.tick) && self.wanted_ticks.len() != 0 || self.wanted_events.len() != 0 {
-                return;
-            }
-        }
-        if self.parse_projectiles {
+        let tick_wanted = self.wanted_ticks.contains(&self.tick) || self.wanted_ticks.is_empty();
+        // Projectiles are collected alongside events so utility damage can be linked to grenades
+        if self.parse_projectiles && tick_wanted {
             self.collect_projectiles();
+            self.collect_infernos();
+            self.collect_smokes();
+        }
+        if !self.prop_controller.event_with_velocity && (!tick_wanted || (!self.wanted_events.is_empty() && !self.parse_ticks_with_events)) {
+            return;
         }
         // iterate every player and every wanted prop name
         // if either one is missing then push None to output
@@ -134,7 +142,8 @@ impl<'a> SecondPassParser<'a> {
             PropType::Button => return self.get_button_prop(&prop_info, &entity_id),
             PropType::Controller => return self.get_controller_prop(&prop_info.id, player),
             PropType::Rules => return self.get_rules_prop(prop_info),
-            PropType::GameTime => return Ok(Variant::F32(self.net_tick as f32 / 64.0)),
+            PropType::GameTime => Ok(self.find_time_prop(&prop_info.prop_name)),
+            PropType::RoundTiming => self.find_round_timing_prop(&prop_info.prop_name),
         }
     }
     pub fn get_prop_from_ent(&self, prop_id: &u32, entity_id: &i32) -> Result<Variant, PropCollectionError> {
@@ -146,6 +155,15 @@ impl<'a> SecondPassParser<'a> {
             _ => return Err(PropCollectionError::GetPropFromEntEntityNotFound),
         }
     }
+    // "game_time" is the server time from net_tick. "seconds" is the time since the demo started
+    // and follows the demo tick, so it lines up with the tick column even when net_tick is offset
+    // from it (demos recorded mid-match) or jumps.
+    pub fn find_time_prop(&self, prop_name: &str) -> Variant {
+        match prop_name {
+            "seconds" => Variant::F32(self.tick as f32 * self.tick_interval),
+            _ => Variant::F32(self.net_tick as f32 * self.tick_interval),
+        }
+    }
     fn create_tick(&self) -> Result<Variant, PropCollectionError> {
         // This can't actually fail
         return Ok(Variant::I32(self.tick));
@@ -438,6 +456,12 @@ impl<'a> SecondPassParser<'a> {
             "is_alive" => return self.find_is_alive(entity_id),
             "user_id" => return self.get_userid(player),
             "is_airborne" => self.find_is_airborne(player),
+            "in_smoke" => self.find_in_smoke(entity_id),
+            "in_fire" => self.find_in_fire(entity_id),
+            "flash_remaining_seconds" => self.find_flash_remaining_seconds(entity_id),
+            "smoked_line_to_enemy" => self.find_smoked_line_to_enemy(entity_id, player),
+            "radar_x" => self.find_radar_coordinate(entity_id, CoordinateAxis::X),
+            "radar_y" => self.find_radar_coordinate(entity_id, CoordinateAxis::Y),
             "agent_skin" => return self.find_agent_skin(player),
             "CCSPlayerController.m_iCompTeammateColor" => return self.find_player_color(player, prop_info),
             "usercmd_input_history" => self.get_prop_from_ent(&USERCMD_INPUT_HISTORY_BASEID, entity_id),
@@ -473,6 +497,79 @@ impl<'a> SecondPassParser<'a> {
         }
         Ok(Variant::Bool(false))
     }
+    pub fn find_player_position(&self, entity_id: &i32) -> Result<[f32; 3], PropCollectionError> {
+        let x = self.collect_cell_coordinate_player(CoordinateAxis::X, entity_id)?;
+        let y = self.collect_cell_coordinate_player(CoordinateAxis::Y, entity_id)?;
+        let z = self.collect_cell_coordinate_player(CoordinateAxis::Z, entity_id)?;
+        match (x, y, z) {
+            (Variant::F32(x), Variant::F32(y), Variant::F32(z)) => Ok([x, y, z]),
+            _ => Err(PropCollectionError::CoordinateIncorrectTypes),
+        }
+    }
+    fn active_smoke_positions(&self) -> Vec<[f32; 3]> {
+        self.projectiles.iter().filter_map(|entid| self.find_active_smoke(entid)).collect()
+    }
+    pub fn find_in_smoke(&self, entity_id: &i32) -> Result<Variant, PropCollectionError> {
+        let pos = self.find_player_position(entity_id)?;
+        Ok(Variant::Bool(is_in_smoke(&pos, &self.active_smoke_positions())))
+    }
+    pub fn find_in_fire(&self, entity_id: &i32) -> Result<Variant, PropCollectionError> {
+        let pos = self.find_player_position(entity_id)?;
+        let fires: Vec<[f32; 3]> = self
+            .infernos
+            .iter()
+            .flat_map(|inferno_entid| self.find_burning_fires(inferno_entid))
+            .map(|(_, fire)| fire)
+            .collect();
+        Ok(Variant::Bool(is_in_fire(&pos, &fires)))
+    }
+    pub fn find_flash_remaining_seconds(&self, entity_id: &i32) -> Result<Variant, PropCollectionError> {
+        let flash_id = match self.prop_controller.special_ids.flash_duration {
+            Some(id) => id,
+            None => return Err(PropCollectionError::SpecialidsFlashDurationNotSet),
+        };
+        let duration = match self.get_prop_from_ent(&flash_id, entity_id) {
+            Ok(Variant::F32(d)) => d,
+            Ok(_) => return Err(PropCollectionError::FlashDurationIncorrectVariant),
+            Err(e) => return Err(e),
+        };
+        let remaining = flash_remaining(duration, self.flash_started_at.get(entity_id).copied(), self.tick, self.tick_interval)?;
+        Ok(Variant::F32(remaining))
+    }
+    pub fn find_smoked_line_to_enemy(&self, entity_id: &i32, player: &PlayerMetaData) -> Result<Variant, PropCollectionError> {
+        let pos = self.find_player_position(entity_id)?;
+        let eye = [pos[0], pos[1], pos[2] + PLAYER_EYE_HEIGHT];
+        let smokes = self.active_smoke_positions();
+        if smokes.is_empty() {
+            return Ok(Variant::Bool(false));
+        }
+        for (enemy_entid, enemy) in &self.players {
+            if enemy.team_num == player.team_num || enemy.team_num.is_none() {
+                continue;
+            }
+            if self.find_is_alive(enemy_entid) != Ok(Variant::Bool(true)) {
+                continue;
+            }
+            let enemy_pos = match self.find_player_position(enemy_entid) {
+                Ok(p) => p,
+                Err(_) => continue,
+            };
+            let enemy_eye = [enemy_pos[0], enemy_pos[1], enemy_pos[2] + PLAYER_EYE_HEIGHT];
+            if smokes.iter().any(|smoke| segment_intersects_sphere(&eye, &enemy_eye, smoke, SMOKE_RADIUS)) {
+                return Ok(Variant::Bool(true));
+            }
+        }
+        Ok(Variant::Bool(false))
+    }
+    pub fn find_radar_coordinate(&self, entity_id: &i32, axis: CoordinateAxis) -> Result<Variant, PropCollectionError> {
+        let map_data = self.map_data.ok_or(PropCollectionError::MapDataNotFound)?;
+        let pos = self.find_player_position(entity_id)?;
+        let radar = map_data.world_to_radar(pos[0], pos[1]);
+        match axis {
+            CoordinateAxis::X => Ok(Variant::F32(radar[0])),
+            _ => Ok(Variant::F32(radar[1])),
+        }
+    }
     pub fn find_skin_float(&self, player: &PlayerMetaData) -> Result<Variant, PropCollectionError> {
         if let Some(player_entity_id) = &player.player_entity_id {
             return self.find_weapon_prop(&WEAPON_FLOAT, &player_entity_id);
@@ -614,7 +711,7 @@ impl<'a> SecondPassParser<'a> {
         };
         if let Some(c) = col {
             if let Some((Some(v1), Some(v2))) = self.index_coordinates_from_propcol(c, indicies) {
-                return Ok(Variant::F32((v1 * 64.0) - (v2 * 64.0)));
+                return Ok(Variant::F32((v1 - v2) / self.tick_interval));
             }
         }
         return Err(PropCollectionError::VelocityNotFound);
@@ -1031,6 +1128,53 @@ impl<'a> SecondPassParser<'a> {
     }
 }
 
+fn distance(a: &[f32; 3], b: &[f32; 3]) -> f32 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+// Either the feet or the eyes are inside one of the smokes
+fn is_in_smoke(pos: &[f32; 3], smokes: &[[f32; 3]]) -> bool {
+    let eye = [pos[0], pos[1], pos[2] + PLAYER_EYE_HEIGHT];
+    smokes
+        .iter()
+        .any(|smoke| distance(pos, smoke) <= SMOKE_RADIUS || distance(&eye, smoke) <= SMOKE_RADIUS)
+}
+
+// Fire cells are treated as cylinders standing on the cell position
+fn is_in_fire(pos: &[f32; 3], fires: &[[f32; 3]]) -> bool {
+    fires.iter().any(|fire| {
+        let horizontal = ((pos[0] - fire[0]).powi(2) + (pos[1] - fire[1]).powi(2)).sqrt();
+        horizontal <= INFERNO_FIRE_RADIUS && (pos[2] - fire[2]).abs() <= INFERNO_FIRE_HEIGHT
+    })
+}
+
+fn flash_remaining(duration: f32, started_at: Option<i32>, tick: i32, tick_interval: f32) -> Result<f32, PropCollectionError> {
+    if duration <= 0.0 {
+        return Ok(0.0);
+    }
+    match started_at {
+        Some(started_at) => {
+            let elapsed = (tick - started_at) as f32 * tick_interval;
+            Ok((duration - elapsed).max(0.0))
+        }
+        // Only happens if the flash started before the parsing did
+        None => Err(PropCollectionError::FlashStartNotFound),
+    }
+}
+
+fn segment_intersects_sphere(start: &[f32; 3], end: &[f32; 3], center: &[f32; 3], radius: f32) -> bool {
+    // Closest point on the segment to the center of the sphere
+    let d = [end[0] - start[0], end[1] - start[1], end[2] - start[2]];
+    let len_sq = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+    let t = if len_sq == 0.0 {
+        0.0
+    } else {
+        (((center[0] - start[0]) * d[0] + (center[1] - start[1]) * d[1] + (center[2] - start[2]) * d[2]) / len_sq).clamp(0.0, 1.0)
+    };
+    let closest = [start[0] + t * d[0], start[1] + t * d[1], start[2] + t * d[2]];
+    distance(&closest, center) <= radius
+}
+
 fn coord_from_cell(cell: Result<Variant, PropCollectionError>, offset: Result<Variant, PropCollectionError>) -> Result<f32, PropCollectionError> {
     // Both cell and offset are needed for calculation
     match (offset, cell) {
@@ -1106,6 +1250,11 @@ pub enum PropCollectionError {
     AgentSpecialIdNotSet,
     UseridNotFound,
     InventoryMaxNotFound,
+    SpecialidsFlashDurationNotSet,
+    FlashDurationIncorrectVariant,
+    FlashStartNotFound,
+    MapDataNotFound,
+    RoundTimingNotSet,
 }
 impl std::error::Error for PropCollectionError {}
 impl fmt::Display for PropCollectionError {
@@ -1113,3 +1262,48 @@ impl fmt::Display for PropCollectionError {
         write!(f, "{:?}", self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_intersects_sphere() {
+        let smoke = [0.0, 0.0, 0.0];
+        // Line straight through the smoke
+        assert!(segment_intersects_sphere(&[-500.0, 0.0, 0.0], &[500.0, 0.0, 0.0], &smoke, SMOKE_RADIUS));
+        // Line passing beside the smoke
+        assert!(!segment_intersects_sphere(&[-500.0, 200.0, 0.0], &[500.0, 200.0, 0.0], &smoke, SMOKE_RADIUS));
+        // Both ends on the same side, smoke is behind the enemy
+        assert!(!segment_intersects_sphere(&[-500.0, 0.0, 0.0], &[-300.0, 0.0, 0.0], &smoke, SMOKE_RADIUS));
+    }
+
+    #[test]
+    fn test_is_in_smoke() {
+        let smokes = [[0.0, 0.0, 0.0]];
+        assert!(is_in_smoke(&[100.0, 0.0, 0.0], &smokes));
+        assert!(!is_in_smoke(&[200.0, 0.0, 0.0], &smokes));
+        // Feet are below the smoke but the eyes are in it
+        assert!(is_in_smoke(&[0.0, 0.0, -200.0], &smokes));
+        assert!(!is_in_smoke(&[0.0, 0.0, 0.0], &[]));
+    }
+
+    #[test]
+    fn test_is_in_fire() {
+        let fires = [[0.0, 0.0, 0.0], [500.0, 0.0, 0.0]];
+        assert!(is_in_fire(&[20.0, 0.0, 10.0], &fires));
+        assert!(is_in_fire(&[510.0, 10.0, 0.0], &fires));
+        assert!(!is_in_fire(&[100.0, 0.0, 0.0], &fires));
+        // Standing on a ledge above the fire
+        assert!(!is_in_fire(&[0.0, 0.0, 100.0], &fires));
+    }
+
+    #[test]
+    fn test_flash_remaining() {
+        assert_eq!(flash_remaining(0.0, None, 100, 1.0 / 64.0), Ok(0.0));
+        // Half a second into a 2 second flash
+        assert_eq!(flash_remaining(2.0, Some(100), 132, 1.0 / 64.0), Ok(1.5));
+        assert_eq!(flash_remaining(2.0, Some(100), 1000, 1.0 / 64.0), Ok(0.0));
+        assert_eq!(flash_remaining(2.0, None, 132, 1.0 / 64.0), Err(PropCollectionError::FlashStartNotFound));
+    }
+}
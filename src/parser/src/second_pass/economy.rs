@@ -0,0 +1,78 @@
+use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::variants::Variant;
+
+const START_ACCOUNT_NAME: &str = "CCSPlayerController.CCSPlayerController_InGameMoneyServices.m_iStartAccount";
+const ACCOUNT_NAME: &str = "CCSPlayerController.CCSPlayerController_InGameMoneyServices.m_iAccount";
+const EQUIPMENT_VALUE_NAME: &str = "CCSPlayerPawn.m_unCurrentEquipmentValue";
+const CT_LOSING_STREAK_NAME: &str = "CCSGameRulesProxy.CCSGameRules.m_iNumConsecutiveCTLoses";
+const T_LOSING_STREAK_NAME: &str = "CCSGameRulesProxy.CCSGameRules.m_iNumConsecutiveTerroristLoses";
+
+// State of every player's economy at the moment the freeze period ends.
+// These are combined into per team economy in analysis::economy.
+#[derive(Debug, Clone)]
+pub struct EconomySnapshot {
+    pub tick: i32,
+    pub round: Option<i32>,
+    pub steamid: Option<u64>,
+    pub name: Option<String>,
+    pub team_num: Option<u32>,
+    pub start_balance: Option<i32>,
+    pub balance: Option<i32>,
+    pub equipment_value: Option<i32>,
+    pub t_losing_streak: Option<i32>,
+    pub ct_losing_streak: Option<i32>,
+}
+
+impl<'a> SecondPassParser<'a> {
+    pub fn collect_economy_snapshots(&mut self) {
+        let round = match self.find_current_round() {
+            Some(Variant::I32(r)) => Some(r),
+            _ => None,
+        };
+        let t_losing_streak = self.find_rules_prop_i32(T_LOSING_STREAK_NAME);
+        let ct_losing_streak = self.find_rules_prop_i32(CT_LOSING_STREAK_NAME);
+        let start_account_id = self.prop_controller.name_to_id.get(START_ACCOUNT_NAME);
+        let account_id = self.prop_controller.name_to_id.get(ACCOUNT_NAME);
+        let equipment_value_id = self.prop_controller.name_to_id.get(EQUIPMENT_VALUE_NAME);
+
+        for (entity_id, player) in &self.players {
+            let start_balance = start_account_id
+                .and_then(|id| self.get_controller_prop(id, player).ok())
+                .and_then(|v| variant_as_i32(&v));
+            let balance = account_id
+                .and_then(|id| self.get_controller_prop(id, player).ok())
+                .and_then(|v| variant_as_i32(&v));
+            let equipment_value = equipment_value_id
+                .and_then(|id| self.get_prop_from_ent(id, entity_id).ok())
+                .and_then(|v| variant_as_i32(&v));
+            self.economy_snapshots.push(EconomySnapshot {
+                tick: self.tick,
+                round,
+                steamid: player.steamid,
+                name: player.name.clone(),
+                team_num: player.team_num,
+                start_balance,
+                balance,
+                equipment_value,
+                t_losing_streak,
+                ct_losing_streak,
+            });
+        }
+    }
+
+    fn find_rules_prop_i32(&self, prop_name: &str) -> Option<i32> {
+        let prop_id = self.prop_controller.name_to_id.get(prop_name)?;
+        let rules_entid = self.rules_entity_id?;
+        variant_as_i32(&self.get_prop_from_ent(prop_id, &rules_entid).ok()?)
+    }
+}
+
+fn variant_as_i32(v: &Variant) -> Option<i32> {
+    match v {
+        Variant::I32(v) => Some(*v),
+        Variant::U32(v) => Some(*v as i32),
+        Variant::I16(v) => Some(*v as i32),
+        Variant::U8(v) => Some(*v as i32),
+        _ => None,
+    }
+}
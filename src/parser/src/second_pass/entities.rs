@@ -39,6 +39,7 @@ pub enum EntityType {
     PlayerController,
     Rules,
     Projectile,
+    Inferno,
     Team,
     Normal,
     C4,
@@ -76,6 +77,9 @@ impl<'a> SecondPassParser<'a> {
             match cmd {
                 EntityCmd::Delete => {
                     self.projectiles.remove(&entity_id);
+                    self.infernos.remove(&entity_id);
+                    self.smoke_voxel_updates.remove(&entity_id);
+                    self.flash_started_at.remove(&entity_id);
                     if let Some(entry) = self.entities.get_mut(entity_id as usize) {
                         *entry = None;
                     }
@@ -229,6 +233,7 @@ impl<'a> SecondPassParser<'a> {
             None => return Err(DemoParserError::ClassNotFound),
         };
 
+        let mut spotted_mask = None;
         for path in self.paths.iter().take(n_updates) {
             let field = find_field(&path, &class.serializer)?;
             let field_info = get_propinfo(&field, path);
@@ -253,8 +258,29 @@ impl<'a> SecondPassParser<'a> {
                 );
             }
 
+            if !is_fullpacket && !is_baseline {
+                if let (Some(fi), Some(flash_id)) = (field_info, self.prop_controller.special_ids.flash_duration) {
+                    if fi.prop_id == flash_id {
+                        if let Variant::F32(d) = &result {
+                            if *d > 0.0 {
+                                self.flash_started_at.insert(entity_id, self.tick);
+                            }
+                        }
+                    }
+                }
+            }
+            if self.parse_visibility && !is_baseline {
+                if let (Some(fi), Some(spotted_id), Variant::U32(mask)) = (field_info, self.prop_controller.special_ids.spotted_by_mask, &result) {
+                    if fi.prop_id == spotted_id {
+                        spotted_mask = Some(*mask);
+                    }
+                }
+            }
             SecondPassParser::insert_field(entity, result, field_info);
         }
+        if let Some(mask) = spotted_mask {
+            self.update_visibility(entity_id, mask);
+        }
         Ok(n_updates)
     }
     pub fn debug_inspect(
@@ -309,6 +335,9 @@ impl<'a> SecondPassParser<'a> {
             EntityType::Projectile => {
                 self.projectiles.insert(*entity_id);
             }
+            EntityType::Inferno => {
+                self.infernos.insert(*entity_id);
+            }
             EntityType::Rules => self.rules_entity_id = Some(*entity_id),
             EntityType::C4 => self.c4_entity_id = Some(*entity_id),
             _ => {}
@@ -351,6 +380,7 @@ impl<'a> SecondPassParser<'a> {
             "CCSGameRulesProxy" => return Ok(EntityType::Rules),
             "CCSTeam" => return Ok(EntityType::Team),
             "CC4" => return Ok(EntityType::C4),
+            "CInferno" => return Ok(EntityType::Inferno),
             _ => {}
         }
         if class.name.contains("Projectile") || class.name == "CIncendiaryGrenade" {
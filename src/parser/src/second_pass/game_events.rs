@@ -16,6 +16,7 @@ use crate::maps::ROUND_WIN_REASON_TO_WINNER;
 use crate::second_pass::collect_data::PropType;
 use crate::second_pass::entities::Entity;
 use crate::second_pass::entities::PlayerMetaData;
+use crate::second_pass::parser_settings::ConvarChange;
 use crate::second_pass::parser_settings::SecondPassParser;
 use crate::second_pass::variants::*;
 use csgoproto::csvc_msg_game_event::KeyT;
@@ -46,6 +47,12 @@ pub struct RoundEnd {
     pub new_value: Option<Variant>,
 }
 #[derive(Debug, Clone)]
+pub struct Interruption {
+    pub interruption_type: &'static str,
+    pub team: Option<i32>,
+    pub active: bool,
+}
+#[derive(Debug, Clone)]
 pub struct RoundWinReason {
     pub reason: i32,
 }
@@ -54,6 +61,9 @@ pub enum GameEventInfo {
     RoundEnd(RoundEnd),
     RoundWinReason(RoundWinReason),
     FreezePeriodStart(bool),
+    FreezePeriodEnd(),
+    BombPlanted(),
+    Interruption(Interruption),
     MatchEnd(),
     WeaponCreateHitem((Variant, i32)),
     WeaponCreateNCost((Variant, i32)),
@@ -117,6 +127,9 @@ impl<'a> SecondPassParser<'a> {
         } else {
             // Add extra fields
             event_fields.extend(self.find_extra(&event_fields)?);
+            if self.parse_shot_details && event_desc.name() == "weapon_fire" {
+                event_fields.extend(self.find_shot_movement_fields(&event_fields));
+            }
             // Remove fields that user does nothing with like userid and user_pawn
             event_fields.retain(|ref x| !INTERNALEVENTFIELDS.contains(&x.name.as_str()));
             let mut event = GameEvent {
@@ -300,8 +313,12 @@ impl<'a> SecondPassParser<'a> {
                 PropType::Team => self.find_other_team_props(&prop_info),
                 PropType::Rules => self.find_other_rules_props(&prop_info),
                 PropType::GameTime => vec![EventField {
-                    data: Some(Variant::F32(self.net_tick as f32 / 64.0)),
-                    name: "game_time".to_string(),
+                    data: Some(self.find_time_prop(&prop_info.prop_name)),
+                    name: prop_info.prop_friendly_name.clone(),
+                }],
+                PropType::RoundTiming => vec![EventField {
+                    data: self.find_round_timing_prop(&prop_info.prop_name).ok(),
+                    name: prop_info.prop_friendly_name.clone(),
                 }],
                 _ => vec![],
             };
@@ -449,13 +466,14 @@ impl<'a> SecondPassParser<'a> {
 
     pub fn create_custom_event_parse_convars(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
         self.game_events_counter.insert("server_cvar".to_string());
-        if !self.wanted_events.contains(&"server_cvar".to_string()) && self.wanted_events.first() != Some(&"all".to_string()) {
-            return Ok(());
-        }
         let convar = match CnetMsgSetConVar::decode(bytes) {
             Ok(m) => m,
             Err(_e) => return Err(DemoParserError::MalformedMessage),
         };
+        self.track_convar_changes(&convar);
+        if !self.wanted_events.contains(&"server_cvar".to_string()) && self.wanted_events.first() != Some(&"all".to_string()) {
+            return Ok(());
+        }
         for cv in &convar.convars {
             let mut fields = vec![];
             for var in &cv.cvars {
@@ -483,6 +501,21 @@ impl<'a> SecondPassParser<'a> {
         }
         Ok(())
     }
+    fn track_convar_changes(&mut self, convar: &CnetMsgSetConVar) {
+        for var in convar.convars.iter().flat_map(|cv| &cv.cvars) {
+            let old_value = self.convars.get(var.name()).cloned();
+            if old_value.as_deref() == Some(var.value()) {
+                continue;
+            }
+            self.convars.insert(var.name().to_string(), var.value().to_string());
+            self.convar_changes.push(ConvarChange {
+                tick: self.tick,
+                name: var.name().to_string(),
+                old_value,
+                new_value: var.value().to_string(),
+            });
+        }
+    }
     fn contains_round_end_event(events: &[GameEventInfo]) -> bool {
         events.iter().any(|s| match s {
             &GameEventInfo::RoundEnd(_) => true,
@@ -495,6 +528,9 @@ impl<'a> SecondPassParser<'a> {
             _ => false,
         })
     }
+    fn contains_freeze_period_end(events: &[GameEventInfo]) -> bool {
+        events.iter().any(|s| matches!(s, GameEventInfo::FreezePeriodEnd()))
+    }
     fn contains_match_end(events: &[GameEventInfo]) -> bool {
         events.iter().any(|s| match s {
             &GameEventInfo::MatchEnd() => true,
@@ -508,6 +544,8 @@ impl<'a> SecondPassParser<'a> {
         })
     }
     pub fn emit_events(&mut self, events: Vec<GameEventInfo>) -> Result<(), DemoParserError> {
+        self.track_round_timing(&events);
+        self.track_match_interruptions(&events);
         if SecondPassParser::contains_round_end_event(&events) {
             self.create_custom_event_round_end(&events)?;
         }
@@ -515,6 +553,9 @@ impl<'a> SecondPassParser<'a> {
             self.create_custom_event_round_officially_ended(&events)?;
             self.create_custom_event_round_start(&events)?;
         }
+        if self.parse_economy && SecondPassParser::contains_freeze_period_end(&events) {
+            self.collect_economy_snapshots();
+        }
         if SecondPassParser::contains_match_end(&events) {
             self.create_custom_event_match_end(&events)?;
         }
@@ -1070,6 +1111,42 @@ impl<'a> SecondPassParser<'a> {
                     events.push(GameEventInfo::FreezePeriodStart(true));
                 }
             }
+            // freeze period end
+            if let Some(id) = prop_controller.special_ids.freeze_period {
+                if fi.prop_id == id {
+                    if let (Some(Variant::Bool(true)), Variant::Bool(false)) = (entity.props.get(&id), result) {
+                        events.push(GameEventInfo::FreezePeriodEnd());
+                    }
+                }
+            }
+            if let Some(id) = prop_controller.special_ids.bomb_planted {
+                if fi.prop_id == id {
+                    if let (Some(Variant::Bool(false)) | None, Variant::Bool(true)) = (entity.props.get(&id), result) {
+                        events.push(GameEventInfo::BombPlanted());
+                    }
+                }
+            }
+            // timeouts and pauses
+            let interruptions = [
+                (prop_controller.special_ids.terrorist_timeout, "tactical_timeout", Some(2)),
+                (prop_controller.special_ids.ct_timeout, "tactical_timeout", Some(3)),
+                (prop_controller.special_ids.technical_timeout, "technical_timeout", None),
+                (prop_controller.special_ids.match_waiting_for_resume, "pause", None),
+            ];
+            for (id, interruption_type, team) in interruptions {
+                if id != Some(fi.prop_id) {
+                    continue;
+                }
+                if let Variant::Bool(active) = result {
+                    if entity.props.get(&fi.prop_id) != Some(result) {
+                        events.push(GameEventInfo::Interruption(Interruption {
+                            interruption_type,
+                            team,
+                            active: *active,
+                        }));
+                    }
+                }
+            }
             if let Some(id) = prop_controller.special_ids.match_end_count {
                 if fi.prop_id == id {
                     events.push(GameEventInfo::MatchEnd());
@@ -0,0 +1,237 @@
+use crate::first_pass::prop_controller::INFERNO_FIRE_IS_BURNING_BASEID;
+use crate::first_pass::prop_controller::INFERNO_FIRE_POSITIONS_BASEID;
+use crate::first_pass::prop_controller::INFERNO_MAX_FIRES;
+use crate::first_pass::prop_controller::SMOKE_VOXEL_DATA_BASEID;
+use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::variants::Variant;
+use ahash::AHashMap;
+
+// Collects the areas covered by molotovs/incendiaries (CInferno) and smokes.
+// One row is emitted per burning fire cell per inferno per tick and one row
+// per active smoke per tick. The smoke volume is the raw m_VoxelFrameData of the
+// smoke, only included on the rows where m_nVoxelUpdate changed.
+
+#[derive(Debug, Clone)]
+pub struct InfernoRecord {
+    pub entity_id: Option<i32>,
+    pub tick: Option<i32>,
+    pub steamid: Option<u64>,
+    pub name: Option<String>,
+    pub fire_idx: Option<u32>,
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+    pub z: Option<f32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SmokeRecord {
+    pub entity_id: Option<i32>,
+    pub tick: Option<i32>,
+    pub steamid: Option<u64>,
+    pub name: Option<String>,
+    pub start_tick: Option<i32>,
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+    pub z: Option<f32>,
+    pub voxel_update: Option<i32>,
+    pub voxel_data: Option<Vec<u8>>,
+}
+
+impl<'a> SecondPassParser<'a> {
+    pub fn collect_infernos(&mut self) {
+        for inferno_entid in &self.infernos {
+            let fires = self.find_burning_fires(inferno_entid);
+            if fires.is_empty() {
+                continue;
+            }
+            let steamid = self.find_thrower_steamid(inferno_entid).ok();
+            let name = self.find_thrower_name(inferno_entid).ok();
+            for (fire_idx, pos) in fires {
+                self.inferno_records.push(InfernoRecord {
+                    entity_id: Some(*inferno_entid),
+                    tick: Some(self.tick),
+                    steamid,
+                    name: name.clone(),
+                    fire_idx: Some(fire_idx),
+                    x: Some(pos[0]),
+                    y: Some(pos[1]),
+                    z: Some(pos[2]),
+                });
+            }
+        }
+    }
+
+    pub fn collect_smokes(&mut self) {
+        for projectile_entid in &self.projectiles {
+            let pos = match self.find_active_smoke(projectile_entid) {
+                Some(pos) => pos,
+                None => continue,
+            };
+            let start_tick = match self.prop_controller.special_ids.smoke_effect_tick_begin {
+                Some(id) => match self.get_prop_from_ent(&id, projectile_entid) {
+                    Ok(Variant::I32(t)) => Some(t),
+                    Ok(Variant::U32(t)) => Some(t as i32),
+                    _ => None,
+                },
+                None => None,
+            };
+            let voxel_update = self.find_smoke_voxel_update(projectile_entid);
+            let voxel_data = match self.entities.get(*projectile_entid as usize) {
+                Some(Some(entity)) => updated_voxel_data(&mut self.smoke_voxel_updates, *projectile_entid, voxel_update, &entity.props),
+                _ => None,
+            };
+            self.smoke_records.push(SmokeRecord {
+                entity_id: Some(*projectile_entid),
+                tick: Some(self.tick),
+                steamid: self.find_thrower_steamid(projectile_entid).ok(),
+                name: self.find_thrower_name(projectile_entid).ok(),
+                start_tick,
+                x: Some(pos[0]),
+                y: Some(pos[1]),
+                z: Some(pos[2]),
+                voxel_update,
+                voxel_data,
+            });
+        }
+    }
+
+    fn find_smoke_voxel_update(&self, entity_id: &i32) -> Option<i32> {
+        match self.get_prop_from_ent(&self.prop_controller.special_ids.smoke_voxel_update?, entity_id) {
+            Ok(Variant::I32(update)) => Some(update),
+            Ok(Variant::U32(update)) => Some(update as i32),
+            _ => None,
+        }
+    }
+
+    // Returns (fire index, position) of every fire cell of the inferno that is currently burning.
+    pub fn find_burning_fires(&self, entity_id: &i32) -> Vec<(u32, [f32; 3])> {
+        match (self.prop_controller.special_ids.inferno_fire_count, self.entities.get(*entity_id as usize)) {
+            (Some(fire_count_id), Some(Some(entity))) => burning_fires(&entity.props, fire_count_id),
+            _ => vec![],
+        }
+    }
+
+    // Returns the detonation position of the smoke if the entity is a smoke that has popped.
+    pub fn find_active_smoke(&self, entity_id: &i32) -> Option<[f32; 3]> {
+        let did_smoke_id = self.prop_controller.special_ids.smoke_did_effect?;
+        let pos_id = self.prop_controller.special_ids.smoke_detonation_pos?;
+        match self.get_prop_from_ent(&did_smoke_id, entity_id) {
+            Ok(Variant::Bool(true)) => {}
+            _ => return None,
+        }
+        match self.get_prop_from_ent(&pos_id, entity_id) {
+            Ok(Variant::VecXYZ(pos)) => Some(pos),
+            _ => None,
+        }
+    }
+}
+
+fn burning_fires(props: &AHashMap<u32, Variant>, fire_count_id: u32) -> Vec<(u32, [f32; 3])> {
+    let fire_count = match props.get(&fire_count_id) {
+        Some(Variant::U32(c)) => *c,
+        Some(Variant::I32(c)) => (*c).max(0) as u32,
+        _ => return vec![],
+    };
+    let mut fires = vec![];
+    for fire_idx in 0..fire_count.min(INFERNO_MAX_FIRES) {
+        if let Some(Variant::Bool(true)) = props.get(&(INFERNO_FIRE_IS_BURNING_BASEID + fire_idx)) {
+            if let Some(Variant::VecXYZ(pos)) = props.get(&(INFERNO_FIRE_POSITIONS_BASEID + fire_idx)) {
+                fires.push((fire_idx, *pos));
+            }
+        }
+    }
+    fires
+}
+
+// The voxel bytes are only included when m_nVoxelUpdate changed since the last row of the smoke
+fn updated_voxel_data(last_updates: &mut AHashMap<i32, i32>, entity_id: i32, voxel_update: Option<i32>, props: &AHashMap<u32, Variant>) -> Option<Vec<u8>> {
+    let update = voxel_update?;
+    if last_updates.insert(entity_id, update) == Some(update) {
+        return None;
+    }
+    Some(smoke_voxel_data(props))
+}
+
+// m_VoxelFrameData is flattened into its length and one prop per byte
+fn smoke_voxel_data(props: &AHashMap<u32, Variant>) -> Vec<u8> {
+    let len = match props.get(&SMOKE_VOXEL_DATA_BASEID) {
+        Some(Variant::U32(len)) => *len,
+        _ => return vec![],
+    };
+    (0..len)
+        .map(|idx| match props.get(&(SMOKE_VOXEL_DATA_BASEID + idx + 1)) {
+            Some(Variant::U32(byte)) => *byte as u8,
+            Some(Variant::I32(byte)) => *byte as u8,
+            _ => 0,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRE_COUNT_ID: u32 = 1;
+
+    fn inferno(fire_count: u32, fires: &[(u32, bool, Option<[f32; 3]>)]) -> AHashMap<u32, Variant> {
+        let mut props = AHashMap::default();
+        props.insert(FIRE_COUNT_ID, Variant::U32(fire_count));
+        for (idx, burning, pos) in fires {
+            props.insert(INFERNO_FIRE_IS_BURNING_BASEID + idx, Variant::Bool(*burning));
+            if let Some(pos) = pos {
+                props.insert(INFERNO_FIRE_POSITIONS_BASEID + idx, Variant::VecXYZ(*pos));
+            }
+        }
+        props
+    }
+
+    #[test]
+    fn test_burning_fires() {
+        let props = inferno(
+            4,
+            &[
+                (0, true, Some([1.0, 2.0, 3.0])),
+                // Put out
+                (1, false, Some([4.0, 5.0, 6.0])),
+                // Burning but the position hasn't arrived yet
+                (2, true, None),
+                (3, true, Some([7.0, 8.0, 9.0])),
+                // Past m_fireCount, left over from an earlier update
+                (4, true, Some([10.0, 11.0, 12.0])),
+            ],
+        );
+        assert_eq!(burning_fires(&props, FIRE_COUNT_ID), vec![(0, [1.0, 2.0, 3.0]), (3, [7.0, 8.0, 9.0])]);
+
+        // Once every fire went out there is nothing to emit
+        let props = inferno(2, &[(0, false, Some([1.0, 2.0, 3.0])), (1, false, Some([4.0, 5.0, 6.0]))]);
+        assert!(burning_fires(&props, FIRE_COUNT_ID).is_empty());
+        assert!(burning_fires(&AHashMap::default(), FIRE_COUNT_ID).is_empty());
+
+        // The count is capped to the size of the fire arrays
+        let fires: Vec<_> = (0..INFERNO_MAX_FIRES + 1).map(|idx| (idx, true, Some([idx as f32, 0.0, 0.0]))).collect();
+        let props = inferno(INFERNO_MAX_FIRES + 1, &fires);
+        assert_eq!(burning_fires(&props, FIRE_COUNT_ID).len(), INFERNO_MAX_FIRES as usize);
+    }
+
+    #[test]
+    fn test_smoke_voxel_data() {
+        let mut props = AHashMap::default();
+        props.insert(SMOKE_VOXEL_DATA_BASEID, Variant::U32(4));
+        props.insert(SMOKE_VOXEL_DATA_BASEID + 1, Variant::U32(0xAB));
+        props.insert(SMOKE_VOXEL_DATA_BASEID + 2, Variant::I32(7));
+        // Byte 2 was never sent
+        props.insert(SMOKE_VOXEL_DATA_BASEID + 4, Variant::U32(255));
+
+        let mut last_updates = AHashMap::default();
+        assert_eq!(updated_voxel_data(&mut last_updates, 10, Some(1), &props), Some(vec![0xAB, 7, 0, 255]));
+        // Same update on the next tick, the volume didn't change
+        assert_eq!(updated_voxel_data(&mut last_updates, 10, Some(1), &props), None);
+        // Another smoke is tracked on its own
+        assert_eq!(updated_voxel_data(&mut last_updates, 11, Some(1), &props), Some(vec![0xAB, 7, 0, 255]));
+
+        props.insert(SMOKE_VOXEL_DATA_BASEID, Variant::U32(2));
+        assert_eq!(updated_voxel_data(&mut last_updates, 10, Some(2), &props), Some(vec![0xAB, 7]));
+        assert_eq!(updated_voxel_data(&mut last_updates, 10, None, &props), None);
+        assert_eq!(smoke_voxel_data(&AHashMap::default()), Vec::<u8>::new());
+    }
+}
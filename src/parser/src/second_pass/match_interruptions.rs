@@ -0,0 +1,205 @@
+use crate::first_pass::read_bits::DemoParserError;
+use crate::maps::VOTE_TYPES;
+use crate::second_pass::game_events::GameEventInfo;
+use crate::second_pass::game_events::Interruption;
+use crate::second_pass::parser_settings::SecondPassParser;
+use csgoproto::CcsUsrMsgVoteFailed;
+use csgoproto::CcsUsrMsgVotePass;
+use csgoproto::CcsUsrMsgVoteStart;
+use prost::Message;
+
+// Tactical/technical timeouts and pauses come from the rules entity, votes from the
+// CS_UM_Vote* user messages. An interruption is opened when it starts and closed (end_tick)
+// when it ends, so one that spans chunks needs the demo to be parsed single threaded.
+
+#[derive(Debug, Clone)]
+pub struct MatchInterruption {
+    // "tactical_timeout", "technical_timeout", "pause" or "vote_<vote type>"
+    pub interruption_type: String,
+    pub team: Option<i32>,
+    pub initiator_steamid: Option<u64>,
+    pub initiator_name: Option<String>,
+    pub start_tick: i32,
+    pub end_tick: Option<i32>,
+    // "passed" or "failed", None for timeouts and pauses
+    pub vote_outcome: Option<String>,
+}
+
+impl<'a> SecondPassParser<'a> {
+    pub fn track_match_interruptions(&mut self, events: &[GameEventInfo]) {
+        for event in events {
+            if let GameEventInfo::Interruption(interruption) = event {
+                track_interruption(&mut self.match_interruptions, interruption, self.tick);
+            }
+        }
+    }
+
+    pub fn parse_vote_start(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
+        let vote = match CcsUsrMsgVoteStart::decode(bytes) {
+            Ok(vote) => vote,
+            Err(_) => return Err(DemoParserError::MalformedMessage),
+        };
+        // player_slot is the controller entity id - 1
+        let (initiator_steamid, initiator_name) = match self.find_user_by_controller_id(vote.player_slot() + 1) {
+            Some(player) => (player.steamid, player.name.clone()),
+            None => (None, None),
+        };
+        let vote_type = match VOTE_TYPES.get(&vote.vote_type()) {
+            Some(vote_type) => vote_type.to_string(),
+            None => vote.vote_type().to_string(),
+        };
+        self.match_interruptions.push(MatchInterruption {
+            interruption_type: "vote_".to_string() + &vote_type,
+            team: vote.team,
+            initiator_steamid,
+            initiator_name,
+            start_tick: self.tick,
+            end_tick: None,
+            vote_outcome: None,
+        });
+        Ok(())
+    }
+
+    pub fn parse_vote_pass(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
+        let vote = match CcsUsrMsgVotePass::decode(bytes) {
+            Ok(vote) => vote,
+            Err(_) => return Err(DemoParserError::MalformedMessage),
+        };
+        self.end_vote(vote.team, "passed");
+        Ok(())
+    }
+
+    pub fn parse_vote_failed(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
+        let vote = match CcsUsrMsgVoteFailed::decode(bytes) {
+            Ok(vote) => vote,
+            Err(_) => return Err(DemoParserError::MalformedMessage),
+        };
+        self.end_vote(vote.team, "failed");
+        Ok(())
+    }
+
+    fn end_vote(&mut self, team: Option<i32>, outcome: &str) {
+        end_vote(&mut self.match_interruptions, team, outcome, self.tick);
+    }
+}
+fn track_interruption(interruptions: &mut Vec<MatchInterruption>, interruption: &Interruption, tick: i32) {
+    match interruption.active {
+        true => interruptions.push(MatchInterruption {
+            interruption_type: interruption.interruption_type.to_string(),
+            team: interruption.team,
+            initiator_steamid: None,
+            initiator_name: None,
+            start_tick: tick,
+            end_tick: None,
+            vote_outcome: None,
+        }),
+        false => {
+            if let Some(open) = interruptions
+                .iter_mut()
+                .rev()
+                .find(|i| i.interruption_type == interruption.interruption_type && i.team == interruption.team && i.end_tick.is_none())
+            {
+                open.end_tick = Some(tick);
+            }
+        }
+    }
+}
+fn end_vote(interruptions: &mut [MatchInterruption], team: Option<i32>, outcome: &str, tick: i32) {
+    // Only one vote runs at a time so the latest open one is the one that ended
+    if let Some(vote) = interruptions
+        .iter_mut()
+        .rev()
+        .find(|i| i.interruption_type.starts_with("vote_") && i.vote_outcome.is_none())
+    {
+        vote.end_tick = Some(tick);
+        vote.vote_outcome = Some(outcome.to_string());
+        if vote.team.is_none() {
+            vote.team = team;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interruption(interruption_type: &'static str, team: Option<i32>, active: bool) -> Interruption {
+        Interruption {
+            interruption_type,
+            team,
+            active,
+        }
+    }
+
+    fn vote(vote_type: &str, team: Option<i32>, start_tick: i32) -> MatchInterruption {
+        MatchInterruption {
+            interruption_type: "vote_".to_string() + vote_type,
+            team,
+            initiator_steamid: Some(1),
+            initiator_name: None,
+            start_tick,
+            end_tick: None,
+            vote_outcome: None,
+        }
+    }
+
+    #[test]
+    fn test_timeouts_and_pauses() {
+        let mut interruptions = vec![];
+        track_interruption(&mut interruptions, &interruption("tactical_timeout", Some(2), true), 100);
+        track_interruption(&mut interruptions, &interruption("pause", None, true), 150);
+        // The other team ending a timeout does not close this one
+        track_interruption(&mut interruptions, &interruption("tactical_timeout", Some(3), false), 200);
+        track_interruption(&mut interruptions, &interruption("tactical_timeout", Some(2), false), 300);
+        track_interruption(&mut interruptions, &interruption("pause", None, false), 400);
+        // Ending something that never started is ignored
+        track_interruption(&mut interruptions, &interruption("technical_timeout", Some(3), false), 500);
+        track_interruption(&mut interruptions, &interruption("tactical_timeout", Some(2), true), 600);
+
+        let rows: Vec<_> = interruptions
+            .iter()
+            .map(|i| (i.interruption_type.as_str(), i.team, i.start_tick, i.end_tick))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("tactical_timeout", Some(2), 100, Some(300)),
+                ("pause", None, 150, Some(400)),
+                ("tactical_timeout", Some(2), 600, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_end_vote() {
+        let mut interruptions = vec![vote("kick", Some(2), 100), vote("surrender", None, 500)];
+        interruptions[0].vote_outcome = Some("failed".to_string());
+        interruptions[0].end_tick = Some(200);
+        interruptions.push(MatchInterruption {
+            interruption_type: "pause".to_string(),
+            team: None,
+            initiator_steamid: None,
+            initiator_name: None,
+            start_tick: 550,
+            end_tick: None,
+            vote_outcome: None,
+        });
+
+        // The latest open vote ends and takes the team from the result when the start had none
+        end_vote(&mut interruptions, Some(3), "passed", 600);
+        assert_eq!(interruptions[1].end_tick, Some(600));
+        assert_eq!(interruptions[1].vote_outcome.as_deref(), Some("passed"));
+        assert_eq!(interruptions[1].team, Some(3));
+        // Earlier votes and pauses are left alone
+        assert_eq!(
+            (interruptions[0].end_tick, interruptions[0].vote_outcome.as_deref()),
+            (Some(200), Some("failed"))
+        );
+        assert_eq!((interruptions[2].end_tick, interruptions[2].vote_outcome.as_deref()), (None, None));
+
+        // No open vote left
+        end_vote(&mut interruptions, Some(2), "failed", 700);
+        assert_eq!(interruptions[1].vote_outcome.as_deref(), Some("passed"));
+        assert_eq!(interruptions[1].end_tick, Some(600));
+    }
+}
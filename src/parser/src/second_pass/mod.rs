@@ -1,10 +1,18 @@
 pub mod collect_data;
 pub mod decoder;
+pub mod economy;
 pub mod entities;
 pub mod game_events;
+pub mod grenade_areas;
+pub mod match_interruptions;
+pub mod movement;
+pub mod net_messages;
 pub mod other_netmessages;
 pub mod parser;
 pub mod parser_settings;
 pub mod path_ops;
+pub mod round_timing;
+pub mod subtick;
 pub mod variants;
+pub mod visibility;
 pub mod voice_data;
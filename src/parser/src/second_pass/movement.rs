@@ -0,0 +1,159 @@
+use crate::maps::BUTTONMAP;
+use crate::maps::WEAPON_MAX_SPEED;
+use crate::second_pass::entities::PlayerMetaData;
+use crate::second_pass::game_events::EventField;
+use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::variants::Variant;
+
+// Movement state at the time of a shot, added to weapon_fire events when
+// ParserInputs::parse_shot_details is set. Used to see if a player was shooting while
+// moving and whether they counter-strafed before the shot.
+
+// How far back usercmd button states are kept for the movement keys of a shot
+pub const MOVEMENT_KEYS_WINDOW_MS: i32 = 200;
+// Shots are accurate when moving slower than this fraction of the weapons max speed
+const ACCURATE_SPEED_FRACTION: f32 = 0.34;
+const MOVEMENT_KEYS: &[&str] = &["FORWARD", "BACK", "LEFT", "RIGHT"];
+
+impl<'a> SecondPassParser<'a> {
+    fn movement_keys_window_ticks(&self) -> i32 {
+        (MOVEMENT_KEYS_WINDOW_MS as f32 / 1000.0 / self.tick_interval).round() as i32
+    }
+
+    pub fn record_buttons(&mut self, entity_id: i32, button_mask: u64) {
+        let tick = self.tick;
+        let window_ticks = self.movement_keys_window_ticks();
+        let history = self.button_history.entry(entity_id).or_default();
+        history.push((tick, button_mask));
+        history.retain(|(t, _)| tick - t <= window_ticks);
+    }
+
+    // Keeps the two latest positions of every pawn, the speed of a shot is taken from these.
+    // Called after every entity update so the latest entry is the current state.
+    pub fn record_player_positions(&mut self) {
+        let tick = self.tick;
+        let positions: Vec<(i32, [f32; 3])> = self.players.keys().filter_map(|id| Some((*id, self.find_player_position(id).ok()?))).collect();
+        for (entity_id, position) in positions {
+            let history = self.position_history.entry(entity_id).or_default();
+            if history.last().is_some_and(|(t, _)| *t == tick) {
+                history.pop();
+            }
+            history.push((tick, position));
+            if history.len() > 2 {
+                history.remove(0);
+            }
+        }
+    }
+
+    fn shot_speed(&self, entity_id: i32) -> Option<f32> {
+        match self.position_history.get(&entity_id)?.as_slice() {
+            [(prev_tick, prev), (tick, current)] => horizontal_speed(*prev, *current, (tick - prev_tick) as f32 * self.tick_interval),
+            _ => None,
+        }
+    }
+
+    fn get_pawn_bool(&self, prop_id: Option<u32>, entity_id: &i32) -> Option<bool> {
+        match self.get_prop_from_ent(&prop_id?, entity_id) {
+            Ok(Variant::Bool(b)) => Some(b),
+            _ => None,
+        }
+    }
+
+    pub fn find_shot_movement_fields(&self, fields: &[EventField]) -> Vec<EventField> {
+        let entity_id = fields.iter().find(|f| f.name == "userid").and_then(|f| match f.data {
+            Some(Variant::I32(u)) => self.entity_id_from_userid(u),
+            _ => None,
+        });
+        let weapon = fields.iter().find(|f| f.name == "weapon").and_then(|f| match &f.data {
+            Some(Variant::String(s)) => Some(s.as_str()),
+            _ => None,
+        });
+        let player: Option<&PlayerMetaData> = entity_id.and_then(|id| self.players.get(&id));
+        let field = |name: &str, data: Option<Variant>| EventField { name: name.to_string(), data };
+
+        let speed = entity_id.and_then(|id| self.shot_speed(id));
+        let accurate = match (speed, weapon.and_then(|w| WEAPON_MAX_SPEED.get(w))) {
+            (Some(speed), Some(max_speed)) => Some(speed <= max_speed * ACCURATE_SPEED_FRACTION),
+            _ => None,
+        };
+        let ducking = entity_id.and_then(|id| {
+            let ducked = self.get_pawn_bool(self.prop_controller.special_ids.is_ducked, &id);
+            let ducking = self.get_pawn_bool(self.prop_controller.special_ids.is_ducking, &id);
+            match (ducked, ducking) {
+                (None, None) => None,
+                (ducked, ducking) => Some(ducked.unwrap_or(false) || ducking.unwrap_or(false)),
+            }
+        });
+        let walking = entity_id.and_then(|id| self.get_pawn_bool(self.prop_controller.special_ids.is_walking, &id));
+        let airborne = match player.map(|p| self.find_is_airborne(p)) {
+            Some(Ok(Variant::Bool(b))) => Some(b),
+            _ => None,
+        };
+        let keys = entity_id
+            .and_then(|id| self.button_history.get(&id))
+            .map(|history| pressed_movement_keys(history, self.tick, self.movement_keys_window_ticks()))
+            .unwrap_or_default();
+        let counter_strafe = accurate.map(|accurate| accurate && is_counter_strafe(&keys));
+
+        vec![
+            field("shot_velocity", speed.map(Variant::F32)),
+            field("shot_accurate_speed", accurate.map(Variant::Bool)),
+            field("shot_ducking", ducking.map(Variant::Bool)),
+            field("shot_walking", walking.map(Variant::Bool)),
+            field("shot_airborne", airborne.map(Variant::Bool)),
+            field("shot_movement_keys", Some(Variant::StringVec(keys.iter().map(|k| k.to_string()).collect()))),
+            field("shot_counter_strafe", counter_strafe.map(Variant::Bool)),
+        ]
+    }
+}
+
+fn horizontal_speed(from: [f32; 3], to: [f32; 3], seconds: f32) -> Option<f32> {
+    if seconds <= 0.0 {
+        return None;
+    }
+    Some((to[0] - from[0]).hypot(to[1] - from[1]) / seconds)
+}
+
+// Movement keys held at some point within window_ticks before tick
+fn pressed_movement_keys(history: &[(i32, u64)], tick: i32, window_ticks: i32) -> Vec<&'static str> {
+    MOVEMENT_KEYS
+        .iter()
+        .filter(|key| {
+            let flag = BUTTONMAP.get(**key).copied().unwrap_or(0);
+            history.iter().any(|(t, mask)| tick - t <= window_ticks && mask & flag != 0)
+        })
+        .copied()
+        .collect()
+}
+
+// Pressing the opposite key of the direction you were moving in to stop quicker
+fn is_counter_strafe(keys: &[&str]) -> bool {
+    (keys.contains(&"LEFT") && keys.contains(&"RIGHT")) || (keys.contains(&"FORWARD") && keys.contains(&"BACK"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_horizontal_speed() {
+        // 2 units in 1/64 s, height is ignored
+        assert_eq!(horizontal_speed([0.0, 0.0, 0.0], [0.0, 2.0, 50.0], 1.0 / 64.0), Some(128.0));
+        assert_eq!(horizontal_speed([0.0; 3], [3.0, 4.0, 0.0], 1.0), Some(5.0));
+        assert_eq!(horizontal_speed([0.0; 3], [3.0, 4.0, 0.0], 0.0), None);
+    }
+
+    #[test]
+    fn test_movement_keys() {
+        let left = BUTTONMAP.get("LEFT").copied().unwrap();
+        let right = BUTTONMAP.get("RIGHT").copied().unwrap();
+        let forward = BUTTONMAP.get("FORWARD").copied().unwrap();
+        let history = vec![(80, forward), (95, left), (100, right)];
+        let keys = pressed_movement_keys(&history, 100, 13);
+        assert_eq!(keys, vec!["LEFT", "RIGHT"]);
+        assert!(is_counter_strafe(&keys));
+        // A longer window also sees the forward key, which alone is not a counter-strafe
+        assert_eq!(pressed_movement_keys(&history, 100, 20), vec!["FORWARD", "LEFT", "RIGHT"]);
+        assert!(!is_counter_strafe(&["FORWARD", "LEFT"]));
+    }
+}
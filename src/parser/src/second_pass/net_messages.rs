@@ -0,0 +1,279 @@
+use crate::first_pass::read_bits::DemoParserError;
+use crate::second_pass::parser_settings::SecondPassParser;
+use csgoproto::message_type::NetMessageType::{self, *};
+use csgoproto::*;
+use prost::Message;
+use serde::Serialize;
+use serde_json::Value;
+
+// Raw capture of net messages by their NetMessageType name ("CS_UM_VoteStart", "svc_ServerInfo" ...).
+// The message is decoded with the matching csgoproto struct and serialized into a JSON map so
+// messages the parser doesn't otherwise care about can be looked at without patching it.
+
+// Every id in csgoproto::message_type is below this
+const MAX_NET_MESSAGE_ID: i32 = 512;
+
+#[derive(Debug, Clone)]
+pub struct NetMessageRecord {
+    pub tick: i32,
+    pub msg_type: String,
+    pub data: Value,
+}
+
+impl<'a> SecondPassParser<'a> {
+    // A wanted message that fails to decode is skipped, it's not worth failing the whole parse over
+    pub fn capture_net_message(&mut self, msg_type: i32, bytes: &[u8]) {
+        let msg_type = NetMessageType::from(msg_type);
+        let name = format!("{:?}", msg_type);
+        if !self.wanted_net_messages.contains(&name) {
+            return;
+        }
+        if let Ok(Some(data)) = net_message_to_json(&msg_type, bytes) {
+            self.net_messages.push(NetMessageRecord {
+                tick: self.tick,
+                msg_type: name,
+                data,
+            });
+        }
+    }
+}
+
+fn to_json<T: Message + Default + Serialize>(bytes: &[u8]) -> Result<Option<Value>, DemoParserError> {
+    let msg = match T::decode(bytes) {
+        Ok(msg) => msg,
+        Err(_) => return Err(DemoParserError::MalformedMessage),
+    };
+    match serde_json::to_value(&msg) {
+        Ok(value) => Ok(Some(value)),
+        Err(_) => Err(DemoParserError::MalformedMessage),
+    }
+}
+
+// Names of the message types that net_message_to_json has a protobuf struct for
+pub fn supported_net_message_types() -> Vec<String> {
+    (0..MAX_NET_MESSAGE_ID)
+        .map(NetMessageType::from)
+        .filter(|msg_type| matches!(net_message_to_json(msg_type, &[]), Ok(Some(_))))
+        .map(|msg_type| format!("{:?}", msg_type))
+        .collect()
+}
+
+// Returns None for message types without a known protobuf struct
+pub fn net_message_to_json(msg_type: &NetMessageType, bytes: &[u8]) -> Result<Option<Value>, DemoParserError> {
+    match msg_type {
+        net_NOP => to_json::<CnetMsgNop>(bytes),
+        net_SplitScreenUser => to_json::<CnetMsgSplitScreenUser>(bytes),
+        net_Tick => to_json::<CnetMsgTick>(bytes),
+        net_StringCmd => to_json::<CnetMsgStringCmd>(bytes),
+        net_SetConVar => to_json::<CnetMsgSetConVar>(bytes),
+        net_SignonState => to_json::<CnetMsgSignonState>(bytes),
+        net_SpawnGroup_Load => to_json::<CnetMsgSpawnGroupLoad>(bytes),
+        net_SpawnGroup_ManifestUpdate => to_json::<CnetMsgSpawnGroupManifestUpdate>(bytes),
+        net_SpawnGroup_SetCreationTick => to_json::<CnetMsgSpawnGroupSetCreationTick>(bytes),
+        net_SpawnGroup_Unload => to_json::<CnetMsgSpawnGroupUnload>(bytes),
+        net_SpawnGroup_LoadCompleted => to_json::<CnetMsgSpawnGroupLoadCompleted>(bytes),
+        net_DebugOverlay => to_json::<CnetMsgDebugOverlay>(bytes),
+        svc_ServerInfo => to_json::<CsvcMsgServerInfo>(bytes),
+        svc_FlattenedSerializer => to_json::<CsvcMsgFlattenedSerializer>(bytes),
+        svc_ClassInfo => to_json::<CsvcMsgClassInfo>(bytes),
+        svc_SetPause => to_json::<CsvcMsgSetPause>(bytes),
+        svc_CreateStringTable => to_json::<CsvcMsgCreateStringTable>(bytes),
+        svc_UpdateStringTable => to_json::<CsvcMsgUpdateStringTable>(bytes),
+        svc_VoiceInit => to_json::<CsvcMsgVoiceInit>(bytes),
+        svc_VoiceData => to_json::<CsvcMsgVoiceData>(bytes),
+        svc_Print => to_json::<CsvcMsgPrint>(bytes),
+        svc_Sounds => to_json::<CsvcMsgSounds>(bytes),
+        svc_SetView => to_json::<CsvcMsgSetView>(bytes),
+        svc_ClearAllStringTables => to_json::<CsvcMsgClearAllStringTables>(bytes),
+        svc_CmdKeyValues => to_json::<CsvcMsgCmdKeyValues>(bytes),
+        svc_BSPDecal => to_json::<CsvcMsgBspDecal>(bytes),
+        svc_SplitScreen => to_json::<CsvcMsgSplitScreen>(bytes),
+        svc_PacketEntities => to_json::<CsvcMsgPacketEntities>(bytes),
+        svc_Prefetch => to_json::<CsvcMsgPrefetch>(bytes),
+        svc_Menu => to_json::<CsvcMsgMenu>(bytes),
+        svc_GetCvarValue => to_json::<CsvcMsgGetCvarValue>(bytes),
+        svc_StopSound => to_json::<CsvcMsgStopSound>(bytes),
+        svc_PeerList => to_json::<CsvcMsgPeerList>(bytes),
+        svc_PacketReliable => to_json::<CsvcMsgPacketReliable>(bytes),
+        svc_HLTVStatus => to_json::<CsvcMsgHltvStatus>(bytes),
+        svc_ServerSteamID => to_json::<CsvcMsgServerSteamId>(bytes),
+        svc_FullFrameSplit => to_json::<CsvcMsgFullFrameSplit>(bytes),
+        svc_RconServerDetails => to_json::<CsvcMsgRconServerDetails>(bytes),
+        svc_UserMessage => to_json::<CsvcMsgUserMessage>(bytes),
+        svc_Broadcast_Command => to_json::<CsvcMsgBroadcastCommand>(bytes),
+        svc_HltvFixupOperatorStatus => to_json::<CsvcMsgHltvFixupOperatorStatus>(bytes),
+        svc_UserCmds => to_json::<CsvcMsgUserCommands>(bytes),
+        UM_AchievementEvent => to_json::<CUserMessageAchievementEvent>(bytes),
+        UM_CloseCaption => to_json::<CUserMessageCloseCaption>(bytes),
+        UM_CloseCaptionDirect => to_json::<CUserMessageCloseCaptionDirect>(bytes),
+        UM_CurrentTimescale => to_json::<CUserMessageCurrentTimescale>(bytes),
+        UM_DesiredTimescale => to_json::<CUserMessageDesiredTimescale>(bytes),
+        UM_Fade => to_json::<CUserMessageFade>(bytes),
+        UM_GameTitle => to_json::<CUserMessageGameTitle>(bytes),
+        UM_HudMsg => to_json::<CUserMessageHudMsg>(bytes),
+        UM_HudText => to_json::<CUserMessageHudText>(bytes),
+        UM_ColoredText => to_json::<CUserMessageColoredText>(bytes),
+        UM_RequestState => to_json::<CUserMessageRequestState>(bytes),
+        UM_ResetHUD => to_json::<CUserMessageResetHud>(bytes),
+        UM_Rumble => to_json::<CUserMessageRumble>(bytes),
+        UM_SayText => to_json::<CUserMessageSayText>(bytes),
+        UM_SayText2 => to_json::<CUserMessageSayText2>(bytes),
+        UM_SayTextChannel => to_json::<CUserMessageSayTextChannel>(bytes),
+        UM_Shake => to_json::<CUserMessageShake>(bytes),
+        UM_ShakeDir => to_json::<CUserMessageShakeDir>(bytes),
+        UM_WaterShake => to_json::<CUserMessageWaterShake>(bytes),
+        UM_TextMsg => to_json::<CUserMessageTextMsg>(bytes),
+        UM_ScreenTilt => to_json::<CUserMessageScreenTilt>(bytes),
+        UM_VoiceMask => to_json::<CUserMessageVoiceMask>(bytes),
+        UM_SendAudio => to_json::<CUserMessageSendAudio>(bytes),
+        UM_ItemPickup => to_json::<CUserMessageItemPickup>(bytes),
+        UM_AmmoDenied => to_json::<CUserMessageAmmoDenied>(bytes),
+        UM_ShowMenu => to_json::<CUserMessageShowMenu>(bytes),
+        UM_CreditsMsg => to_json::<CUserMessageCreditsMsg>(bytes),
+        UM_CloseCaptionPlaceholder => to_json::<CUserMessageCloseCaptionPlaceholder>(bytes),
+        UM_CameraTransition => to_json::<CUserMessageCameraTransition>(bytes),
+        UM_AudioParameter => to_json::<CUserMessageAudioParameter>(bytes),
+        UM_ParticleManager => to_json::<CUserMsgParticleManager>(bytes),
+        UM_HudError => to_json::<CUserMsgHudError>(bytes),
+        UM_CustomGameEvent => to_json::<CUserMsgCustomGameEvent>(bytes),
+        UM_AnimGraphUpdate => to_json::<CUserMessageAnimStateGraphState>(bytes),
+        UM_HapticsManagerPulse => to_json::<CUserMessageHapticsManagerPulse>(bytes),
+        UM_HapticsManagerEffect => to_json::<CUserMessageHapticsManagerEffect>(bytes),
+        UM_UpdateCssClasses => to_json::<CUserMessageUpdateCssClasses>(bytes),
+        UM_ServerFrameTime => to_json::<CUserMessageServerFrameTime>(bytes),
+        UM_LagCompensationError => to_json::<CUserMessageLagCompensationError>(bytes),
+        UM_RequestDllStatus => to_json::<CUserMessageRequestDllStatus>(bytes),
+        UM_RequestUtilAction => to_json::<CUserMessageRequestUtilAction>(bytes),
+        UM_UtilActionResponse => to_json::<CUserMessageUtilMsgResponse>(bytes),
+        UM_DllStatusResponse => to_json::<CUserMessageDllStatus>(bytes),
+        UM_RequestInventory => to_json::<CUserMessageRequestInventory>(bytes),
+        UM_InventoryResponse => to_json::<CUserMessageInventoryResponse>(bytes),
+        UM_RequestDiagnostic => to_json::<CUserMessageRequestDiagnostic>(bytes),
+        UM_DiagnosticResponse => to_json::<CUserMessageDiagnosticResponse>(bytes),
+        UM_ExtraUserData => to_json::<CUserMessageExtraUserData>(bytes),
+        UM_NotifyResponseFound => to_json::<CUserMessageNotifyResponseFound>(bytes),
+        UM_PlayResponseConditional => to_json::<CUserMessagePlayResponseConditional>(bytes),
+        GE_VDebugGameSessionIDEvent => to_json::<CMsgVDebugGameSessionIdEvent>(bytes),
+        GE_PlaceDecalEvent => to_json::<CMsgPlaceDecalEvent>(bytes),
+        GE_ClearWorldDecalsEvent => to_json::<CMsgClearWorldDecalsEvent>(bytes),
+        GE_ClearEntityDecalsEvent => to_json::<CMsgClearEntityDecalsEvent>(bytes),
+        GE_ClearDecalsForSkeletonInstanceEvent => to_json::<CMsgClearDecalsForSkeletonInstanceEvent>(bytes),
+        GE_Source1LegacyGameEventList => to_json::<CMsgSource1LegacyGameEventList>(bytes),
+        GE_Source1LegacyListenEvents => to_json::<CMsgSource1LegacyListenEvents>(bytes),
+        GE_Source1LegacyGameEvent => to_json::<CMsgSource1LegacyGameEvent>(bytes),
+        GE_SosStartSoundEvent => to_json::<CMsgSosStartSoundEvent>(bytes),
+        GE_SosStopSoundEvent => to_json::<CMsgSosStopSoundEvent>(bytes),
+        GE_SosSetSoundEventParams => to_json::<CMsgSosSetSoundEventParams>(bytes),
+        GE_SosSetLibraryStackFields => to_json::<CMsgSosSetLibraryStackFields>(bytes),
+        GE_SosStopSoundEventHash => to_json::<CMsgSosStopSoundEventHash>(bytes),
+        CS_UM_VGUIMenu => to_json::<CcsUsrMsgVguiMenu>(bytes),
+        CS_UM_Geiger => to_json::<CcsUsrMsgGeiger>(bytes),
+        CS_UM_Train => to_json::<CcsUsrMsgTrain>(bytes),
+        CS_UM_HudText => to_json::<CcsUsrMsgHudText>(bytes),
+        CS_UM_HudMsg => to_json::<CcsUsrMsgHudMsg>(bytes),
+        CS_UM_ResetHud => to_json::<CcsUsrMsgResetHud>(bytes),
+        CS_UM_GameTitle => to_json::<CcsUsrMsgGameTitle>(bytes),
+        CS_UM_Shake => to_json::<CcsUsrMsgShake>(bytes),
+        CS_UM_Fade => to_json::<CcsUsrMsgFade>(bytes),
+        CS_UM_Rumble => to_json::<CcsUsrMsgRumble>(bytes),
+        CS_UM_CloseCaption => to_json::<CcsUsrMsgCloseCaption>(bytes),
+        CS_UM_CloseCaptionDirect => to_json::<CcsUsrMsgCloseCaptionDirect>(bytes),
+        CS_UM_SendAudio => to_json::<CcsUsrMsgSendAudio>(bytes),
+        CS_UM_RawAudio => to_json::<CcsUsrMsgRawAudio>(bytes),
+        CS_UM_VoiceMask => to_json::<CcsUsrMsgVoiceMask>(bytes),
+        CS_UM_RequestState => to_json::<CcsUsrMsgRequestState>(bytes),
+        CS_UM_Damage => to_json::<CcsUsrMsgDamage>(bytes),
+        CS_UM_RadioText => to_json::<CcsUsrMsgRadioText>(bytes),
+        CS_UM_HintText => to_json::<CcsUsrMsgHintText>(bytes),
+        CS_UM_KeyHintText => to_json::<CcsUsrMsgKeyHintText>(bytes),
+        CS_UM_ProcessSpottedEntityUpdate => to_json::<CcsUsrMsgProcessSpottedEntityUpdate>(bytes),
+        CS_UM_ReloadEffect => to_json::<CcsUsrMsgReloadEffect>(bytes),
+        CS_UM_AdjustMoney => to_json::<CcsUsrMsgAdjustMoney>(bytes),
+        CS_UM_StopSpectatorMode => to_json::<CcsUsrMsgStopSpectatorMode>(bytes),
+        CS_UM_KillCam => to_json::<CcsUsrMsgKillCam>(bytes),
+        CS_UM_DesiredTimescale => to_json::<CcsUsrMsgDesiredTimescale>(bytes),
+        CS_UM_CurrentTimescale => to_json::<CcsUsrMsgCurrentTimescale>(bytes),
+        CS_UM_AchievementEvent => to_json::<CcsUsrMsgAchievementEvent>(bytes),
+        CS_UM_MatchEndConditions => to_json::<CcsUsrMsgMatchEndConditions>(bytes),
+        CS_UM_DisconnectToLobby => to_json::<CcsUsrMsgDisconnectToLobby>(bytes),
+        CS_UM_PlayerStatsUpdate => to_json::<CcsUsrMsgPlayerStatsUpdate>(bytes),
+        CS_UM_WarmupHasEnded => to_json::<CcsUsrMsgWarmupHasEnded>(bytes),
+        CS_UM_ClientInfo => to_json::<CcsUsrMsgClientInfo>(bytes),
+        CS_UM_XRankGet => to_json::<CcsUsrMsgXRankGet>(bytes),
+        CS_UM_XRankUpd => to_json::<CcsUsrMsgXRankUpd>(bytes),
+        CS_UM_CallVoteFailed => to_json::<CcsUsrMsgCallVoteFailed>(bytes),
+        CS_UM_VoteStart => to_json::<CcsUsrMsgVoteStart>(bytes),
+        CS_UM_VotePass => to_json::<CcsUsrMsgVotePass>(bytes),
+        CS_UM_VoteFailed => to_json::<CcsUsrMsgVoteFailed>(bytes),
+        CS_UM_VoteSetup => to_json::<CcsUsrMsgVoteSetup>(bytes),
+        CS_UM_ServerRankRevealAll => to_json::<CcsUsrMsgServerRankRevealAll>(bytes),
+        CS_UM_SendLastKillerDamageToClient => to_json::<CcsUsrMsgSendLastKillerDamageToClient>(bytes),
+        CS_UM_ServerRankUpdate => to_json::<CcsUsrMsgServerRankUpdate>(bytes),
+        CS_UM_ItemPickup => to_json::<CcsUsrMsgItemPickup>(bytes),
+        CS_UM_ShowMenu => to_json::<CcsUsrMsgShowMenu>(bytes),
+        CS_UM_BarTime => to_json::<CcsUsrMsgBarTime>(bytes),
+        CS_UM_AmmoDenied => to_json::<CcsUsrMsgAmmoDenied>(bytes),
+        CS_UM_MarkAchievement => to_json::<CcsUsrMsgMarkAchievement>(bytes),
+        CS_UM_MatchStatsUpdate => to_json::<CcsUsrMsgMatchStatsUpdate>(bytes),
+        CS_UM_ItemDrop => to_json::<CcsUsrMsgItemDrop>(bytes),
+        CS_UM_GlowPropTurnOff => to_json::<CcsUsrMsgGlowPropTurnOff>(bytes),
+        CS_UM_SendPlayerItemDrops => to_json::<CcsUsrMsgSendPlayerItemDrops>(bytes),
+        CS_UM_RoundBackupFilenames => to_json::<CcsUsrMsgRoundBackupFilenames>(bytes),
+        CS_UM_SendPlayerItemFound => to_json::<CcsUsrMsgSendPlayerItemFound>(bytes),
+        CS_UM_ReportHit => to_json::<CcsUsrMsgReportHit>(bytes),
+        CS_UM_XpUpdate => to_json::<CcsUsrMsgXpUpdate>(bytes),
+        CS_UM_QuestProgress => to_json::<CcsUsrMsgQuestProgress>(bytes),
+        CS_UM_ScoreLeaderboardData => to_json::<CcsUsrMsgScoreLeaderboardData>(bytes),
+        CS_UM_PlayerDecalDigitalSignature => to_json::<CcsUsrMsgPlayerDecalDigitalSignature>(bytes),
+        CS_UM_WeaponSound => to_json::<CcsUsrMsgWeaponSound>(bytes),
+        CS_UM_UpdateScreenHealthBar => to_json::<CcsUsrMsgUpdateScreenHealthBar>(bytes),
+        CS_UM_EntityOutlineHighlight => to_json::<CcsUsrMsgEntityOutlineHighlight>(bytes),
+        CS_UM_SSUI => to_json::<CcsUsrMsgSsui>(bytes),
+        CS_UM_SurvivalStats => to_json::<CcsUsrMsgSurvivalStats>(bytes),
+        CS_UM_DisconnectToLobby2 => to_json::<CcsUsrMsgDisconnectToLobby>(bytes),
+        CS_UM_EndOfMatchAllPlayersData => to_json::<CcsUsrMsgEndOfMatchAllPlayersData>(bytes),
+        CS_UM_PostRoundDamageReport => to_json::<CcsUsrMsgPostRoundDamageReport>(bytes),
+        CS_UM_RoundEndReportData => to_json::<CcsUsrMsgRoundEndReportData>(bytes),
+        CS_UM_CurrentRoundOdds => to_json::<CcsUsrMsgCurrentRoundOdds>(bytes),
+        CS_UM_DeepStats => to_json::<CcsUsrMsgDeepStats>(bytes),
+        CS_UM_ShootInfo => to_json::<CcsUsrMsgShootInfo>(bytes),
+        CS_UM_CounterStrafe => to_json::<CcsUsrMsgCounterStrafe>(bytes),
+        CS_UM_DamagePrediction => to_json::<CcsUsrMsgDamagePrediction>(bytes),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_net_message_to_json() {
+        let bytes = CcsUsrMsgVoteStart {
+            team: Some(3),
+            player_slot: Some(4),
+            vote_type: Some(0),
+            disp_str: Some("#SFUI_vote_kick_player_other".to_string()),
+            ..Default::default()
+        }
+        .encode_to_vec();
+        let data = net_message_to_json(&NetMessageType::from(346), &bytes).unwrap().unwrap();
+        assert_eq!(data["team"], 3);
+        assert_eq!(data["player_slot"], 4);
+        assert_eq!(data["vote_type"], 0);
+        assert_eq!(data["disp_str"], "#SFUI_vote_kick_player_other");
+
+        assert!(net_message_to_json(&CS_UM_VoteStart, &[0xFF, 0xFF]).is_err());
+        assert_eq!(net_message_to_json(&Unknown, &bytes).unwrap(), None);
+    }
+
+    #[test]
+    fn test_supported_net_message_types() {
+        let supported = supported_net_message_types();
+        assert!(supported.contains(&"CS_UM_VoteStart".to_string()));
+        assert!(supported.contains(&"svc_ServerInfo".to_string()));
+        assert!(!supported.contains(&"Unknown".to_string()));
+        // Known to csgoproto but without a struct
+        assert!(!supported.contains(&"net_Disconnect_Legacy".to_string()));
+    }
+}
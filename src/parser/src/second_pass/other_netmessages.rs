@@ -1,13 +1,21 @@
 use crate::first_pass::read_bits::DemoParserError;
 use crate::first_pass::sendtables::Serializer;
+use crate::maps::ACCOLADE_NAMES;
+use crate::maps::PLAYER_STAT_NAMES;
+use crate::second_pass::entities::PlayerMetaData;
+use crate::second_pass::parser_settings::Accolade;
 use crate::second_pass::parser_settings::EconItem;
 use crate::second_pass::parser_settings::PlayerEndMetaData;
+use crate::second_pass::parser_settings::PlayerStatsUpdate;
 use crate::second_pass::parser_settings::SecondPassParser;
+use csgoproto::ccs_usr_msg_end_of_match_all_players_data::PlayerData;
 use csgoproto::maps::PAINTKITS;
 use csgoproto::maps::WEAPINDICIES;
 use csgoproto::CcsUsrMsgEndOfMatchAllPlayersData;
+use csgoproto::CcsUsrMsgPlayerStatsUpdate;
 use csgoproto::CcsUsrMsgSendPlayerItemDrops;
 use prost::Message;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone)]
 pub struct Class {
@@ -58,29 +66,15 @@ impl<'a> SecondPassParser<'a> {
             Ok(msg) => msg,
             Err(_) => return Err(DemoParserError::MalformedMessage),
         };
-        /*
-        Todo parse "accolade", seems to be the awards at the end like "most mvps in game"
-        But seems to only have integers so need to figure out what they mean
-        example:
-
-        Accolade {
-            eaccolade: Some(
-                21,
-            ),
-            value: Some(
-                5100.0,
-            ),
-            position: Some(
-                1,
-            ),
-        }
-        */
         for player in &end_data.allplayerdata {
             self.player_end_data.push(PlayerEndMetaData {
                 name: player.name.clone(),
                 steamid: player.xuid,
                 team_number: player.teamnumber,
             });
+            if let Some(accolade) = accolade(player) {
+                self.accolades.push(accolade);
+            }
             for item in &player.items {
                 if item.itemid() != 0 {
                     let item_name = match WEAPINDICIES.get(&item.defindex.unwrap_or(u32::MAX)) {
@@ -114,12 +108,140 @@ impl<'a> SecondPassParser<'a> {
         }
         Ok(())
     }
-    pub fn parse_player_stats_update(&mut self, _bytes: &[u8]) -> Result<(), DemoParserError> {
-        // let upd: CCSUsrMsg_PlayerStatsUpdate = Message::parse_from_bytes(bytes);
+    pub fn parse_player_stats_update(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
+        let updates = decode_player_stats_update(bytes, self.tick, &self.players)?;
+        self.player_stats_updates.extend(updates);
         Ok(())
     }
-    pub fn parse_file_info(&mut self, _bytes: &[u8]) -> Result<(), DemoParserError> {
-        // let _info: CDemoFileInfo = Message::parse_from_bytes(bytes);
-        Ok(())
+}
+// The award shown for the player on the end of match screen
+fn accolade(player: &PlayerData) -> Option<Accolade> {
+    let nomination = player.nomination.as_ref()?;
+    Some(Accolade {
+        steamid: player.xuid,
+        name: player.name.clone(),
+        accolade_id: nomination.eaccolade,
+        accolade_name: nomination.eaccolade.and_then(|id| ACCOLADE_NAMES.get(&id)).map(|name| name.to_string()),
+        value: nomination.value,
+        position: nomination.position,
+    })
+}
+fn decode_player_stats_update(bytes: &[u8], tick: i32, players: &BTreeMap<i32, PlayerMetaData>) -> Result<Vec<PlayerStatsUpdate>, DemoParserError> {
+    let update = match CcsUsrMsgPlayerStatsUpdate::decode(bytes) {
+        Ok(msg) => msg,
+        Err(_) => return Err(DemoParserError::MalformedMessage),
+    };
+    // ehandle points to the pawn, fall back to the controller
+    let entity_id = (update.ehandle() & 0x7FF) as i32;
+    let player = players
+        .get(&entity_id)
+        .or_else(|| players.values().find(|player| player.controller_entid == Some(entity_id)));
+    let (steamid, name) = match player {
+        Some(player) => (player.steamid, player.name.clone()),
+        None => (None, None),
+    };
+    Ok(update
+        .stats
+        .iter()
+        .map(|stat| PlayerStatsUpdate {
+            tick,
+            steamid,
+            name: name.clone(),
+            stat_id: stat.idx(),
+            stat_name: PLAYER_STAT_NAMES.get(&stat.idx()).map(|name| name.to_string()),
+            delta: stat.delta(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csgoproto::ccs_usr_msg_end_of_match_all_players_data::Accolade as Nomination;
+    use csgoproto::ccs_usr_msg_player_stats_update::Stat;
+
+    fn player(player_entity_id: i32, controller_entid: i32, steamid: u64) -> PlayerMetaData {
+        PlayerMetaData {
+            player_entity_id: Some(player_entity_id),
+            steamid: Some(steamid),
+            controller_entid: Some(controller_entid),
+            name: Some(format!("player_{}", steamid)),
+            team_num: Some(2),
+        }
+    }
+
+    fn stats_update(ehandle: u32, stats: &[(i32, i32)]) -> Vec<u8> {
+        CcsUsrMsgPlayerStatsUpdate {
+            version: Some(1),
+            stats: stats
+                .iter()
+                .map(|(idx, delta)| Stat {
+                    idx: Some(*idx),
+                    delta: Some(*delta),
+                })
+                .collect(),
+            ehandle: Some(ehandle),
+            ..Default::default()
+        }
+        .encode_to_vec()
+    }
+
+    #[test]
+    fn test_player_stats_update() {
+        let players = BTreeMap::from([(10, player(10, 1, 76561198000000001)), (11, player(11, 2, 76561198000000002))]);
+
+        // Serial number bits above the entity index are ignored
+        let updates = decode_player_stats_update(&stats_update((5 << 11) | 10, &[(2, 1), (4, 100)]), 500, &players).unwrap();
+        let updates: Vec<_> = updates.iter().map(|u| (u.tick, u.steamid, u.stat_name.as_deref(), u.delta)).collect();
+        assert_eq!(
+            updates,
+            vec![
+                (500, Some(76561198000000001), Some("kills"), 1),
+                (500, Some(76561198000000001), Some("damage"), 100),
+            ]
+        );
+
+        // Controller handle and unknown stat id
+        let updates = decode_player_stats_update(&stats_update(2, &[(99, -1)]), 600, &players).unwrap();
+        assert_eq!(updates[0].steamid, Some(76561198000000002));
+        assert_eq!(updates[0].name.as_deref(), Some("player_76561198000000002"));
+        assert_eq!((updates[0].stat_id, updates[0].stat_name.as_deref(), updates[0].delta), (99, None, -1));
+
+        let updates = decode_player_stats_update(&stats_update(40, &[(3, 1)]), 700, &players).unwrap();
+        assert_eq!((updates[0].steamid, updates[0].name.as_deref()), (None, None));
+        assert!(decode_player_stats_update(&[0xFF, 0xFF], 0, &players).is_err());
+    }
+
+    #[test]
+    fn test_accolade() {
+        // The nomination from a real demo: most money spent
+        let bytes = CcsUsrMsgEndOfMatchAllPlayersData {
+            allplayerdata: vec![
+                PlayerData {
+                    xuid: Some(76561198000000001),
+                    name: Some("player".to_string()),
+                    nomination: Some(Nomination {
+                        eaccolade: Some(21),
+                        value: Some(5100.0),
+                        position: Some(1),
+                    }),
+                    ..Default::default()
+                },
+                PlayerData {
+                    xuid: Some(76561198000000002),
+                    ..Default::default()
+                },
+            ],
+            scene: None,
+        }
+        .encode_to_vec();
+        let data = CcsUsrMsgEndOfMatchAllPlayersData::decode(bytes.as_slice()).unwrap();
+
+        let award = accolade(&data.allplayerdata[0]).unwrap();
+        assert_eq!(award.steamid, Some(76561198000000001));
+        assert_eq!(award.name.as_deref(), Some("player"));
+        assert_eq!((award.accolade_id, award.accolade_name.as_deref()), (Some(21), Some("cash_spent")));
+        assert_eq!((award.value, award.position), (Some(5100.0), Some(1)));
+        assert!(accolade(&data.allplayerdata[1]).is_none());
     }
 }
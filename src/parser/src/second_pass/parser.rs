@@ -7,19 +7,28 @@ use crate::first_pass::read_bits::read_varint;
 use crate::first_pass::read_bits::Bitreader;
 use crate::first_pass::read_bits::DemoParserError;
 use crate::first_pass::stringtables::parse_userinfo;
+use crate::first_pass::stringtables::StringTableRecord;
 use crate::maps::demo_cmd_type_from_int;
 use crate::second_pass::collect_data::ProjectileRecord;
+use crate::second_pass::economy::EconomySnapshot;
 use crate::second_pass::entities::Entity;
 use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::grenade_areas::InfernoRecord;
+use crate::second_pass::grenade_areas::SmokeRecord;
+use crate::second_pass::match_interruptions::MatchInterruption;
+use crate::second_pass::net_messages::NetMessageRecord;
 use crate::second_pass::parser_settings::SecondPassParser;
 use crate::second_pass::parser_settings::*;
+use crate::second_pass::subtick::SubtickShot;
 use crate::second_pass::variants::PropColumn;
 use crate::second_pass::variants::Variant;
+use crate::second_pass::visibility::VisibilityRecord;
 use ahash::AHashMap;
 use ahash::AHashSet;
 use csgoproto::message_type::NetMessageType::{self, *};
 use csgoproto::CDemoFullPacket;
 use csgoproto::CDemoPacket;
+use csgoproto::CDemoUserCmd;
 use csgoproto::CnetMsgTick;
 use csgoproto::CsgoUserCmdPb;
 use csgoproto::CsvcMsgServerInfo;
@@ -41,6 +50,12 @@ pub struct SecondPassOutput {
     pub game_events: Vec<GameEvent>,
     pub skins: Vec<EconItem>,
     pub item_drops: Vec<EconItem>,
+    pub player_stats_updates: Vec<PlayerStatsUpdate>,
+    pub accolades: Vec<Accolade>,
+    pub match_interruptions: Vec<MatchInterruption>,
+    pub net_messages: Vec<NetMessageRecord>,
+    pub convar_changes: Vec<ConvarChange>,
+    pub string_table_records: Vec<StringTableRecord>,
     pub chat_messages: Vec<ChatMessageRecord>,
     pub convars: AHashMap<String, String>,
     pub header: Option<AHashMap<String, String>>,
@@ -48,6 +63,11 @@ pub struct SecondPassOutput {
     pub game_events_counter: AHashSet<String>,
     pub prop_info: PropController,
     pub projectiles: Vec<ProjectileRecord>,
+    pub infernos: Vec<InfernoRecord>,
+    pub smokes: Vec<SmokeRecord>,
+    pub economy_snapshots: Vec<EconomySnapshot>,
+    pub visibility: Vec<VisibilityRecord>,
+    pub subtick_shots: Vec<SubtickShot>,
     pub ptr: usize,
     pub voice_data: Vec<CsvcMsgVoiceData>,
     pub df_per_player: AHashMap<u64, AHashMap<u32, PropColumn>>,
@@ -75,7 +95,7 @@ impl<'a> SecondPassParser<'a> {
                 DemSignonPacket => self.parse_packet(&bytes, &mut buf2),
                 DemPacket => self.parse_packet(&bytes, &mut buf2),
                 DemStop => break,
-                DemUserCmd => Ok(()),
+                DemUserCmd => self.parse_user_command_cmd(bytes),
                 DemFullPacket => {
                     if self.parse_full_packet_and_break_if_needed(&bytes, &mut buf2, started_at)? {
                         break;
@@ -188,12 +208,19 @@ impl<'a> SecondPassParser<'a> {
             }
             bitreader.read_n_bytes_mut(size as usize, buf)?;
             let msg_bytes = &buf[..size as usize];
+            // Fullpackets repeat state we have already seen
+            if !self.wanted_net_messages.is_empty() && !is_fullpacket {
+                self.capture_net_message(msg_type as i32, msg_bytes);
+            }
             let ok = match NetMessageType::from(msg_type as i32) {
                 svc_PacketEntities => {
                     if should_parse_entities {
                         self.parse_packet_ents(&msg_bytes, is_fullpacket)?;
                         if !is_fullpacket {
                             self.collect_entities();
+                            if self.parse_shot_details {
+                                self.record_player_positions();
+                            }
                         }
                     }
                     Ok(())
@@ -207,6 +234,9 @@ impl<'a> SecondPassParser<'a> {
                 UM_SayText => self.create_custom_event_server_message(msg_bytes),
                 net_SetConVar => self.create_custom_event_parse_convars(msg_bytes),
                 CS_UM_PlayerStatsUpdate => self.parse_player_stats_update(msg_bytes),
+                CS_UM_VoteStart => self.parse_vote_start(msg_bytes),
+                CS_UM_VotePass => self.parse_vote_pass(msg_bytes),
+                CS_UM_VoteFailed => self.parse_vote_failed(msg_bytes),
                 CS_UM_ServerRankUpdate => self.create_custom_event_rank_update(msg_bytes),
                 net_Tick => self.parse_net_tick(msg_bytes),
                 svc_ClearAllStringTables => self.clear_stringtables(),
@@ -239,45 +269,46 @@ impl<'a> SecondPassParser<'a> {
                 Ok(m) => m,
                 _ => return Ok(()),
             };
+            let entity_id = match &user_cmd.base {
+                Some(base) => (base.pawn_entity_handle() & 0x7FF) as i32,
+                None => continue,
+            };
+            self.insert_user_cmd(entity_id, user_cmd);
+        }
+        Ok(())
+    }
+    fn insert_user_cmd(&mut self, entity_id: i32, user_cmd: CsgoUserCmdPb) {
+        self.record_subtick_shot(&user_cmd, entity_id);
 
-            if let Some(base) = user_cmd.base {
-                let entity_id = base.pawn_entity_handle() & 0x7FF;
-                if let Some(Some(ent)) = self.entities.get_mut(entity_id as usize) {
-                    let mut history = vec![];
-                    for input in user_cmd.input_history {
-                        let ih = InputHistory {
-                            player_tick_count: input.player_tick_count(),
-                            player_tick_fraction: input.player_tick_fraction(),
-                            render_tick_count: input.render_tick_count(),
-                            render_tick_fraction: input.render_tick_fraction(),
-                            x: input.view_angles.expect("CsgoInputHistoryEntryPb has no CMsgQAngle").x(),
-                            y: input.view_angles.expect("CsgoInputHistoryEntryPb has no CMsgQAngle").y(),
-                            z: input.view_angles.expect("CsgoInputHistoryEntryPb has no CMsgQAngle").z(),
-                        };
-                        history.push(ih);
-                    }
-                    ent.props.insert(USERCMD_INPUT_HISTORY_BASEID, Variant::InputHistory(history));
-                    ent.props.insert(USERCMD_LEFTMOVE, Variant::F32(base.leftmove()));
-                    ent.props.insert(USERCMD_FORWARDMOVE, Variant::F32(base.forwardmove()));
-                    ent.props.insert(USERCMD_IMPULSE, Variant::I32(base.impulse()));
-                    ent.props.insert(USERCMD_MOUSE_DX, Variant::I32(base.mousedx()));
-                    ent.props.insert(USERCMD_MOUSE_DY, Variant::I32(base.mousedy()));
-                    if let Some(viewangles) = base.viewangles {
-                        ent.props.insert(USERCMD_VIEWANGLE_X, Variant::F32(viewangles.x()));
-                        ent.props.insert(USERCMD_VIEWANGLE_Y, Variant::F32(viewangles.y()));
-                        ent.props.insert(USERCMD_VIEWANGLE_Z, Variant::F32(viewangles.z()));
-                    }
-                    if let Some(buttons_pb) = base.buttons_pb {
-                        ent.props.insert(USERCMD_BUTTONSTATE_1, Variant::U64(buttons_pb.buttonstate1()));
-                        ent.props.insert(USERCMD_BUTTONSTATE_2, Variant::U64(buttons_pb.buttonstate2()));
-                        ent.props.insert(USERCMD_BUTTONSTATE_3, Variant::U64(buttons_pb.buttonstate3()));
-                    }
-                    ent.props
-                        .insert(USERCMD_CONSUMED_SERVER_ANGLE_CHANGES, Variant::U32(base.consumed_server_angle_changes()));
-                }
+        let history = input_history(&user_cmd);
+        let base = match user_cmd.base {
+            Some(base) => base,
+            None => return,
+        };
+        let button_mask = base.buttons_pb.map(|buttons_pb| buttons_pb.buttonstate1());
+        if let Some(Some(ent)) = self.entities.get_mut(entity_id as usize) {
+            ent.props.insert(USERCMD_INPUT_HISTORY_BASEID, Variant::InputHistory(history));
+            ent.props.insert(USERCMD_LEFTMOVE, Variant::F32(base.leftmove()));
+            ent.props.insert(USERCMD_FORWARDMOVE, Variant::F32(base.forwardmove()));
+            ent.props.insert(USERCMD_IMPULSE, Variant::I32(base.impulse()));
+            ent.props.insert(USERCMD_MOUSE_DX, Variant::I32(base.mousedx()));
+            ent.props.insert(USERCMD_MOUSE_DY, Variant::I32(base.mousedy()));
+            if let Some(viewangles) = base.viewangles {
+                ent.props.insert(USERCMD_VIEWANGLE_X, Variant::F32(viewangles.x()));
+                ent.props.insert(USERCMD_VIEWANGLE_Y, Variant::F32(viewangles.y()));
+                ent.props.insert(USERCMD_VIEWANGLE_Z, Variant::F32(viewangles.z()));
+            }
+            if let Some(buttons_pb) = base.buttons_pb {
+                ent.props.insert(USERCMD_BUTTONSTATE_1, Variant::U64(buttons_pb.buttonstate1()));
+                ent.props.insert(USERCMD_BUTTONSTATE_2, Variant::U64(buttons_pb.buttonstate2()));
+                ent.props.insert(USERCMD_BUTTONSTATE_3, Variant::U64(buttons_pb.buttonstate3()));
             }
+            ent.props
+                .insert(USERCMD_CONSUMED_SERVER_ANGLE_CHANGES, Variant::U32(base.consumed_server_angle_changes()));
+        }
+        if let Some(button_mask) = button_mask {
+            self.record_buttons(entity_id, button_mask);
         }
-        Ok(())
     }
 
     pub fn parse_voice_data(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
@@ -307,7 +338,6 @@ impl<'a> SecondPassParser<'a> {
     }
 
     pub fn parse_full_packet(&mut self, bytes: &[u8], should_parse_entities: bool, buf: &mut Vec<u8>) -> Result<(), DemoParserError> {
-        self.string_tables = vec![];
         let full_packet = match CDemoFullPacket::decode(bytes) {
             Err(_e) => return Err(DemoParserError::MalformedMessage),
             Ok(p) => p,
@@ -324,6 +354,10 @@ impl<'a> SecondPassParser<'a> {
     pub fn parse_full_packet_stringtables(&mut self, full_packet: &CDemoFullPacket) {
         if let Some(string_table) = &full_packet.string_table {
             for item in &string_table.tables {
+                // Tables are not cleared on fullpackets, later updates are applied on top of the snapshot
+                if let Some(table) = self.string_tables.iter_mut().find(|t| t.name == item.table_name()) {
+                    table.replace_entries(&item.items);
+                }
                 if item.table_name == Some("instancebaseline".to_string()) {
                     for i in &item.items {
                         let k = i.str().parse::<u32>().unwrap_or(u32::MAX);
@@ -353,10 +387,105 @@ impl<'a> SecondPassParser<'a> {
         };
         let class_count = server_info.max_classes();
         self.cls_bits = Some((class_count as f32 + 1.).log2().ceil() as u32);
+        if server_info.tick_interval() > 0.0 {
+            self.tick_interval = server_info.tick_interval();
+        }
         Ok(())
     }
-    pub fn parse_user_command_cmd(&mut self, _data: &[u8]) -> Result<(), DemoParserError> {
-        // Only in pov demos. Maybe implement sometime. Includes buttons etc.
+    pub fn parse_user_command_cmd(&mut self, bytes: &[u8]) -> Result<(), DemoParserError> {
+        // Only in pov demos. These are the commands of the player recording the demo, so they are
+        // attached to the pawn of the recording players slot instead of the pawn handle in the cmd.
+        if !self.parse_usercmd {
+            return Ok(());
+        }
+        let user_cmd = match decode_pov_user_cmd(bytes) {
+            Some(user_cmd) => user_cmd,
+            None => return Ok(()),
+        };
+        if let Some(entity_id) = self.recording_player_entity_id() {
+            self.insert_user_cmd(entity_id, user_cmd);
+        }
         Ok(())
     }
+    fn recording_player_entity_id(&self) -> Option<i32> {
+        // player_slot is the controller entity id - 1
+        let player = self.find_user_by_controller_id(self.recording_player_slot? + 1)?;
+        player.player_entity_id
+    }
+}
+// DemUserCmd wraps the same CsgoUserCmdPb that GOTV demos send in CSGOUserCmd messages
+fn decode_pov_user_cmd(bytes: &[u8]) -> Option<CsgoUserCmdPb> {
+    let msg = CDemoUserCmd::decode(bytes).ok()?;
+    CsgoUserCmdPb::decode(msg.data()).ok()
+}
+// Entries without view angles (seen in pov demos) fall back to the angles of the command itself
+fn input_history(user_cmd: &CsgoUserCmdPb) -> Vec<InputHistory> {
+    let fallback = user_cmd.base.as_ref().and_then(|base| base.viewangles).unwrap_or_default();
+    user_cmd
+        .input_history
+        .iter()
+        .map(|input| {
+            let angles = input.view_angles.unwrap_or(fallback);
+            InputHistory {
+                player_tick_count: input.player_tick_count(),
+                player_tick_fraction: input.player_tick_fraction(),
+                render_tick_count: input.render_tick_count(),
+                render_tick_fraction: input.render_tick_fraction(),
+                x: angles.x(),
+                y: angles.y(),
+                z: angles.z(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csgoproto::CBaseUserCmdPb;
+    use csgoproto::CMsgQAngle;
+    use csgoproto::CsgoInputHistoryEntryPb;
+
+    fn angles(x: f32, y: f32) -> Option<CMsgQAngle> {
+        Some(CMsgQAngle {
+            x: Some(x),
+            y: Some(y),
+            z: Some(0.0),
+        })
+    }
+
+    #[test]
+    fn test_pov_user_cmd() {
+        let user_cmd = CsgoUserCmdPb {
+            base: Some(CBaseUserCmdPb {
+                viewangles: angles(10.0, 90.0),
+                ..Default::default()
+            }),
+            input_history: vec![
+                CsgoInputHistoryEntryPb {
+                    view_angles: angles(5.0, 45.0),
+                    player_tick_count: Some(1000),
+                    ..Default::default()
+                },
+                // Missing angles must not panic
+                CsgoInputHistoryEntryPb {
+                    player_tick_count: Some(1001),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let bytes = CDemoUserCmd {
+            cmd_number: Some(1),
+            data: Some(user_cmd.encode_to_vec().into()),
+        }
+        .encode_to_vec();
+
+        let decoded = decode_pov_user_cmd(&bytes).unwrap();
+        assert_eq!(decoded, user_cmd);
+        let history = input_history(&decoded);
+        assert_eq!((history[0].x, history[0].y, history[0].player_tick_count), (5.0, 45.0, 1000));
+        assert_eq!((history[1].x, history[1].y, history[1].player_tick_count), (10.0, 90.0, 1001));
+        assert_eq!(decode_pov_user_cmd(&[0xFF, 0xFF]), None);
+    }
 }
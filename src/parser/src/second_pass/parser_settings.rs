@@ -1,19 +1,32 @@
 use crate::first_pass::frameparser::StartEndOffset;
 use crate::first_pass::parser::FirstPassOutput;
+use crate::first_pass::parser::HEADER_ENDS_AT_BYTE;
 use crate::first_pass::prop_controller::PropController;
 use crate::first_pass::read_bits::DemoParserError;
 use crate::first_pass::sendtables::Serializer;
 use crate::first_pass::stringtables::StringTable;
+use crate::first_pass::stringtables::StringTableRecord;
 use crate::first_pass::stringtables::UserInfo;
+use crate::map_data::find_map_data;
+use crate::map_data::MapData;
 use crate::second_pass::collect_data::ProjectileRecord;
 use crate::second_pass::decoder::QfMapper;
+use crate::second_pass::economy::EconomySnapshot;
 use crate::second_pass::entities::Entity;
 use crate::second_pass::entities::PlayerMetaData;
 use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::grenade_areas::InfernoRecord;
+use crate::second_pass::grenade_areas::SmokeRecord;
+use crate::second_pass::match_interruptions::MatchInterruption;
+use crate::second_pass::net_messages::NetMessageRecord;
 use crate::second_pass::other_netmessages::Class;
 use crate::second_pass::parser::SecondPassOutput;
 use crate::second_pass::path_ops::FieldPath;
+use crate::second_pass::round_timing::RoundTiming;
+use crate::second_pass::subtick::SubtickShot;
 use crate::second_pass::variants::PropColumn;
+use crate::second_pass::visibility::OpenVisibility;
+use crate::second_pass::visibility::VisibilityRecord;
 use ahash::AHashMap;
 use ahash::AHashSet;
 use ahash::HashMap;
@@ -33,6 +46,7 @@ pub struct SecondPassParser<'a> {
     pub cls_by_id: &'a Vec<Class>,
     pub stringtable_players: BTreeMap<i32, UserInfo>,
     pub net_tick: u32,
+    pub tick_interval: f32,
     pub parse_inventory: bool,
     pub paths: Vec<FieldPath>,
     pub ptr: usize,
@@ -52,28 +66,59 @@ pub struct SecondPassParser<'a> {
     pub game_events_counter: AHashSet<String>,
     pub baselines: AHashMap<u32, Vec<u8>, RandomState>,
     pub projectiles: BTreeSet<i32>,
+    pub infernos: BTreeSet<i32>,
+    pub flash_started_at: AHashMap<i32, i32>,
+    pub map_data: Option<&'static MapData>,
     pub fullpackets_parsed: u32,
     pub wanted_players: AHashSet<u64>,
     pub wanted_ticks: AHashSet<i32>,
     // Output from parsing
     pub projectile_records: Vec<ProjectileRecord>,
+    pub inferno_records: Vec<InfernoRecord>,
+    pub smoke_records: Vec<SmokeRecord>,
+    // smoke entity id -> last m_nVoxelUpdate that was emitted
+    pub smoke_voxel_updates: AHashMap<i32, i32>,
+    pub economy_snapshots: Vec<EconomySnapshot>,
+    pub visibility_records: Vec<VisibilityRecord>,
+    // (observer steamid, target steamid) -> interval that is still open
+    pub open_visibility: AHashMap<(u64, u64), OpenVisibility>,
+    pub subtick_shots: Vec<SubtickShot>,
+    // pawn entity id -> recent (tick, usercmd button mask)
+    pub button_history: AHashMap<i32, Vec<(i32, u64)>>,
+    // pawn entity id -> two latest (tick, position)
+    pub position_history: AHashMap<i32, Vec<(i32, [f32; 3])>>,
+    pub round_timing: RoundTiming,
     pub voice_data: Vec<CsvcMsgVoiceData>,
     pub output: AHashMap<u32, PropColumn, RandomState>,
     pub header: HashMap<String, String>,
     pub skins: Vec<EconItem>,
     pub item_drops: Vec<EconItem>,
+    pub player_stats_updates: Vec<PlayerStatsUpdate>,
+    pub accolades: Vec<Accolade>,
+    pub match_interruptions: Vec<MatchInterruption>,
+    pub net_messages: Vec<NetMessageRecord>,
+    pub convar_changes: Vec<ConvarChange>,
+    pub string_table_records: Vec<StringTableRecord>,
     pub convars: AHashMap<String, String>,
     pub chat_messages: Vec<ChatMessageRecord>,
     pub player_end_data: Vec<PlayerEndMetaData>,
     // Settings
     pub wanted_events: Vec<String>,
+    pub wanted_net_messages: AHashSet<String>,
     pub parse_entities: bool,
     pub parse_projectiles: bool,
+    pub parse_economy: bool,
+    pub parse_visibility: bool,
+    pub parse_ticks_with_events: bool,
+    pub parse_shot_details: bool,
+    pub parse_string_tables: bool,
     pub is_debug_mode: bool,
     pub df_per_player: AHashMap<u64, AHashMap<u32, PropColumn>>,
     pub order_by_steamid: bool,
     pub last_tick: i32,
     pub parse_usercmd: bool,
+    // Slot of the player recording a pov demo, None for GOTV demos
+    pub recording_player_slot: Option<i32>,
 }
 #[derive(Debug, Clone)]
 pub struct Teams {
@@ -119,6 +164,32 @@ pub struct EconItem {
     pub skin_name: Option<String>,
 }
 #[derive(Debug, Clone)]
+pub struct PlayerStatsUpdate {
+    pub tick: i32,
+    pub steamid: Option<u64>,
+    pub name: Option<String>,
+    pub stat_id: i32,
+    pub stat_name: Option<String>,
+    pub delta: i32,
+}
+#[derive(Debug, Clone)]
+pub struct Accolade {
+    pub steamid: Option<u64>,
+    pub name: Option<String>,
+    pub accolade_id: Option<i32>,
+    pub accolade_name: Option<String>,
+    pub value: Option<f32>,
+    pub position: Option<i32>,
+}
+#[derive(Debug, Clone)]
+pub struct ConvarChange {
+    pub tick: i32,
+    pub name: String,
+    // None the first time the convar is seen
+    pub old_value: Option<String>,
+    pub new_value: String,
+}
+#[derive(Debug, Clone)]
 pub struct PlayerEndMetaData {
     pub steamid: Option<u64>,
     pub name: Option<String>,
@@ -126,7 +197,8 @@ pub struct PlayerEndMetaData {
 }
 
 impl<'a> SecondPassParser<'a> {
-    pub fn create_output(self) -> SecondPassOutput {
+    pub fn create_output(mut self) -> SecondPassOutput {
+        self.close_visibility();
         SecondPassOutput {
             voice_data: self.voice_data,
             chat_messages: self.chat_messages,
@@ -135,6 +207,12 @@ impl<'a> SecondPassParser<'a> {
             game_events: self.game_events,
             skins: self.skins,
             item_drops: self.item_drops,
+            player_stats_updates: self.player_stats_updates,
+            accolades: self.accolades,
+            match_interruptions: self.match_interruptions,
+            net_messages: self.net_messages,
+            convar_changes: self.convar_changes,
+            string_table_records: self.string_table_records,
             header: None,
             player_md: self.player_end_data,
             game_events_counter: self.game_events_counter,
@@ -147,6 +225,11 @@ impl<'a> SecondPassParser<'a> {
                 &["none".to_string()],
             ),
             projectiles: self.projectile_records,
+            infernos: self.inferno_records,
+            smokes: self.smoke_records,
+            economy_snapshots: self.economy_snapshots,
+            visibility: self.visibility_records,
+            subtick_shots: self.subtick_shots,
             ptr: self.ptr,
             df_per_player: self.df_per_player,
             entities: self.entities,
@@ -168,7 +251,9 @@ impl<'a> SecondPassParser<'a> {
         let debug = if args.len() > 2 { args[2] == "true" } else { false };
 
         Ok(SecondPassParser {
-            parse_usercmd: contains_usercmd_prop(&first_pass_output.settings.wanted_player_props),
+            parse_usercmd: contains_usercmd_prop(&first_pass_output.settings.wanted_player_props)
+                || (first_pass_output.settings.parse_shot_details && wants_weapon_fire(&first_pass_output.settings.wanted_events)),
+            recording_player_slot: first_pass_output.header.get("player_slot").and_then(|slot| slot.parse().ok()),
             last_tick: 0,
             start_end_offset: start_end_offset,
             order_by_steamid: first_pass_output.order_by_steamid,
@@ -183,10 +268,21 @@ impl<'a> SecondPassParser<'a> {
             ],
             parse_inventory: first_pass_output.prop_controller.wanted_player_props.contains(&"inventory".to_string()),
             net_tick: 0,
+            tick_interval: first_pass_output.tick_interval,
             c4_entity_id: None,
             stringtable_players: first_pass_output.stringtable_players,
             is_debug_mode: debug,
             projectile_records: vec![],
+            inferno_records: vec![],
+            smoke_records: vec![],
+            smoke_voxel_updates: AHashMap::default(),
+            economy_snapshots: vec![],
+            visibility_records: vec![],
+            open_visibility: AHashMap::default(),
+            subtick_shots: vec![],
+            button_history: AHashMap::default(),
+            position_history: AHashMap::default(),
+            round_timing: RoundTiming::default(),
             parse_all_packets: parse_all_packets,
             wanted_players: first_pass_output.wanted_players.clone(),
             wanted_ticks: first_pass_output.wanted_ticks.clone(),
@@ -204,17 +300,36 @@ impl<'a> SecondPassParser<'a> {
             output: AHashMap::default(),
             game_events: vec![],
             wanted_events: first_pass_output.settings.wanted_events.clone(),
+            wanted_net_messages: AHashSet::from_iter(first_pass_output.settings.wanted_net_messages.iter().cloned()),
             parse_entities: first_pass_output.settings.parse_ents,
             projectiles: BTreeSet::default(),
+            infernos: BTreeSet::default(),
+            flash_started_at: AHashMap::default(),
+            map_data: first_pass_output.header.get("map_name").and_then(|name| find_map_data(name)),
             baselines: first_pass_output.baselines.clone(),
-            string_tables: first_pass_output.string_tables.clone(),
+            // Starting from the beginning the tables get created again
+            string_tables: match offset == HEADER_ENDS_AT_BYTE {
+                true => vec![],
+                false => first_pass_output.string_tables.clone(),
+            },
             teams: Teams::new(),
             game_events_counter: AHashSet::default(),
             parse_projectiles: first_pass_output.settings.parse_projectiles,
+            parse_economy: first_pass_output.settings.parse_economy,
+            parse_visibility: first_pass_output.settings.parse_visibility,
+            parse_ticks_with_events: first_pass_output.settings.parse_ticks_with_events,
+            parse_shot_details: first_pass_output.settings.parse_shot_details,
+            parse_string_tables: first_pass_output.settings.parse_string_tables,
             rules_entity_id: None,
             convars: AHashMap::default(),
             chat_messages: vec![],
             item_drops: vec![],
+            player_stats_updates: vec![],
+            accolades: vec![],
+            match_interruptions: vec![],
+            net_messages: vec![],
+            convar_changes: vec![],
+            string_table_records: vec![],
             skins: vec![],
             player_end_data: vec![],
             huffman_lookup_table: &first_pass_output.settings.huffman_lookup_table,
@@ -281,6 +396,24 @@ pub struct SpecialIDs {
     pub custom_name: Option<u32>,
 
     pub is_airborn: Option<u32>,
+
+    pub inferno_fire_count: Option<u32>,
+    pub smoke_did_effect: Option<u32>,
+    pub smoke_effect_tick_begin: Option<u32>,
+    pub smoke_detonation_pos: Option<u32>,
+    pub smoke_voxel_update: Option<u32>,
+    pub flash_duration: Option<u32>,
+    pub freeze_period: Option<u32>,
+    pub warmup_period: Option<u32>,
+    pub bomb_planted: Option<u32>,
+    pub terrorist_timeout: Option<u32>,
+    pub ct_timeout: Option<u32>,
+    pub technical_timeout: Option<u32>,
+    pub match_waiting_for_resume: Option<u32>,
+    pub spotted_by_mask: Option<u32>,
+    pub is_ducked: Option<u32>,
+    pub is_ducking: Option<u32>,
+    pub is_walking: Option<u32>,
 }
 impl SpecialIDs {
     pub fn new() -> Self {
@@ -329,6 +462,23 @@ impl SpecialIDs {
             in_buy_zone: None,
             custom_name: None,
             is_airborn: None,
+            inferno_fire_count: None,
+            smoke_did_effect: None,
+            smoke_effect_tick_begin: None,
+            smoke_detonation_pos: None,
+            smoke_voxel_update: None,
+            flash_duration: None,
+            freeze_period: None,
+            warmup_period: None,
+            bomb_planted: None,
+            terrorist_timeout: None,
+            ct_timeout: None,
+            technical_timeout: None,
+            match_waiting_for_resume: None,
+            spotted_by_mask: None,
+            is_ducked: None,
+            is_ducking: None,
+            is_walking: None,
         }
     }
 }
@@ -345,3 +495,7 @@ pub fn create_huffman_lookup_table() -> Vec<(u8, u8)> {
 fn contains_usercmd_prop(names: &[String]) -> bool {
     names.iter().any(|name| name.contains("usercmd"))
 }
+// weapon_fire gets its shot details from the usercmds
+fn wants_weapon_fire(wanted_events: &[String]) -> bool {
+    wanted_events.iter().any(|name| name == "weapon_fire" || name == "all")
+}
@@ -0,0 +1,56 @@
+use crate::second_pass::collect_data::PropCollectionError;
+use crate::second_pass::game_events::GameEventInfo;
+use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::variants::Variant;
+
+// Round relative timing props: "round", "round_time", "time_since_freeze_end",
+// "time_since_bomb_plant" and "is_warmup". The times are tracked from the same rules entity
+// changes that emit the round_start/round_freeze_end events, so they need the whole demo to be
+// parsed in order (see NON_MULTITHREADABLE_PROPS).
+
+#[derive(Debug, Clone, Default)]
+pub struct RoundTiming {
+    pub round_start_tick: Option<i32>,
+    pub freeze_end_tick: Option<i32>,
+    pub bomb_plant_tick: Option<i32>,
+}
+
+impl<'a> SecondPassParser<'a> {
+    pub fn track_round_timing(&mut self, events: &[GameEventInfo]) {
+        for event in events {
+            match event {
+                GameEventInfo::FreezePeriodStart(_) => {
+                    self.round_timing = RoundTiming {
+                        round_start_tick: Some(self.tick),
+                        freeze_end_tick: None,
+                        bomb_plant_tick: None,
+                    };
+                }
+                GameEventInfo::FreezePeriodEnd() => self.round_timing.freeze_end_tick = Some(self.tick),
+                GameEventInfo::BombPlanted() => self.round_timing.bomb_plant_tick = Some(self.tick),
+                _ => {}
+            }
+        }
+    }
+
+    fn seconds_since(&self, tick: Option<i32>) -> Result<Variant, PropCollectionError> {
+        match tick {
+            Some(tick) => Ok(Variant::F32((self.tick - tick) as f32 * self.tick_interval)),
+            None => Err(PropCollectionError::RoundTimingNotSet),
+        }
+    }
+
+    pub fn find_round_timing_prop(&self, prop_name: &str) -> Result<Variant, PropCollectionError> {
+        match prop_name {
+            "round" => self.find_current_round().ok_or(PropCollectionError::RoundTimingNotSet),
+            "round_time" => self.seconds_since(self.round_timing.round_start_tick),
+            "time_since_freeze_end" => self.seconds_since(self.round_timing.freeze_end_tick),
+            "time_since_bomb_plant" => self.seconds_since(self.round_timing.bomb_plant_tick),
+            "is_warmup" => match (self.prop_controller.special_ids.warmup_period, self.rules_entity_id) {
+                (Some(prop_id), Some(entid)) => self.get_prop_from_ent(&prop_id, &entid),
+                _ => Err(PropCollectionError::RulesEntityIdNotSet),
+            },
+            _ => Err(PropCollectionError::UnknownCustomPropName),
+        }
+    }
+}
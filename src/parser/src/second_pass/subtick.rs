@@ -0,0 +1,148 @@
+use crate::second_pass::game_events::EventField;
+use crate::second_pass::game_events::GameEvent;
+use crate::second_pass::parser_settings::SecondPassParser;
+use crate::second_pass::variants::Variant;
+use ahash::AHashMap;
+use csgoproto::CsgoUserCmdPb;
+
+// Shots happen at subtick fractions. The usercmd that presses attack points to the input
+// history entry where the press happened, which carries the exact fraction, view angles
+// and shoot position. These are joined onto weapon_fire events after parsing when
+// ParserInputs::parse_shot_details is set.
+//
+// Only presses are recorded (attack1_start_history_index). Automatic weapons keep firing
+// while attack is held down without a new press, those shots have no input history entry
+// that marks them, so their weapon_fire events get None for all subtick fields.
+
+// Max difference between the tick the usercmd was received and the weapon_fire tick
+const SUBTICK_MATCH_WINDOW: i32 = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtickShot {
+    pub steamid: u64,
+    pub tick: i32,
+    pub player_tick_count: i32,
+    pub player_tick_fraction: f32,
+    pub render_tick_fraction: f32,
+    pub pitch: Option<f32>,
+    pub yaw: Option<f32>,
+    pub position: Option<[f32; 3]>,
+}
+
+impl<'a> SecondPassParser<'a> {
+    pub fn record_subtick_shot(&mut self, user_cmd: &CsgoUserCmdPb, entity_id: i32) {
+        let idx = user_cmd.attack1_start_history_index();
+        if !self.parse_shot_details || idx < 0 {
+            return;
+        }
+        let (input, steamid) = match (user_cmd.input_history.get(idx as usize), self.players.get(&entity_id).and_then(|p| p.steamid)) {
+            (Some(input), Some(steamid)) => (input, steamid),
+            _ => return,
+        };
+        let position = match &input.shoot_position {
+            Some(pos) => Some([pos.x(), pos.y(), pos.z()]),
+            None => self.find_player_position(&entity_id).ok(),
+        };
+        self.subtick_shots.push(SubtickShot {
+            steamid,
+            tick: self.tick,
+            player_tick_count: input.player_tick_count(),
+            player_tick_fraction: input.player_tick_fraction(),
+            render_tick_fraction: input.render_tick_fraction(),
+            pitch: input.view_angles.as_ref().map(|a| a.x()),
+            yaw: input.view_angles.as_ref().map(|a| a.y()),
+            position,
+        });
+    }
+}
+
+fn shot_fields(shot: Option<&SubtickShot>) -> Vec<EventField> {
+    let field = |name: &str, data: Option<Variant>| EventField { name: name.to_string(), data };
+    let coordinate = |axis: usize| shot.and_then(|s| s.position).map(|p| Variant::F32(p[axis]));
+    vec![
+        field("subtick_fraction", shot.map(|s| Variant::F32(s.player_tick_fraction))),
+        field("render_tick_fraction", shot.map(|s| Variant::F32(s.render_tick_fraction))),
+        field("shot_pitch", shot.and_then(|s| s.pitch).map(Variant::F32)),
+        field("shot_yaw", shot.and_then(|s| s.yaw).map(Variant::F32)),
+        field("shot_X", coordinate(0)),
+        field("shot_Y", coordinate(1)),
+        field("shot_Z", coordinate(2)),
+    ]
+}
+
+// Each shot is matched to the closest weapon_fire of the same player and used at most once.
+// Shots fired by holding attack down don't start a new press, so those events get None.
+pub fn add_subtick_fields(events: &mut [GameEvent], shots: &[SubtickShot]) {
+    let mut per_player: AHashMap<u64, Vec<(&SubtickShot, bool)>> = AHashMap::default();
+    for shot in shots {
+        per_player.entry(shot.steamid).or_default().push((shot, false));
+    }
+    for player_shots in per_player.values_mut() {
+        player_shots.sort_by_key(|(shot, _)| (shot.tick, shot.player_tick_count));
+        player_shots.dedup_by(|a, b| a.0.player_tick_count == b.0.player_tick_count && a.0.player_tick_fraction == b.0.player_tick_fraction);
+    }
+    for event in events.iter_mut().filter(|e| e.name == "weapon_fire") {
+        let steamid = event.fields.iter().find(|f| f.name == "user_steamid").and_then(|f| match &f.data {
+            Some(Variant::String(s)) => s.parse::<u64>().ok(),
+            Some(Variant::U64(s)) => Some(*s),
+            _ => None,
+        });
+        let shot = steamid.and_then(|steamid| per_player.get_mut(&steamid)).and_then(|player_shots| {
+            let best = player_shots
+                .iter_mut()
+                .filter(|(shot, used)| !used && (shot.tick - event.tick).abs() <= SUBTICK_MATCH_WINDOW)
+                .min_by_key(|(shot, _)| (shot.tick - event.tick).abs())?;
+            best.1 = true;
+            Some(best.0)
+        });
+        event.fields.extend(shot_fields(shot));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shot(tick: i32, fraction: f32) -> SubtickShot {
+        SubtickShot {
+            steamid: 1,
+            tick,
+            player_tick_count: tick,
+            player_tick_fraction: fraction,
+            render_tick_fraction: fraction,
+            pitch: Some(1.0),
+            yaw: None,
+            position: Some([1.0, 2.0, 3.0]),
+        }
+    }
+
+    fn weapon_fire(tick: i32) -> GameEvent {
+        GameEvent {
+            name: "weapon_fire".to_string(),
+            fields: vec![EventField {
+                name: "user_steamid".to_string(),
+                data: Some(Variant::String("1".to_string())),
+            }],
+            tick,
+        }
+    }
+
+    fn field(event: &GameEvent, name: &str) -> Option<Variant> {
+        event.fields.iter().find(|f| f.name == name).and_then(|f| f.data.clone())
+    }
+
+    #[test]
+    fn test_add_subtick_fields() {
+        // Same shot repeated from an overlapping chunk
+        let shots = vec![shot(100, 0.25), shot(100, 0.25), shot(200, 0.5)];
+        let mut events = vec![weapon_fire(100), weapon_fire(108), weapon_fire(201)];
+        add_subtick_fields(&mut events, &shots);
+        assert_eq!(field(&events[0], "subtick_fraction"), Some(Variant::F32(0.25)));
+        assert_eq!(field(&events[0], "shot_Z"), Some(Variant::F32(3.0)));
+        assert_eq!(field(&events[0], "shot_pitch"), Some(Variant::F32(1.0)));
+        // Missing view angles stay None instead of becoming 0.0
+        assert_eq!(field(&events[0], "shot_yaw"), None);
+        assert_eq!(field(&events[1], "subtick_fraction"), None);
+        assert_eq!(field(&events[2], "subtick_fraction"), Some(Variant::F32(0.5)));
+    }
+}
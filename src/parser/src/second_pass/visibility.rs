@@ -0,0 +1,144 @@
+use crate::second_pass::parser_settings::SecondPassParser;
+use ahash::AHashMap;
+
+// Visibility intervals built from the pawns' m_bSpottedByMask. Instead of emitting the mask
+// every tick, an interval is opened when a bit gets set and closed when it is cleared.
+// Each bit in the mask is the controller entity id (minus one) of the observer.
+
+const SPOTTED_MASK_BITS: u32 = 32;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisibilityRecord {
+    pub observer_steamid: u64,
+    pub observer_name: Option<String>,
+    pub target_steamid: u64,
+    pub target_name: Option<String>,
+    pub start_tick: i32,
+    // First tick the target was no longer spotted (or the last parsed tick)
+    pub end_tick: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenVisibility {
+    pub start_tick: i32,
+    pub observer_name: Option<String>,
+    pub target_name: Option<String>,
+}
+
+impl<'a> SecondPassParser<'a> {
+    pub fn update_visibility(&mut self, target_entid: i32, mask: u32) {
+        let target = match self.players.get(&target_entid) {
+            Some(player) => player,
+            None => return,
+        };
+        let target_steamid = match target.steamid {
+            Some(steamid) => steamid,
+            None => return,
+        };
+        let target_name = target.name.clone();
+
+        let mut spotted_by = vec![];
+        for bit in 0..SPOTTED_MASK_BITS {
+            if mask & (1 << bit) == 0 {
+                continue;
+            }
+            if let Some(observer) = self.find_user_by_controller_id(bit as i32 + 1) {
+                if let Some(steamid) = observer.steamid {
+                    spotted_by.push((steamid, observer.name.clone()));
+                }
+            }
+        }
+        // Close intervals of observers that lost sight
+        let lost: Vec<(u64, u64)> = self
+            .open_visibility
+            .keys()
+            .filter(|(observer, target)| *target == target_steamid && !spotted_by.iter().any(|(s, _)| s == observer))
+            .copied()
+            .collect();
+        for key in lost {
+            if let Some(open) = self.open_visibility.remove(&key) {
+                self.visibility_records.push(VisibilityRecord {
+                    observer_steamid: key.0,
+                    observer_name: open.observer_name,
+                    target_steamid: key.1,
+                    target_name: open.target_name,
+                    start_tick: open.start_tick,
+                    end_tick: self.tick,
+                });
+            }
+        }
+        let tick = self.tick;
+        for (observer_steamid, observer_name) in spotted_by {
+            self.open_visibility
+                .entry((observer_steamid, target_steamid))
+                .or_insert_with(|| OpenVisibility {
+                    start_tick: tick,
+                    observer_name,
+                    target_name: target_name.clone(),
+                });
+        }
+    }
+
+    // Intervals still open when this part of the demo ends are closed at the last tick.
+    pub fn close_visibility(&mut self) {
+        let open: AHashMap<(u64, u64), OpenVisibility> = std::mem::take(&mut self.open_visibility);
+        for ((observer_steamid, target_steamid), open) in open {
+            self.visibility_records.push(VisibilityRecord {
+                observer_steamid,
+                observer_name: open.observer_name,
+                target_steamid,
+                target_name: open.target_name,
+                start_tick: open.start_tick,
+                end_tick: self.tick,
+            });
+        }
+    }
+}
+
+// Demos are parsed in parallel chunks that each start from a fullpacket, so the same
+// interval can be split or repeated across chunks. Overlapping and touching intervals
+// of the same observer and target are merged.
+pub fn merge_visibility(mut records: Vec<VisibilityRecord>) -> Vec<VisibilityRecord> {
+    records.sort_by_key(|r| (r.observer_steamid, r.target_steamid, r.start_tick, r.end_tick));
+    let mut merged: Vec<VisibilityRecord> = vec![];
+    for record in records {
+        if let Some(last) = merged.last_mut() {
+            if last.observer_steamid == record.observer_steamid && last.target_steamid == record.target_steamid && record.start_tick <= last.end_tick {
+                last.end_tick = last.end_tick.max(record.end_tick);
+                continue;
+            }
+        }
+        merged.push(record);
+    }
+    merged.sort_by_key(|r| (r.start_tick, r.observer_steamid, r.target_steamid));
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(observer: u64, target: u64, start_tick: i32, end_tick: i32) -> VisibilityRecord {
+        VisibilityRecord {
+            observer_steamid: observer,
+            observer_name: None,
+            target_steamid: target,
+            target_name: None,
+            start_tick,
+            end_tick,
+        }
+    }
+
+    #[test]
+    fn test_merge_visibility() {
+        let records = vec![
+            record(1, 2, 100, 200),
+            record(1, 2, 200, 300),
+            record(1, 2, 250, 260),
+            record(1, 2, 400, 500),
+            record(3, 2, 150, 160),
+        ];
+        let merged = merge_visibility(records);
+        assert_eq!(merged, vec![record(1, 2, 100, 300), record(3, 2, 150, 160), record(1, 2, 400, 500)]);
+    }
+}
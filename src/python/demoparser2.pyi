@@ -1,25 +1,50 @@
 import pandas as pd
-from typing import Dict, Sequence, Optional, List, Tuple
+from typing import Any, Dict, Sequence, Optional, List, Tuple
 
 class DemoParser:
     def __init__(self, path: str) -> None: ...
     def parse_header(self) -> Dict[str, str]: ...
+    def parse_file_info(self) -> Dict[str, Any]: ...
+    def parse_map_data(self, *, nav_path: Optional[str] = None) -> Optional[Dict[str, Any]]: ...
     def list_game_events(self) -> List[str]: ...
     def parse_grenades(self) -> pd.DataFrame: ...
+    def parse_infernos(self) -> pd.DataFrame: ...
+    def parse_smokes(self) -> pd.DataFrame: ...
     def parse_player_info(self) -> pd.DataFrame: ...
+    def parse_rounds(self) -> pd.DataFrame: ...
+    def parse_economy(self) -> pd.DataFrame: ...
+    def parse_player_stats(self, *, per_round: bool = False) -> pd.DataFrame: ...
+    def parse_trade_kills(self, *, window_ticks: Optional[int] = None) -> pd.DataFrame: ...
+    def parse_weapon_accuracy(self) -> pd.DataFrame: ...
+    def parse_flashes(self, *, per_thrower: bool = False) -> pd.DataFrame: ...
+    def parse_grenade_damage(self) -> pd.DataFrame: ...
+    def parse_clutches(self) -> pd.DataFrame: ...
+    def parse_visibility(self) -> pd.DataFrame: ...
+    def parse_aim(self) -> pd.DataFrame: ...
     def parse_item_drops(self) -> pd.DataFrame: ...
+    def parse_player_stats_updates(self) -> pd.DataFrame: ...
+    def parse_accolades(self) -> pd.DataFrame: ...
+    def parse_match_interruptions(self) -> pd.DataFrame: ...
+    def parse_net_messages(self, msg_types: Sequence[str]) -> List[Dict[str, Any]]: ...
+    def parse_convars(self) -> Dict[str, str]: ...
+    def parse_convar_changes(self) -> pd.DataFrame: ...
+    def parse_string_tables(self, *, tables: Optional[Sequence[str]] = None) -> pd.DataFrame: ...
     def parse_skins(self) -> pd.DataFrame: ...
     def parse_event(
         self,
         event_name: str,
         player: Optional[Sequence[str]] = None,
         other: Optional[Sequence[str]] = None,
+        nav_path: Optional[str] = None,
+        shot_details: bool = False,
     ) -> pd.DataFrame: ...
     def parse_events(
         self,
         event_name: Sequence[str],
         player: Optional[Sequence[str]] = None,
         other: Optional[Sequence[str]] = None,
+        nav_path: Optional[str] = None,
+        shot_details: bool = False,
     ) -> List[Tuple[str, pd.DataFrame]]: ...
     def parse_voice(self) -> Dict[str, bytes]: ...
     def parse_ticks(
@@ -27,6 +52,7 @@ class DemoParser:
         wanted_props: Sequence[str],
         players: Optional[Sequence[int]] = None,
         ticks: Optional[Sequence[int]] = None,
+        nav_path: Optional[str] = None,
     ) -> pd.DataFrame:
         """Parse the specified props.
 
@@ -36,6 +62,9 @@ class DemoParser:
                 `None` or an empty Sequence means all players. Defaults to `None`.
             ticks (Optional[Sequence[int]]): Sequence of ticks to parse.
                 `None` or an empty Sequence means all ticks. Defaults to `None`.
+            nav_path (Optional[str]): Path to the map's .nav file. Adds the nav area
+                (nav_area_id, nav_area_X/Y/Z centroid and nav_place) each player stands in.
+                Requires X, Y and Z in wanted_props. Defaults to `None`.
 
         Returns:
             pd.DataFrame: Dataframe of all the parsed props for each player at each tick.
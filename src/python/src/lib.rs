@@ -1,15 +1,72 @@
 use ahash::AHashMap;
 use itertools::Itertools;
 use memmap2::Mmap;
+use parser::analysis::accuracy::shots_from_events;
+use parser::analysis::accuracy::weapon_accuracy;
+use parser::analysis::accuracy::ACCURACY_EVENTS;
+use parser::analysis::accuracy::ACCURACY_OTHER_PROPS;
+use parser::analysis::accuracy::ACCURACY_PLAYER_PROPS;
+use parser::analysis::aim::aim_samples;
+use parser::analysis::aim::engagements;
+use parser::analysis::aim::AIM_EVENTS;
+use parser::analysis::aim::AIM_OTHER_PROPS;
+use parser::analysis::aim::AIM_PLAYER_PROPS;
+use parser::analysis::clutches::alive_states;
+use parser::analysis::clutches::clutches;
+use parser::analysis::clutches::CLUTCHES_EVENTS;
+use parser::analysis::clutches::CLUTCHES_OTHER_PROPS;
+use parser::analysis::clutches::CLUTCHES_PLAYER_PROPS;
+use parser::analysis::clutches::CLUTCHES_TICK_PROPS;
+use parser::analysis::economy::economy_from_snapshots;
+use parser::analysis::economy::ECONOMY_EVENTS;
+use parser::analysis::flashes::flash_blinds;
+use parser::analysis::flashes::flash_detonations;
+use parser::analysis::flashes::flash_stats;
+use parser::analysis::flashes::FlashStats;
+use parser::analysis::flashes::FLASHES_EVENTS;
+use parser::analysis::flashes::FLASHES_OTHER_PROPS;
+use parser::analysis::flashes::FLASHES_PLAYER_PROPS;
+use parser::analysis::player_stats::player_round_stats;
+use parser::analysis::player_stats::player_stats;
+use parser::analysis::player_stats::PlayerRoundStats;
+use parser::analysis::player_stats::PLAYER_STATS_EVENTS;
+use parser::analysis::player_stats::PLAYER_STATS_OTHER_PROPS;
+use parser::analysis::player_stats::PLAYER_STATS_PLAYER_PROPS;
+use parser::analysis::rounds::rounds_from_events;
+use parser::analysis::rounds::ROUND_EVENTS;
+use parser::analysis::rounds::ROUND_OTHER_PROPS;
+use parser::analysis::seconds_to_ticks;
+use parser::analysis::trades::kills_from_events;
+use parser::analysis::trades::trade_kills;
+use parser::analysis::trades::TRADES_EVENTS;
+use parser::analysis::trades::TRADES_OTHER_PROPS;
+use parser::analysis::trades::TRADES_PLAYER_PROPS;
+use parser::analysis::trades::TRADE_WINDOW_SECONDS;
+use parser::analysis::utility_damage::grenade_throws;
+use parser::analysis::utility_damage::utility_damage;
+use parser::analysis::utility_damage::UTILITY_DAMAGE_EVENTS;
+use parser::analysis::utility_damage::UTILITY_DAMAGE_OTHER_PROPS;
+use parser::analysis::utility_damage::UTILITY_DAMAGE_PLAYER_PROPS;
+use parser::first_pass::file_info::parse_file_info;
 use parser::first_pass::parser_settings::create_mmap;
 use parser::first_pass::parser_settings::rm_map_user_friendly_names;
 use parser::first_pass::parser_settings::rm_user_friendly_names;
 use parser::first_pass::parser_settings::ParserInputs;
+use parser::first_pass::prop_controller::PropInfo;
+use parser::first_pass::prop_controller::PLAYER_X_ID;
+use parser::first_pass::prop_controller::PLAYER_Y_ID;
+use parser::first_pass::prop_controller::PLAYER_Z_ID;
 use parser::first_pass::read_bits::DemoParserError;
+use parser::map_data::find_map_data;
+use parser::nav::add_nav_fields;
+use parser::nav::NavArea;
+use parser::nav::NavMesh;
 use parser::parse_demo::Parser;
 use parser::second_pass::game_events::EventField;
 use parser::second_pass::game_events::GameEvent;
+use parser::second_pass::net_messages::supported_net_message_types;
 use parser::second_pass::parser_settings::create_huffman_lookup_table;
+use parser::second_pass::variants::PropColumn;
 use parser::second_pass::variants::VarVec;
 use parser::second_pass::variants::Variant;
 #[cfg(feature = "voice")]
@@ -19,7 +76,7 @@ use polars::prelude::ArrowField;
 use polars::prelude::NamedFrom;
 use polars::series::Series;
 use polars_arrow::array::{
-    Array, BooleanArray, Float32Array, Int32Array, UInt32Array, UInt64Array, Utf8Array,
+    Array, BinaryArray, BooleanArray, Float32Array, Int32Array, UInt32Array, UInt64Array, Utf8Array,
 };
 use polars_arrow::ffi;
 use pyo3::exceptions::PyValueError;
@@ -34,8 +91,11 @@ use pyo3::{PyAny, PyObject, PyResult};
 use std::sync::Arc;
 
 use pyo3::create_exception;
+
 create_exception!(DemoParser, Exception, pyo3::exceptions::PyException);
 
+const LAST_PLACE_NAME: &str = "CCSPlayerPawn.m_szLastPlaceName";
+
 struct PyVariant(Variant);
 
 impl<'source> FromPyObject<'source> for PyVariant {
@@ -103,26 +163,1792 @@ impl DemoParser {
             only_convars: false,
             huffman_lookup_table: &self.huf,
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        Ok(output
+            .header
+            .unwrap_or_else(AHashMap::default)
+            .to_object(py))
+    }
+    /// Returns the CDemoFileInfo written at the end of the demo: "playback_time", "playback_ticks",
+    /// "playback_frames" and "round_start_ticks". Only reads the fileinfo frame, no parsing needed.
+    pub fn parse_file_info(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let info = match parse_file_info(&self.mmap) {
+            Ok(info) => info,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let dict = PyDict::new_bound(py);
+        dict.set_item("playback_time", info.playback_time)?;
+        dict.set_item("playback_ticks", info.playback_ticks)?;
+        dict.set_item("playback_frames", info.playback_frames)?;
+        dict.set_item("round_start_ticks", info.round_start_ticks)?;
+        Ok(dict.to_object(py))
+    }
+    /// Returns the radar metadata of the demo's map: "map_name", "pos_x", "pos_y", "scale" and
+    /// "levels" (vertical sections with altitude_min/altitude_max). Returns None if the map is
+    /// not known. Use the "radar_x"/"radar_y" props in parse_ticks to get positions in radar pixels.
+    /// Callouts are not bundled: with nav_path (the map's .nav file) the result also has
+    /// "callouts" (place name -> list of polygons), with place names learned from the players'
+    /// last_place_name in this demo. This parses every tick and is slower.
+    #[pyo3(signature = (*, nav_path=None))]
+    pub fn parse_map_data(&self, py: Python<'_>, nav_path: Option<String>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: false,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let map_name = match output.header.as_ref().and_then(|h| h.get("map_name")) {
+            Some(name) => name.clone(),
+            None => return Ok(py.None()),
+        };
+        let map_data = match find_map_data(&map_name) {
+            Some(map_data) => map_data,
+            None => return Ok(py.None()),
+        };
+        let levels = PyList::empty_bound(py);
+        for level in map_data.levels {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("name", level.name)?;
+            dict.set_item("altitude_min", level.altitude_min)?;
+            dict.set_item("altitude_max", level.altitude_max)?;
+            levels.append(dict)?;
+        }
+        let dict = PyDict::new_bound(py);
+        dict.set_item("map_name", map_name)?;
+        dict.set_item("pos_x", map_data.pos_x)?;
+        dict.set_item("pos_y", map_data.pos_y)?;
+        dict.set_item("scale", map_data.scale)?;
+        dict.set_item("levels", levels)?;
+        if let Some(nav_path) = nav_path {
+            let mut nav = match NavMesh::from_path(&nav_path) {
+                Ok(nav) => nav,
+                Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+            };
+            let wanted_player_props: Vec<String> = ["X", "Y", "Z", "last_place_name"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect();
+            let real_player_props = match rm_user_friendly_names(&wanted_player_props) {
+                Ok(real_props) => real_props,
+                Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+            };
+            let mut real_name_to_og_name = AHashMap::default();
+            for (real_name, user_friendly_name) in
+                real_player_props.iter().zip(&wanted_player_props)
+            {
+                real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+            }
+            let settings = ParserInputs {
+                real_name_to_og_name,
+                wanted_players: vec![],
+                wanted_player_props: real_player_props,
+                wanted_other_props: vec![],
+                wanted_prop_states: AHashMap::default(),
+                wanted_events: vec![],
+                parse_ents: true,
+                wanted_ticks: vec![],
+                parse_projectiles: false,
+                only_header: true,
+                count_props: false,
+                only_convars: false,
+                huffman_lookup_table: &self.huf,
+                order_by_steamid: false,
+                parse_economy: false,
+                parse_visibility: false,
+                parse_ticks_with_events: false,
+                parse_shot_details: false,
+                wanted_net_messages: vec![],
+                parse_string_tables: false,
+            };
+            let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+            let output = match parser.parse_demo(&self.mmap) {
+                Ok(output) => output,
+                Err(e) => return Err(Exception::new_err(format!("{e}"))),
+            };
+            if let Some(positions) = df_positions(&output.df) {
+                learn_df_places(
+                    &mut nav,
+                    &output.df,
+                    &output.prop_controller.prop_infos,
+                    &positions,
+                );
+            }
+            let callouts = PyDict::new_bound(py);
+            for callout in nav.callouts() {
+                callouts.set_item(callout.name, callout.polygons)?;
+            }
+            dict.set_item("callouts", callouts)?;
+        }
+        Ok(dict.to_object(py))
+    }
+    /// Returns the names of game events present in the demo
+    pub fn list_game_events(&self, _py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec!["all".to_string()],
+            parse_ents: false,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let as_vec = output.game_events_counter.iter().collect_vec();
+        let ge = pyo3::Python::with_gil(|py| as_vec.to_object(py));
+        Ok(ge)
+    }
+
+    /// Returns all coordinates of all grenades along with info about thrower.
+    ///
+    /// Example:
+    ///          X           Y       Z  tick  thrower_steamid grenade_type
+    /// 0 -388.875  1295.46875 -5120.0   982              NaN    HeGrenade
+    /// 1 -388.875  1295.46875 -5120.0   983              NaN    HeGrenade
+    /// 2 -388.875  1295.46875 -5120.0   983              NaN    HeGrenade
+
+    pub fn parse_grenades(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: true,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+
+        let entity_id: Vec<Option<i32>> = output.projectiles.iter().map(|s| s.entity_id).collect();
+        let xs: Vec<Option<f32>> = output.projectiles.iter().map(|s| s.x).collect();
+        let ys: Vec<Option<f32>> = output.projectiles.iter().map(|s| s.y).collect();
+        let zs: Vec<Option<f32>> = output.projectiles.iter().map(|s| s.z).collect();
+
+        let ticks: Vec<Option<i32>> = output.projectiles.iter().map(|s| s.tick).collect();
+        let steamid: Vec<Option<u64>> = output.projectiles.iter().map(|s| s.steamid).collect();
+        let name: Vec<Option<String>> = output.projectiles.iter().map(|s| s.name.clone()).collect();
+        let grenade_type: Vec<Option<String>> = output
+            .projectiles
+            .iter()
+            .map(|s| s.grenade_type.clone())
+            .collect();
+
+        // SoA form
+        let xs = arr_to_py(Box::new(Float32Array::from(xs))).unwrap();
+        let ys = arr_to_py(Box::new(Float32Array::from(ys))).unwrap();
+        let zs = arr_to_py(Box::new(Float32Array::from(zs))).unwrap();
+        // Actually not sure about Z coordinate. Leave out for now.
+        let ticks = arr_to_py(Box::new(Int32Array::from(ticks))).unwrap();
+        let grenade_type = arr_to_py(Box::new(Utf8Array::<i32>::from(grenade_type))).unwrap();
+        let name = arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap();
+        let steamids = arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap();
+        let entity_ids = arr_to_py(Box::new(Int32Array::from(entity_id))).unwrap();
+
+        let polars = py.import_bound("polars")?;
+        let all_series_py =
+            [xs, ys, zs, ticks, steamids, name, grenade_type, entity_ids].to_object(py);
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            // Set column names
+            let column_names = [
+                "X",
+                "Y",
+                "Z",
+                "tick",
+                "thrower_steamid",
+                "name",
+                "grenade_type",
+                "entity_id",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            // Call to_pandas with use_pyarrow_extension_array = true
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns every burning fire cell of every molotov/incendiary (inferno) for each tick.
+    ///
+    /// Example:
+    ///    entity_id  tick  thrower_steamid  name  fire_idx        X        Y       Z
+    /// 0        153  5321  76561198...      ...          0  -1052.3  1433.02  -47.97
+    /// 1        153  5321  76561198...      ...          1  -1020.1  1450.88  -47.97
+    pub fn parse_infernos(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: true,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+
+        let entity_id: Vec<Option<i32>> = output.infernos.iter().map(|s| s.entity_id).collect();
+        let ticks: Vec<Option<i32>> = output.infernos.iter().map(|s| s.tick).collect();
+        let steamid: Vec<Option<u64>> = output.infernos.iter().map(|s| s.steamid).collect();
+        let name: Vec<Option<String>> = output.infernos.iter().map(|s| s.name.clone()).collect();
+        let fire_idx: Vec<Option<u32>> = output.infernos.iter().map(|s| s.fire_idx).collect();
+        let xs: Vec<Option<f32>> = output.infernos.iter().map(|s| s.x).collect();
+        let ys: Vec<Option<f32>> = output.infernos.iter().map(|s| s.y).collect();
+        let zs: Vec<Option<f32>> = output.infernos.iter().map(|s| s.z).collect();
+
+        let entity_ids = arr_to_py(Box::new(Int32Array::from(entity_id))).unwrap();
+        let ticks = arr_to_py(Box::new(Int32Array::from(ticks))).unwrap();
+        let steamids = arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap();
+        let name = arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap();
+        let fire_idx = arr_to_py(Box::new(UInt32Array::from(fire_idx))).unwrap();
+        let xs = arr_to_py(Box::new(Float32Array::from(xs))).unwrap();
+        let ys = arr_to_py(Box::new(Float32Array::from(ys))).unwrap();
+        let zs = arr_to_py(Box::new(Float32Array::from(zs))).unwrap();
+
+        let polars = py.import_bound("polars")?;
+        let all_series_py = [entity_ids, ticks, steamids, name, fire_idx, xs, ys, zs].to_object(py);
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "entity_id",
+                "tick",
+                "thrower_steamid",
+                "name",
+                "fire_idx",
+                "X",
+                "Y",
+                "Z",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+
+    /// Returns the center of every smoke that has popped for each tick it is active.
+    /// voxel_data is the raw smoke volume (m_VoxelFrameData) and is only set on the ticks
+    /// where voxel_update changes.
+    ///
+    /// Example:
+    ///    entity_id  tick  thrower_steamid  name  start_tick        X        Y       Z  voxel_update  voxel_data
+    /// 0        201  6012  76561198...      ...        5990   -312.5   1780.2  -120.1             1  b'\x01...'
+    pub fn parse_smokes(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: true,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+
+        let entity_id: Vec<Option<i32>> = output.smokes.iter().map(|s| s.entity_id).collect();
+        let ticks: Vec<Option<i32>> = output.smokes.iter().map(|s| s.tick).collect();
+        let steamid: Vec<Option<u64>> = output.smokes.iter().map(|s| s.steamid).collect();
+        let name: Vec<Option<String>> = output.smokes.iter().map(|s| s.name.clone()).collect();
+        let start_tick: Vec<Option<i32>> = output.smokes.iter().map(|s| s.start_tick).collect();
+        let xs: Vec<Option<f32>> = output.smokes.iter().map(|s| s.x).collect();
+        let ys: Vec<Option<f32>> = output.smokes.iter().map(|s| s.y).collect();
+        let zs: Vec<Option<f32>> = output.smokes.iter().map(|s| s.z).collect();
+        let voxel_update: Vec<Option<i32>> = output.smokes.iter().map(|s| s.voxel_update).collect();
+
+        let entity_ids = arr_to_py(Box::new(Int32Array::from(entity_id))).unwrap();
+        let ticks = arr_to_py(Box::new(Int32Array::from(ticks))).unwrap();
+        let steamids = arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap();
+        let name = arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap();
+        let start_tick = arr_to_py(Box::new(Int32Array::from(start_tick))).unwrap();
+        let xs = arr_to_py(Box::new(Float32Array::from(xs))).unwrap();
+        let ys = arr_to_py(Box::new(Float32Array::from(ys))).unwrap();
+        let zs = arr_to_py(Box::new(Float32Array::from(zs))).unwrap();
+        let voxel_update = arr_to_py(Box::new(Int32Array::from(voxel_update))).unwrap();
+        let voxel_data = arr_to_py(Box::new(BinaryArray::<i32>::from_iter(
+            output.smokes.iter().map(|s| s.voxel_data.as_ref()),
+        )))
+        .unwrap();
+
+        let polars = py.import_bound("polars")?;
+        let all_series_py = [
+            entity_ids,
+            ticks,
+            steamids,
+            name,
+            start_tick,
+            xs,
+            ys,
+            zs,
+            voxel_update,
+            voxel_data,
+        ]
+        .to_object(py);
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "entity_id",
+                "tick",
+                "thrower_steamid",
+                "name",
+                "start_tick",
+                "X",
+                "Y",
+                "Z",
+                "voxel_update",
+                "voxel_data",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    pub fn parse_player_info(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: false,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let steamids: Vec<Option<u64>> = output.player_md.iter().map(|p| p.steamid).collect();
+        let team_numbers: Vec<Option<i32>> =
+            output.player_md.iter().map(|p| p.team_number).collect();
+        let names: Vec<Option<String>> = output.player_md.iter().map(|p| p.name.clone()).collect();
+
+        // SoA form
+        let steamid = rust_series_to_py_series(&Series::new("Steamid", steamids))?;
+        let team_number = arr_to_py(Box::new(Int32Array::from(team_numbers)))?;
+        let name = rust_series_to_py_series(&Series::new("param2", names))?;
+
+        let polars = py.import_bound("polars")?;
+        let all_series_py = [steamid, name, team_number].to_object(py);
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            // Set column names
+            let column_names = ["steamid", "name", "team_number"];
+            df.setattr("columns", column_names.to_object(py))?;
+            // Call to_pandas with use_pyarrow_extension_array = true
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs))?;
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns one row per round with the ticks where the round started, freezetime ended,
+    /// the round ended and the round officially ended, along with the winner, the reason,
+    /// the score after the round and the team names per side.
+    pub fn parse_rounds(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let wanted_other_props: Vec<String> =
+            ROUND_OTHER_PROPS.iter().map(|s| s.to_string()).collect();
+        let real_other_props = match rm_user_friendly_names(&wanted_other_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        let settings = ParserInputs {
+            real_name_to_og_name,
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: real_other_props,
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: ROUND_EVENTS.iter().map(|s| s.to_string()).collect(),
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let rounds = rounds_from_events(&output.game_events);
+
+        let round: Vec<Option<i32>> = rounds.iter().map(|r| Some(r.round)).collect();
+        let start_tick: Vec<Option<i32>> = rounds.iter().map(|r| r.start_tick).collect();
+        let freeze_end_tick: Vec<Option<i32>> = rounds.iter().map(|r| r.freeze_end_tick).collect();
+        let end_tick: Vec<Option<i32>> = rounds.iter().map(|r| r.end_tick).collect();
+        let officially_ended_tick: Vec<Option<i32>> =
+            rounds.iter().map(|r| r.officially_ended_tick).collect();
+        let winner: Vec<Option<String>> = rounds.iter().map(|r| r.winner.clone()).collect();
+        let reason: Vec<Option<String>> = rounds.iter().map(|r| r.reason.clone()).collect();
+        let t_score: Vec<Option<i32>> = rounds.iter().map(|r| r.t_score).collect();
+        let ct_score: Vec<Option<i32>> = rounds.iter().map(|r| r.ct_score).collect();
+        let t_name: Vec<Option<String>> = rounds.iter().map(|r| r.t_name.clone()).collect();
+        let ct_name: Vec<Option<String>> = rounds.iter().map(|r| r.ct_name.clone()).collect();
+        let is_overtime: Vec<Option<bool>> = rounds.iter().map(|r| Some(r.is_overtime)).collect();
+
+        let round = arr_to_py(Box::new(Int32Array::from(round))).unwrap();
+        let start_tick = arr_to_py(Box::new(Int32Array::from(start_tick))).unwrap();
+        let freeze_end_tick = arr_to_py(Box::new(Int32Array::from(freeze_end_tick))).unwrap();
+        let end_tick = arr_to_py(Box::new(Int32Array::from(end_tick))).unwrap();
+        let officially_ended_tick =
+            arr_to_py(Box::new(Int32Array::from(officially_ended_tick))).unwrap();
+        let winner = arr_to_py(Box::new(Utf8Array::<i32>::from(winner))).unwrap();
+        let reason = arr_to_py(Box::new(Utf8Array::<i32>::from(reason))).unwrap();
+        let t_score = arr_to_py(Box::new(Int32Array::from(t_score))).unwrap();
+        let ct_score = arr_to_py(Box::new(Int32Array::from(ct_score))).unwrap();
+        let t_name = arr_to_py(Box::new(Utf8Array::<i32>::from(t_name))).unwrap();
+        let ct_name = arr_to_py(Box::new(Utf8Array::<i32>::from(ct_name))).unwrap();
+        let is_overtime = arr_to_py(Box::new(BooleanArray::from(is_overtime))).unwrap();
+
+        let polars = py.import_bound("polars")?;
+        let all_series_py = [
+            round,
+            start_tick,
+            freeze_end_tick,
+            end_tick,
+            officially_ended_tick,
+            winner,
+            reason,
+            t_score,
+            ct_score,
+            t_name,
+            ct_name,
+            is_overtime,
+        ]
+        .to_object(py);
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "round",
+                "start_tick",
+                "freeze_end_tick",
+                "end_tick",
+                "officially_ended_tick",
+                "winner",
+                "reason",
+                "t_score",
+                "ct_score",
+                "t_name",
+                "ct_name",
+                "is_overtime",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns the economy of each team per round: starting money, money spent,
+    /// equipment value at the end of freezetime, loss bonus and a buy classification
+    /// (pistol, eco, force, half or full).
+    pub fn parse_economy(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let wanted_other_props: Vec<String> =
+            ROUND_OTHER_PROPS.iter().map(|s| s.to_string()).collect();
+        let real_other_props = match rm_user_friendly_names(&wanted_other_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        let settings = ParserInputs {
+            real_name_to_og_name,
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: real_other_props,
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: ECONOMY_EVENTS.iter().map(|s| s.to_string()).collect(),
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: true,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let rounds = rounds_from_events(&output.game_events);
+        let economy =
+            economy_from_snapshots(&rounds, &output.economy_snapshots, &output.game_events);
+
+        let round: Vec<Option<i32>> = economy.iter().map(|e| Some(e.round)).collect();
+        let side: Vec<Option<String>> = economy.iter().map(|e| Some(e.side.clone())).collect();
+        let tick: Vec<Option<i32>> = economy.iter().map(|e| Some(e.tick)).collect();
+        let start_money: Vec<Option<i32>> = economy.iter().map(|e| Some(e.start_money)).collect();
+        let spend: Vec<Option<i32>> = economy.iter().map(|e| Some(e.spend)).collect();
+        let equipment_value: Vec<Option<i32>> =
+            economy.iter().map(|e| Some(e.equipment_value)).collect();
+        let loss_bonus_level: Vec<Option<i32>> =
+            economy.iter().map(|e| e.loss_bonus_level).collect();
+        let loss_bonus: Vec<Option<i32>> = economy.iter().map(|e| e.loss_bonus).collect();
+        let buy_type: Vec<Option<String>> =
+            economy.iter().map(|e| Some(e.buy_type.clone())).collect();
+
+        let round = arr_to_py(Box::new(Int32Array::from(round))).unwrap();
+        let side = arr_to_py(Box::new(Utf8Array::<i32>::from(side))).unwrap();
+        let tick = arr_to_py(Box::new(Int32Array::from(tick))).unwrap();
+        let start_money = arr_to_py(Box::new(Int32Array::from(start_money))).unwrap();
+        let spend = arr_to_py(Box::new(Int32Array::from(spend))).unwrap();
+        let equipment_value = arr_to_py(Box::new(Int32Array::from(equipment_value))).unwrap();
+        let loss_bonus_level = arr_to_py(Box::new(Int32Array::from(loss_bonus_level))).unwrap();
+        let loss_bonus = arr_to_py(Box::new(Int32Array::from(loss_bonus))).unwrap();
+        let buy_type = arr_to_py(Box::new(Utf8Array::<i32>::from(buy_type))).unwrap();
+
+        let polars = py.import_bound("polars")?;
+        let all_series_py = [
+            round,
+            side,
+            tick,
+            start_money,
+            spend,
+            equipment_value,
+            loss_bonus_level,
+            loss_bonus,
+            buy_type,
+        ]
+        .to_object(py);
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "round",
+                "side",
+                "freeze_end_tick",
+                "start_money",
+                "spend",
+                "equipment_value",
+                "loss_bonus_level",
+                "loss_bonus",
+                "buy_type",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns kills, deaths, assists, ADR, KAST, opening duels, multi kills, utility damage
+    /// and an HLTV 1.0 rating per player for the whole match. With per_round=True one row is
+    /// returned per player per round instead.
+    #[pyo3(signature = (*, per_round=false))]
+    pub fn parse_player_stats(&self, py: Python<'_>, per_round: bool) -> PyResult<Py<PyAny>> {
+        let wanted_player_props: Vec<String> = PLAYER_STATS_PLAYER_PROPS
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let wanted_other_props: Vec<String> = PLAYER_STATS_OTHER_PROPS
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let real_player_props = match rm_user_friendly_names(&wanted_player_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let real_other_props = match rm_user_friendly_names(&wanted_other_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_player_props.iter().zip(&wanted_player_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        let settings = ParserInputs {
+            real_name_to_og_name,
+            wanted_players: vec![],
+            wanted_player_props: real_player_props,
+            wanted_other_props: real_other_props,
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: PLAYER_STATS_EVENTS.iter().map(|s| s.to_string()).collect(),
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let round_stats = player_round_stats(&output.game_events, output.tick_interval);
+        if per_round {
+            return player_round_stats_to_df(py, &round_stats);
+        }
+        let stats = player_stats(&round_stats);
+
+        let steamid: Vec<Option<u64>> = stats.iter().map(|s| Some(s.steamid)).collect();
+        let name: Vec<Option<String>> = stats.iter().map(|s| s.name.clone()).collect();
+        let rounds: Vec<Option<i32>> = stats.iter().map(|s| Some(s.rounds)).collect();
+        let kills: Vec<Option<i32>> = stats.iter().map(|s| Some(s.kills)).collect();
+        let deaths: Vec<Option<i32>> = stats.iter().map(|s| Some(s.deaths)).collect();
+        let assists: Vec<Option<i32>> = stats.iter().map(|s| Some(s.assists)).collect();
+        let flash_assists: Vec<Option<i32>> = stats.iter().map(|s| Some(s.flash_assists)).collect();
+        let headshot_kills: Vec<Option<i32>> =
+            stats.iter().map(|s| Some(s.headshot_kills)).collect();
+        let damage: Vec<Option<i32>> = stats.iter().map(|s| Some(s.damage)).collect();
+        let adr: Vec<Option<f32>> = stats.iter().map(|s| Some(s.adr)).collect();
+        let kast: Vec<Option<f32>> = stats.iter().map(|s| Some(s.kast)).collect();
+        let opening_kills: Vec<Option<i32>> = stats.iter().map(|s| Some(s.opening_kills)).collect();
+        let opening_deaths: Vec<Option<i32>> =
+            stats.iter().map(|s| Some(s.opening_deaths)).collect();
+        let traded_deaths: Vec<Option<i32>> = stats.iter().map(|s| Some(s.traded_deaths)).collect();
+        let utility_damage: Vec<Option<i32>> =
+            stats.iter().map(|s| Some(s.utility_damage)).collect();
+        let multi_kill = |n: usize| -> Vec<Option<i32>> {
+            stats.iter().map(|s| Some(s.multi_kills[n])).collect()
+        };
+        let rating: Vec<Option<f32>> = stats.iter().map(|s| Some(s.rating)).collect();
+
+        let all_series_py = [
+            arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(rounds))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(kills))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(deaths))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(assists))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(flash_assists))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(headshot_kills))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(damage))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(adr))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(kast))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(opening_kills))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(opening_deaths))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(traded_deaths))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(utility_damage))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(multi_kill(0)))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(multi_kill(1)))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(multi_kill(2)))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(multi_kill(3)))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(multi_kill(4)))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(rating))).unwrap(),
+        ]
+        .to_object(py);
+        let polars = py.import_bound("polars")?;
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "steamid",
+                "name",
+                "rounds",
+                "kills",
+                "deaths",
+                "assists",
+                "flash_assists",
+                "headshot_kills",
+                "damage",
+                "adr",
+                "kast",
+                "opening_kills",
+                "opening_deaths",
+                "traded_deaths",
+                "utility_damage",
+                "1k",
+                "2k",
+                "3k",
+                "4k",
+                "5k",
+                "rating",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns one row per trade kill: a kill on a player who had killed a teammate of the
+    /// trader within window_ticks ticks (default 5 seconds at the tickrate of the demo).
+    #[pyo3(signature = (*, window_ticks=None))]
+    pub fn parse_trade_kills(
+        &self,
+        py: Python<'_>,
+        window_ticks: Option<i32>,
+    ) -> PyResult<Py<PyAny>> {
+        let wanted_player_props: Vec<String> =
+            TRADES_PLAYER_PROPS.iter().map(|s| s.to_string()).collect();
+        let wanted_other_props: Vec<String> =
+            TRADES_OTHER_PROPS.iter().map(|s| s.to_string()).collect();
+        let real_player_props = match rm_user_friendly_names(&wanted_player_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let real_other_props = match rm_user_friendly_names(&wanted_other_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_player_props.iter().zip(&wanted_player_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        let settings = ParserInputs {
+            real_name_to_og_name,
+            wanted_players: vec![],
+            wanted_player_props: real_player_props,
+            wanted_other_props: real_other_props,
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: TRADES_EVENTS.iter().map(|s| s.to_string()).collect(),
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let rounds = rounds_from_events(&output.game_events);
+        let kills = kills_from_events(&output.game_events, &rounds);
+        let window_ticks = window_ticks
+            .unwrap_or_else(|| seconds_to_ticks(TRADE_WINDOW_SECONDS, output.tick_interval));
+        let trades = trade_kills(&kills, window_ticks);
+
+        let round: Vec<Option<i32>> = trades.iter().map(|t| Some(t.round)).collect();
+        let tick: Vec<Option<i32>> = trades.iter().map(|t| Some(t.tick)).collect();
+        let steamid: Vec<Option<u64>> = trades.iter().map(|t| t.steamid).collect();
+        let name: Vec<Option<String>> = trades.iter().map(|t| t.name.clone()).collect();
+        let traded_steamid: Vec<Option<u64>> = trades.iter().map(|t| t.traded_steamid).collect();
+        let traded_name: Vec<Option<String>> =
+            trades.iter().map(|t| t.traded_name.clone()).collect();
+        let victim_steamid: Vec<Option<u64>> =
+            trades.iter().map(|t| Some(t.victim_steamid)).collect();
+        let victim_name: Vec<Option<String>> =
+            trades.iter().map(|t| t.victim_name.clone()).collect();
+        let victim_death_tick: Vec<Option<i32>> =
+            trades.iter().map(|t| Some(t.victim_death_tick)).collect();
+        let ticks_after_death: Vec<Option<i32>> =
+            trades.iter().map(|t| Some(t.ticks_after_death)).collect();
+
+        let all_series_py = [
+            arr_to_py(Box::new(Int32Array::from(round))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(tick))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(traded_steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(traded_name))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(victim_steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(victim_name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(victim_death_tick))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(ticks_after_death))).unwrap(),
+        ]
+        .to_object(py);
+        let polars = py.import_bound("polars")?;
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "round",
+                "tick",
+                "steamid",
+                "name",
+                "traded_steamid",
+                "traded_name",
+                "victim_steamid",
+                "victim_name",
+                "victim_death_tick",
+                "ticks_after_death",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns one row per player and weapon with shots fired, shots that hit, headshot hits
+    /// and damage. Each weapon_fire is paired with the player_hurt events of the same attacker
+    /// and weapon within a few ticks. first_bullet_* only counts the first shot of a spray and
+    /// spray_transfer_kills counts kills on a second enemy within the same spray.
+    pub fn parse_weapon_accuracy(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let wanted_player_props: Vec<String> = ACCURACY_PLAYER_PROPS
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let wanted_other_props: Vec<String> =
+            ACCURACY_OTHER_PROPS.iter().map(|s| s.to_string()).collect();
+        let real_player_props = match rm_user_friendly_names(&wanted_player_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let real_other_props = match rm_user_friendly_names(&wanted_other_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_player_props.iter().zip(&wanted_player_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        let settings = ParserInputs {
+            real_name_to_og_name,
+            wanted_players: vec![],
+            wanted_player_props: real_player_props,
+            wanted_other_props: real_other_props,
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: ACCURACY_EVENTS.iter().map(|s| s.to_string()).collect(),
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let shots = shots_from_events(&output.game_events, output.tick_interval);
+        let stats = weapon_accuracy(&shots);
+
+        let steamid: Vec<Option<u64>> = stats.iter().map(|s| Some(s.steamid)).collect();
+        let name: Vec<Option<String>> = stats.iter().map(|s| s.name.clone()).collect();
+        let weapon: Vec<Option<String>> = stats.iter().map(|s| Some(s.weapon.clone())).collect();
+        let shots: Vec<Option<i32>> = stats.iter().map(|s| Some(s.shots)).collect();
+        let hits: Vec<Option<i32>> = stats.iter().map(|s| Some(s.hits)).collect();
+        let headshot_hits: Vec<Option<i32>> = stats.iter().map(|s| Some(s.headshot_hits)).collect();
+        let damage: Vec<Option<i32>> = stats.iter().map(|s| Some(s.damage)).collect();
+        let first_bullet_shots: Vec<Option<i32>> =
+            stats.iter().map(|s| Some(s.first_bullet_shots)).collect();
+        let first_bullet_hits: Vec<Option<i32>> =
+            stats.iter().map(|s| Some(s.first_bullet_hits)).collect();
+        let spray_transfer_kills: Vec<Option<i32>> =
+            stats.iter().map(|s| Some(s.spray_transfer_kills)).collect();
+
+        let all_series_py = [
+            arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(weapon))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(shots))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(hits))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(headshot_hits))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(damage))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(first_bullet_shots))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(first_bullet_hits))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(spray_transfer_kills))).unwrap(),
+        ]
+        .to_object(py);
+        let polars = py.import_bound("polars")?;
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "steamid",
+                "name",
+                "weapon",
+                "shots",
+                "hits",
+                "headshot_hits",
+                "damage",
+                "first_bullet_shots",
+                "first_bullet_hits",
+                "spray_transfer_kills",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns one row per player_blind, linked to the flashbang_detonate of the same grenade
+    /// entity and the tick the flashbang was thrown. enemy marks enemy flashes and
+    /// killed_while_blind whether the throwers team killed the victim before the blind wore
+    /// off. With per_thrower=True one row of aggregated flash stats per thrower is returned.
+    #[pyo3(signature = (*, per_thrower=false))]
+    pub fn parse_flashes(&self, py: Python<'_>, per_thrower: bool) -> PyResult<Py<PyAny>> {
+        let wanted_player_props: Vec<String> =
+            FLASHES_PLAYER_PROPS.iter().map(|s| s.to_string()).collect();
+        let wanted_other_props: Vec<String> =
+            FLASHES_OTHER_PROPS.iter().map(|s| s.to_string()).collect();
+        let real_player_props = match rm_user_friendly_names(&wanted_player_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let real_other_props = match rm_user_friendly_names(&wanted_other_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_player_props.iter().zip(&wanted_player_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        let settings = ParserInputs {
+            real_name_to_og_name,
+            wanted_players: vec![],
+            wanted_player_props: real_player_props,
+            wanted_other_props: real_other_props,
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: FLASHES_EVENTS.iter().map(|s| s.to_string()).collect(),
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let detonations = flash_detonations(&output.game_events, output.tick_interval);
+        let blinds = flash_blinds(&output.game_events, &detonations, output.tick_interval);
+        if per_thrower {
+            let stats = flash_stats(&output.game_events, &detonations, &blinds);
+            return flash_stats_to_df(py, &stats);
+        }
+
+        let tick: Vec<Option<i32>> = blinds.iter().map(|b| Some(b.tick)).collect();
+        let entity_id: Vec<Option<i32>> = blinds.iter().map(|b| b.entity_id).collect();
+        let detonate_tick: Vec<Option<i32>> = blinds.iter().map(|b| b.detonate_tick).collect();
+        let throw_tick: Vec<Option<i32>> = blinds.iter().map(|b| b.throw_tick).collect();
+        let thrower_steamid: Vec<Option<u64>> = blinds.iter().map(|b| b.thrower_steamid).collect();
+        let thrower_name: Vec<Option<String>> =
+            blinds.iter().map(|b| b.thrower_name.clone()).collect();
+        let thrower_team: Vec<Option<i32>> = blinds.iter().map(|b| b.thrower_team).collect();
+        let victim_steamid: Vec<Option<u64>> =
+            blinds.iter().map(|b| Some(b.victim_steamid)).collect();
+        let victim_name: Vec<Option<String>> =
+            blinds.iter().map(|b| b.victim_name.clone()).collect();
+        let victim_team: Vec<Option<i32>> = blinds.iter().map(|b| b.victim_team).collect();
+        let blind_duration: Vec<Option<f32>> =
+            blinds.iter().map(|b| Some(b.blind_duration)).collect();
+        let enemy: Vec<Option<bool>> = blinds.iter().map(|b| Some(b.enemy)).collect();
+        let self_flash: Vec<Option<bool>> = blinds.iter().map(|b| Some(b.self_flash)).collect();
+        let killed_while_blind: Vec<Option<bool>> =
+            blinds.iter().map(|b| Some(b.killed_while_blind)).collect();
+
+        let all_series_py = [
+            arr_to_py(Box::new(Int32Array::from(tick))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(entity_id))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(detonate_tick))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(throw_tick))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(thrower_steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(thrower_name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(thrower_team))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(victim_steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(victim_name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(victim_team))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(blind_duration))).unwrap(),
+            arr_to_py(Box::new(BooleanArray::from(enemy))).unwrap(),
+            arr_to_py(Box::new(BooleanArray::from(self_flash))).unwrap(),
+            arr_to_py(Box::new(BooleanArray::from(killed_while_blind))).unwrap(),
+        ]
+        .to_object(py);
+        let polars = py.import_bound("polars")?;
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "tick",
+                "entity_id",
+                "detonate_tick",
+                "throw_tick",
+                "thrower_steamid",
+                "thrower_name",
+                "thrower_team_num",
+                "victim_steamid",
+                "victim_name",
+                "victim_team_num",
+                "blind_duration",
+                "enemy",
+                "self_flash",
+                "killed_while_blind",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns one row per HE grenade and molotov/incendiary with the damage it did. HE damage
+    /// is linked to the detonation of the same thrower and fire damage to the inferno entity
+    /// that was burning, so damage, players hit and kills are counted per throw.
+    pub fn parse_grenade_damage(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let wanted_player_props: Vec<String> = UTILITY_DAMAGE_PLAYER_PROPS
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let wanted_other_props: Vec<String> = UTILITY_DAMAGE_OTHER_PROPS
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let real_player_props = match rm_user_friendly_names(&wanted_player_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let real_other_props = match rm_user_friendly_names(&wanted_other_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_player_props.iter().zip(&wanted_player_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        let settings = ParserInputs {
+            real_name_to_og_name,
+            wanted_players: vec![],
+            wanted_player_props: real_player_props,
+            wanted_other_props: real_other_props,
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: UTILITY_DAMAGE_EVENTS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: true,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let throws = grenade_throws(&output.projectiles, output.tick_interval);
+        let grenades = utility_damage(&output.game_events, &throws, &output.infernos);
+
+        let grenade_type: Vec<Option<String>> = grenades
+            .iter()
+            .map(|g| Some(g.grenade_type.clone()))
+            .collect();
+        let entity_id: Vec<Option<i32>> = grenades.iter().map(|g| g.entity_id).collect();
+        let thrower_steamid: Vec<Option<u64>> =
+            grenades.iter().map(|g| g.thrower_steamid).collect();
+        let thrower_name: Vec<Option<String>> =
+            grenades.iter().map(|g| g.thrower_name.clone()).collect();
+        let thrower_team: Vec<Option<i32>> = grenades.iter().map(|g| g.thrower_team).collect();
+        let throw_tick: Vec<Option<i32>> = grenades.iter().map(|g| g.throw_tick).collect();
+        let detonate_tick: Vec<Option<i32>> =
+            grenades.iter().map(|g| Some(g.detonate_tick)).collect();
+        let end_tick: Vec<Option<i32>> = grenades.iter().map(|g| g.end_tick).collect();
+        let x: Vec<Option<f32>> = grenades.iter().map(|g| g.x).collect();
+        let y: Vec<Option<f32>> = grenades.iter().map(|g| g.y).collect();
+        let z: Vec<Option<f32>> = grenades.iter().map(|g| g.z).collect();
+        let damage: Vec<Option<i32>> = grenades.iter().map(|g| Some(g.damage)).collect();
+        let team_damage: Vec<Option<i32>> = grenades.iter().map(|g| Some(g.team_damage)).collect();
+        let enemies_hit: Vec<Option<i32>> = grenades.iter().map(|g| Some(g.enemies_hit)).collect();
+        let teammates_hit: Vec<Option<i32>> =
+            grenades.iter().map(|g| Some(g.teammates_hit)).collect();
+        let kills: Vec<Option<i32>> = grenades.iter().map(|g| Some(g.kills)).collect();
+
+        let all_series_py = [
+            arr_to_py(Box::new(Utf8Array::<i32>::from(grenade_type))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(entity_id))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(thrower_steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(thrower_name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(thrower_team))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(throw_tick))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(detonate_tick))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(end_tick))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(x))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(y))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(z))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(damage))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(team_damage))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(enemies_hit))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(teammates_hit))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(kills))).unwrap(),
+        ]
+        .to_object(py);
+        let polars = py.import_bound("polars")?;
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "grenade_type",
+                "entity_id",
+                "thrower_steamid",
+                "thrower_name",
+                "thrower_team_num",
+                "throw_tick",
+                "detonate_tick",
+                "end_tick",
+                "X",
+                "Y",
+                "Z",
+                "damage",
+                "team_damage",
+                "enemies_hit",
+                "teammates_hit",
+                "kills",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns one row per round that ended in a clutch (1vX): the last player alive on their
+    /// team, the number of opponents alive at that moment, kills made during the clutch and
+    /// whether the round was won.
+    pub fn parse_clutches(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let wanted_player_props: Vec<String> = CLUTCHES_PLAYER_PROPS
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let wanted_other_props: Vec<String> =
+            CLUTCHES_OTHER_PROPS.iter().map(|s| s.to_string()).collect();
+        let real_player_props = match rm_user_friendly_names(&wanted_player_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let real_other_props = match rm_user_friendly_names(&wanted_other_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_player_props.iter().zip(&wanted_player_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        let settings = ParserInputs {
+            real_name_to_og_name,
+            wanted_players: vec![],
+            wanted_player_props: real_player_props,
+            wanted_other_props: real_other_props,
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: CLUTCHES_EVENTS.iter().map(|s| s.to_string()).collect(),
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let rounds = rounds_from_events(&output.game_events);
+        let kills = kills_from_events(&output.game_events, &rounds);
+
+        // Second pass over the death ticks to see who is still alive
+        let wanted_tick_props: Vec<String> =
+            CLUTCHES_TICK_PROPS.iter().map(|s| s.to_string()).collect();
+        let real_tick_props = match rm_user_friendly_names(&wanted_tick_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_tick_props.iter().zip(&wanted_tick_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        let mut death_ticks: Vec<i32> = kills.iter().map(|k| k.tick).collect();
+        death_ticks.dedup();
+        let states = if death_ticks.is_empty() {
+            vec![]
+        } else {
+            let settings = ParserInputs {
+                real_name_to_og_name,
+                wanted_players: vec![],
+                wanted_player_props: real_tick_props,
+                wanted_other_props: vec![],
+                wanted_prop_states: AHashMap::default(),
+                wanted_events: vec![],
+                parse_ents: true,
+                wanted_ticks: death_ticks,
+                parse_projectiles: false,
+                only_header: true,
+                count_props: false,
+                only_convars: false,
+                huffman_lookup_table: &self.huf,
+                order_by_steamid: false,
+                parse_economy: false,
+                parse_visibility: false,
+                parse_ticks_with_events: false,
+                parse_shot_details: false,
+                wanted_net_messages: vec![],
+                parse_string_tables: false,
+            };
+            let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+            let tick_output = match parser.parse_demo(&self.mmap) {
+                Ok(output) => output,
+                Err(e) => return Err(Exception::new_err(format!("{e}"))),
+            };
+            alive_states(&tick_output.df, &tick_output.prop_controller.prop_infos)
+        };
+        let clutches = clutches(&rounds, &states, &kills);
+
+        let round: Vec<Option<i32>> = clutches.iter().map(|c| Some(c.round)).collect();
+        let start_tick: Vec<Option<i32>> = clutches.iter().map(|c| Some(c.start_tick)).collect();
+        let steamid: Vec<Option<u64>> = clutches.iter().map(|c| Some(c.steamid)).collect();
+        let name: Vec<Option<String>> = clutches.iter().map(|c| c.name.clone()).collect();
+        let side: Vec<Option<String>> = clutches.iter().map(|c| Some(c.side.clone())).collect();
+        let opponents: Vec<Option<i32>> = clutches.iter().map(|c| Some(c.opponents)).collect();
+        let kills: Vec<Option<i32>> = clutches.iter().map(|c| Some(c.kills)).collect();
+        let won: Vec<Option<bool>> = clutches.iter().map(|c| c.won).collect();
+
+        let all_series_py = [
+            arr_to_py(Box::new(Int32Array::from(round))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(start_tick))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(side))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(opponents))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(kills))).unwrap(),
+            arr_to_py(Box::new(BooleanArray::from(won))).unwrap(),
+        ]
+        .to_object(py);
+        let polars = py.import_bound("polars")?;
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "round",
+                "start_tick",
+                "steamid",
+                "name",
+                "side",
+                "opponents",
+                "kills",
+                "won",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns one row per interval in which a player (observer) had an enemy (target)
+    /// spotted, built from m_bSpottedByMask. end_tick is the first tick the target was
+    /// no longer spotted.
+    pub fn parse_visibility(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: true,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let visibility = output.visibility;
+
+        let observer_steamid: Vec<Option<u64>> = visibility
+            .iter()
+            .map(|v| Some(v.observer_steamid))
+            .collect();
+        let observer_name: Vec<Option<String>> =
+            visibility.iter().map(|v| v.observer_name.clone()).collect();
+        let target_steamid: Vec<Option<u64>> =
+            visibility.iter().map(|v| Some(v.target_steamid)).collect();
+        let target_name: Vec<Option<String>> =
+            visibility.iter().map(|v| v.target_name.clone()).collect();
+        let start_tick: Vec<Option<i32>> = visibility.iter().map(|v| Some(v.start_tick)).collect();
+        let end_tick: Vec<Option<i32>> = visibility.iter().map(|v| Some(v.end_tick)).collect();
+
+        let all_series_py = [
+            arr_to_py(Box::new(UInt64Array::from(observer_steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(observer_name))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(target_steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(target_name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(start_tick))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(end_tick))).unwrap(),
+        ]
+        .to_object(py);
+        let polars = py.import_bound("polars")?;
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "observer_steamid",
+                "observer_name",
+                "target_steamid",
+                "target_name",
+                "start_tick",
+                "end_tick",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns one row per engagement: an interval in which a player (observer) had an enemy
+    /// (target) spotted. crosshair_distance is the angle in degrees between the observers view
+    /// and the targets head when spotted, reaction_time the seconds from spotting to the first
+    /// shot and flick_magnitude the largest flick (in degrees) made before that shot.
+    /// Parses every tick including usercmds, so this is slower than most other methods.
+    pub fn parse_aim(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let wanted_player_props: Vec<String> =
+            AIM_PLAYER_PROPS.iter().map(|s| s.to_string()).collect();
+        let wanted_other_props: Vec<String> =
+            AIM_OTHER_PROPS.iter().map(|s| s.to_string()).collect();
+        let real_player_props = match rm_user_friendly_names(&wanted_player_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let real_other_props = match rm_user_friendly_names(&wanted_other_props) {
+            Ok(real_props) => real_props,
+            Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+        };
+        let mut real_name_to_og_name = AHashMap::default();
+        for (real_name, user_friendly_name) in real_player_props.iter().zip(&wanted_player_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        for (real_name, user_friendly_name) in real_other_props.iter().zip(&wanted_other_props) {
+            real_name_to_og_name.insert(real_name.clone(), user_friendly_name.clone());
+        }
+        let settings = ParserInputs {
+            real_name_to_og_name,
+            wanted_players: vec![],
+            wanted_player_props: real_player_props,
+            wanted_other_props: real_other_props,
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: AIM_EVENTS.iter().map(|s| s.to_string()).collect(),
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: true,
+            parse_ticks_with_events: true,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let samples = aim_samples(&output.df, &output.prop_controller.prop_infos);
+        let engagements = engagements(
+            &samples,
+            &output.visibility,
+            &output.game_events,
+            output.tick_interval,
+        );
+
+        let observer_steamid: Vec<Option<u64>> = engagements
+            .iter()
+            .map(|e| Some(e.observer_steamid))
+            .collect();
+        let observer_name: Vec<Option<String>> = engagements
+            .iter()
+            .map(|e| e.observer_name.clone())
+            .collect();
+        let target_steamid: Vec<Option<u64>> =
+            engagements.iter().map(|e| Some(e.target_steamid)).collect();
+        let target_name: Vec<Option<String>> =
+            engagements.iter().map(|e| e.target_name.clone()).collect();
+        let spotted_tick: Vec<Option<i32>> =
+            engagements.iter().map(|e| Some(e.spotted_tick)).collect();
+        let lost_tick: Vec<Option<i32>> = engagements.iter().map(|e| Some(e.lost_tick)).collect();
+        let crosshair_distance: Vec<Option<f32>> =
+            engagements.iter().map(|e| e.crosshair_distance).collect();
+        let first_shot_tick: Vec<Option<i32>> =
+            engagements.iter().map(|e| e.first_shot_tick).collect();
+        let reaction_time: Vec<Option<f32>> = engagements.iter().map(|e| e.reaction_time).collect();
+        let flick_magnitude: Vec<Option<f32>> =
+            engagements.iter().map(|e| e.flick_magnitude).collect();
+
+        let all_series_py = [
+            arr_to_py(Box::new(UInt64Array::from(observer_steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(observer_name))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(target_steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(target_name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(spotted_tick))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(lost_tick))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(crosshair_distance))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(first_shot_tick))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(reaction_time))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(flick_magnitude))).unwrap(),
+        ]
+        .to_object(py);
+        let polars = py.import_bound("polars")?;
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "observer_steamid",
+                "observer_name",
+                "target_steamid",
+                "target_name",
+                "spotted_tick",
+                "lost_tick",
+                "crosshair_distance",
+                "first_shot_tick",
+                "reaction_time",
+                "flick_magnitude",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    pub fn parse_item_drops(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: false,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let def_index: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.def_index).collect();
+        let account_id: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.account_id).collect();
+        let dropreason: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.dropreason).collect();
+        let inventory: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.inventory).collect();
+        let item_id: Vec<Option<u64>> = output.item_drops.iter().map(|x| x.item_id).collect();
+        let paint_index: Vec<Option<u32>> =
+            output.item_drops.iter().map(|x| x.paint_index).collect();
+        let paint_seed: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.paint_seed).collect();
+        let paint_wear: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.paint_wear).collect();
+        let custom_name: Vec<Option<String>> = output
+            .item_drops
+            .iter()
+            .map(|x| x.custom_name.clone())
+            .collect();
+        // SoA form
+        let account_id = arr_to_py(Box::new(UInt32Array::from(account_id)))?;
+        let def_index = arr_to_py(Box::new(UInt32Array::from(def_index)))?;
+        let dropreason = arr_to_py(Box::new(UInt32Array::from(dropreason)))?;
+        let inventory = arr_to_py(Box::new(UInt32Array::from(inventory)))?;
+        let item_id = arr_to_py(Box::new(UInt64Array::from(item_id)))?;
+        let paint_index = arr_to_py(Box::new(UInt32Array::from(paint_index)))?;
+        let paint_seed = arr_to_py(Box::new(UInt32Array::from(paint_seed)))?;
+        let paint_wear = arr_to_py(Box::new(UInt32Array::from(paint_wear)))?;
+        let custom_name = rust_series_to_py_series(&Series::new("custom_name", custom_name))?;
+
+        let polars = py.import_bound("polars")?;
+        let all_series_py = [
+            account_id,
+            def_index,
+            dropreason,
+            inventory,
+            item_id,
+            paint_index,
+            paint_seed,
+            paint_wear,
+            custom_name,
+        ]
+        .to_object(py);
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            // Set column names
+            let column_names = [
+                "account_id",
+                "def_index",
+                "dropreason",
+                "inventory",
+                "item_id",
+                "paint_index",
+                "paint_seed",
+                "paint_wear",
+                "custom_name",
+            ];
+            df.setattr("columns", column_names.to_object(py))?;
+            // Call to_pandas with use_pyarrow_extension_array = true
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs))?;
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns the server's own per-player stat deltas from CS_UM_PlayerStatsUpdate messages:
+    /// one row per stat change with tick, steamid, name, stat_id, stat_name and delta.
+    pub fn parse_player_stats_updates(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: true,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
         };
         let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
         let output = match parser.parse_demo(&self.mmap) {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
-        Ok(output
-            .header
-            .unwrap_or_else(AHashMap::default)
-            .to_object(py))
+        let updates = &output.player_stats_updates;
+        let tick: Vec<Option<i32>> = updates.iter().map(|u| Some(u.tick)).collect();
+        let steamid: Vec<Option<u64>> = updates.iter().map(|u| u.steamid).collect();
+        let name: Vec<Option<String>> = updates.iter().map(|u| u.name.clone()).collect();
+        let stat_id: Vec<Option<i32>> = updates.iter().map(|u| Some(u.stat_id)).collect();
+        let stat_name: Vec<Option<String>> = updates.iter().map(|u| u.stat_name.clone()).collect();
+        let delta: Vec<Option<i32>> = updates.iter().map(|u| Some(u.delta)).collect();
+
+        let all_series_py = [
+            arr_to_py(Box::new(Int32Array::from(tick))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(stat_id))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(stat_name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(delta))).unwrap(),
+        ]
+        .to_object(py);
+        let polars = py.import_bound("polars")?;
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = ["tick", "steamid", "name", "stat_id", "stat_name", "delta"];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
     }
-    /// Returns the names of game events present in the demo
-    pub fn list_game_events(&self, _py: Python<'_>) -> PyResult<Py<PyAny>> {
+    /// Returns the end of match accolades: one row per player with steamid, name, accolade_id,
+    /// accolade_name (None if the id is not known), value and position.
+    pub fn parse_accolades(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         let settings = ParserInputs {
             real_name_to_og_name: AHashMap::default(),
             wanted_players: vec![],
             wanted_player_props: vec![],
             wanted_other_props: vec![],
             wanted_prop_states: AHashMap::default(),
-            wanted_events: vec!["all".to_string()],
+            wanted_events: vec![],
             parse_ents: false,
             wanted_ticks: vec![],
             parse_projectiles: false,
@@ -131,26 +1957,56 @@ impl DemoParser {
             only_convars: false,
             huffman_lookup_table: &self.huf,
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
         };
         let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
         let output = match parser.parse_demo(&self.mmap) {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
-        let as_vec = output.game_events_counter.iter().collect_vec();
-        let ge = pyo3::Python::with_gil(|py| as_vec.to_object(py));
-        Ok(ge)
-    }
-
-    /// Returns all coordinates of all grenades along with info about thrower.
-    ///
-    /// Example:
-    ///          X           Y       Z  tick  thrower_steamid grenade_type
-    /// 0 -388.875  1295.46875 -5120.0   982              NaN    HeGrenade
-    /// 1 -388.875  1295.46875 -5120.0   983              NaN    HeGrenade
-    /// 2 -388.875  1295.46875 -5120.0   983              NaN    HeGrenade
+        let accolades = &output.accolades;
+        let steamid: Vec<Option<u64>> = accolades.iter().map(|a| a.steamid).collect();
+        let name: Vec<Option<String>> = accolades.iter().map(|a| a.name.clone()).collect();
+        let accolade_id: Vec<Option<i32>> = accolades.iter().map(|a| a.accolade_id).collect();
+        let accolade_name: Vec<Option<String>> =
+            accolades.iter().map(|a| a.accolade_name.clone()).collect();
+        let value: Vec<Option<f32>> = accolades.iter().map(|a| a.value).collect();
+        let position: Vec<Option<i32>> = accolades.iter().map(|a| a.position).collect();
 
-    pub fn parse_grenades(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let all_series_py = [
+            arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(accolade_id))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(accolade_name))).unwrap(),
+            arr_to_py(Box::new(Float32Array::from(value))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(position))).unwrap(),
+        ]
+        .to_object(py);
+        let polars = py.import_bound("polars")?;
+        Python::with_gil(|py| {
+            let df = polars.call_method1("DataFrame", (all_series_py,))?;
+            let column_names = [
+                "steamid",
+                "name",
+                "accolade_id",
+                "accolade_name",
+                "value",
+                "position",
+            ];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
+            let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+            Ok(pandas_df.to_object(py))
+        })
+    }
+    /// Returns tactical/technical timeouts, pauses and votes: one row per interruption with type,
+    /// team, initiator_steamid, initiator_name, start_tick, end_tick and vote_outcome.
+    pub fn parse_match_interruptions(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         let settings = ParserInputs {
             real_name_to_og_name: AHashMap::default(),
             wanted_players: vec![],
@@ -160,68 +2016,91 @@ impl DemoParser {
             wanted_events: vec![],
             parse_ents: true,
             wanted_ticks: vec![],
-            parse_projectiles: true,
+            parse_projectiles: false,
             only_header: true,
             count_props: false,
             only_convars: false,
             huffman_lookup_table: &self.huf,
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
         };
-        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        // Interruptions can span chunks
+        let mut parser = Parser::new(
+            settings,
+            parser::parse_demo::ParsingMode::ForceSingleThreaded,
+        );
         let output = match parser.parse_demo(&self.mmap) {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
-
-        let entity_id: Vec<Option<i32>> = output.projectiles.iter().map(|s| s.entity_id).collect();
-        let xs: Vec<Option<f32>> = output.projectiles.iter().map(|s| s.x).collect();
-        let ys: Vec<Option<f32>> = output.projectiles.iter().map(|s| s.y).collect();
-        let zs: Vec<Option<f32>> = output.projectiles.iter().map(|s| s.z).collect();
-
-        let ticks: Vec<Option<i32>> = output.projectiles.iter().map(|s| s.tick).collect();
-        let steamid: Vec<Option<u64>> = output.projectiles.iter().map(|s| s.steamid).collect();
-        let name: Vec<Option<String>> = output.projectiles.iter().map(|s| s.name.clone()).collect();
-        let grenade_type: Vec<Option<String>> = output
-            .projectiles
+        let interruptions = &output.match_interruptions;
+        let interruption_type: Vec<Option<String>> = interruptions
             .iter()
-            .map(|s| s.grenade_type.clone())
+            .map(|i| Some(i.interruption_type.clone()))
+            .collect();
+        let team: Vec<Option<i32>> = interruptions.iter().map(|i| i.team).collect();
+        let initiator_steamid: Vec<Option<u64>> =
+            interruptions.iter().map(|i| i.initiator_steamid).collect();
+        let initiator_name: Vec<Option<String>> = interruptions
+            .iter()
+            .map(|i| i.initiator_name.clone())
+            .collect();
+        let start_tick: Vec<Option<i32>> =
+            interruptions.iter().map(|i| Some(i.start_tick)).collect();
+        let end_tick: Vec<Option<i32>> = interruptions.iter().map(|i| i.end_tick).collect();
+        let vote_outcome: Vec<Option<String>> = interruptions
+            .iter()
+            .map(|i| i.vote_outcome.clone())
             .collect();
 
-        // SoA form
-        let xs = arr_to_py(Box::new(Float32Array::from(xs))).unwrap();
-        let ys = arr_to_py(Box::new(Float32Array::from(ys))).unwrap();
-        let zs = arr_to_py(Box::new(Float32Array::from(zs))).unwrap();
-        // Actually not sure about Z coordinate. Leave out for now.
-        let ticks = arr_to_py(Box::new(Int32Array::from(ticks))).unwrap();
-        let grenade_type = arr_to_py(Box::new(Utf8Array::<i32>::from(grenade_type))).unwrap();
-        let name = arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap();
-        let steamids = arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap();
-        let entity_ids = arr_to_py(Box::new(Int32Array::from(entity_id))).unwrap();
-
+        let all_series_py = [
+            arr_to_py(Box::new(Utf8Array::<i32>::from(interruption_type))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(team))).unwrap(),
+            arr_to_py(Box::new(UInt64Array::from(initiator_steamid))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(initiator_name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(start_tick))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(end_tick))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(vote_outcome))).unwrap(),
+        ]
+        .to_object(py);
         let polars = py.import_bound("polars")?;
-        let all_series_py =
-            [xs, ys, zs, ticks, steamids, name, grenade_type, entity_ids].to_object(py);
         Python::with_gil(|py| {
             let df = polars.call_method1("DataFrame", (all_series_py,))?;
-            // Set column names
             let column_names = [
-                "X",
-                "Y",
-                "Z",
-                "tick",
-                "thrower_steamid",
-                "name",
-                "grenade_type",
-                "entity_id",
+                "type",
+                "team",
+                "initiator_steamid",
+                "initiator_name",
+                "start_tick",
+                "end_tick",
+                "vote_outcome",
             ];
             df.setattr("columns", column_names.to_object(py)).unwrap();
-            // Call to_pandas with use_pyarrow_extension_array = true
             let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
             let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
             Ok(pandas_df.to_object(py))
         })
     }
-    pub fn parse_player_info(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+    /// Returns every net message of the wanted types (NetMessageType names such as
+    /// "CS_UM_VoteStart" or "svc_ServerInfo") as dicts with "tick", "msg_type" and "data",
+    /// where data has the decoded protobuf fields. Raises ValueError for a name without a
+    /// known protobuf struct.
+    pub fn parse_net_messages(
+        &self,
+        py: Python<'_>,
+        msg_types: Vec<String>,
+    ) -> PyResult<Py<PyAny>> {
+        let supported = supported_net_message_types();
+        if let Some(name) = msg_types.iter().find(|name| !supported.contains(name)) {
+            return Err(PyValueError::new_err(format!(
+                "unknown or unsupported net message type: {name}"
+            )));
+        }
         let settings = ParserInputs {
             real_name_to_og_name: AHashMap::default(),
             wanted_players: vec![],
@@ -237,36 +2116,123 @@ impl DemoParser {
             only_convars: false,
             huffman_lookup_table: &self.huf,
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: msg_types,
+            parse_string_tables: false,
         };
         let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
         let output = match parser.parse_demo(&self.mmap) {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
-        let steamids: Vec<Option<u64>> = output.player_md.iter().map(|p| p.steamid).collect();
-        let team_numbers: Vec<Option<i32>> =
-            output.player_md.iter().map(|p| p.team_number).collect();
-        let names: Vec<Option<String>> = output.player_md.iter().map(|p| p.name.clone()).collect();
-
-        // SoA form
-        let steamid = rust_series_to_py_series(&Series::new("Steamid", steamids))?;
-        let team_number = arr_to_py(Box::new(Int32Array::from(team_numbers)))?;
-        let name = rust_series_to_py_series(&Series::new("param2", names))?;
+        let json = py.import_bound("json")?;
+        let messages = PyList::empty_bound(py);
+        for msg in &output.net_messages {
+            let dict = PyDict::new_bound(py);
+            dict.set_item("tick", msg.tick)?;
+            dict.set_item("msg_type", &msg.msg_type)?;
+            dict.set_item("data", json.call_method1("loads", (msg.data.to_string(),))?)?;
+            messages.append(dict)?;
+        }
+        Ok(messages.to_object(py))
+    }
+    /// Returns the last value of every convar the server set during the demo.
+    pub fn parse_convars(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: false,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        Ok(output.convars.to_object(py))
+    }
+    /// Returns every convar change with tick, name, old_value (None the first time the convar
+    /// is set) and new_value.
+    pub fn parse_convar_changes(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let settings = ParserInputs {
+            real_name_to_og_name: AHashMap::default(),
+            wanted_players: vec![],
+            wanted_player_props: vec![],
+            wanted_other_props: vec![],
+            wanted_prop_states: AHashMap::default(),
+            wanted_events: vec![],
+            parse_ents: false,
+            wanted_ticks: vec![],
+            parse_projectiles: false,
+            only_header: true,
+            count_props: false,
+            only_convars: false,
+            huffman_lookup_table: &self.huf,
+            order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
+        };
+        let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
+        let output = match parser.parse_demo(&self.mmap) {
+            Ok(output) => output,
+            Err(e) => return Err(Exception::new_err(format!("{e}"))),
+        };
+        let changes = &output.convar_changes;
+        let tick: Vec<Option<i32>> = changes.iter().map(|c| Some(c.tick)).collect();
+        let name: Vec<Option<String>> = changes.iter().map(|c| Some(c.name.clone())).collect();
+        let old_value: Vec<Option<String>> = changes.iter().map(|c| c.old_value.clone()).collect();
+        let new_value: Vec<Option<String>> =
+            changes.iter().map(|c| Some(c.new_value.clone())).collect();
 
+        let all_series_py = [
+            arr_to_py(Box::new(Int32Array::from(tick))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(old_value))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(new_value))).unwrap(),
+        ]
+        .to_object(py);
         let polars = py.import_bound("polars")?;
-        let all_series_py = [steamid, name, team_number].to_object(py);
         Python::with_gil(|py| {
             let df = polars.call_method1("DataFrame", (all_series_py,))?;
-            // Set column names
-            let column_names = ["steamid", "name", "team_number"];
-            df.setattr("columns", column_names.to_object(py))?;
-            // Call to_pandas with use_pyarrow_extension_array = true
+            let column_names = ["tick", "name", "old_value", "new_value"];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
             let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
-            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs))?;
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
             Ok(pandas_df.to_object(py))
         })
     }
-    pub fn parse_item_drops(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+    /// Returns the entries of all string tables ("modelprecache", "userinfo", "instancebaseline" ...)
+    /// every time they are created or changed: tick, table_name, index, key and value (bytes).
+    /// Use tables to only get some of them.
+    #[pyo3(signature = (*, tables=None))]
+    pub fn parse_string_tables(
+        &self,
+        py: Python<'_>,
+        tables: Option<Vec<String>>,
+    ) -> PyResult<Py<PyAny>> {
         let settings = ParserInputs {
             real_name_to_og_name: AHashMap::default(),
             wanted_players: vec![],
@@ -282,68 +2248,45 @@ impl DemoParser {
             only_convars: false,
             huffman_lookup_table: &self.huf,
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: true,
         };
         let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
         let output = match parser.parse_demo(&self.mmap) {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
-        let def_index: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.def_index).collect();
-        let account_id: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.account_id).collect();
-        let dropreason: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.dropreason).collect();
-        let inventory: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.inventory).collect();
-        let item_id: Vec<Option<u64>> = output.item_drops.iter().map(|x| x.item_id).collect();
-        let paint_index: Vec<Option<u32>> =
-            output.item_drops.iter().map(|x| x.paint_index).collect();
-        let paint_seed: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.paint_seed).collect();
-        let paint_wear: Vec<Option<u32>> = output.item_drops.iter().map(|x| x.paint_wear).collect();
-        let custom_name: Vec<Option<String>> = output
-            .item_drops
+        let records: Vec<_> = output
+            .string_table_records
             .iter()
-            .map(|x| x.custom_name.clone())
+            .filter(|r| tables.as_ref().map_or(true, |t| t.contains(&r.table_name)))
             .collect();
-        // SoA form
-        let account_id = arr_to_py(Box::new(UInt32Array::from(account_id)))?;
-        let def_index = arr_to_py(Box::new(UInt32Array::from(def_index)))?;
-        let dropreason = arr_to_py(Box::new(UInt32Array::from(dropreason)))?;
-        let inventory = arr_to_py(Box::new(UInt32Array::from(inventory)))?;
-        let item_id = arr_to_py(Box::new(UInt64Array::from(item_id)))?;
-        let paint_index = arr_to_py(Box::new(UInt32Array::from(paint_index)))?;
-        let paint_seed = arr_to_py(Box::new(UInt32Array::from(paint_seed)))?;
-        let paint_wear = arr_to_py(Box::new(UInt32Array::from(paint_wear)))?;
-        let custom_name = rust_series_to_py_series(&Series::new("custom_name", custom_name))?;
+        let tick: Vec<Option<i32>> = records.iter().map(|r| Some(r.tick)).collect();
+        let table_name: Vec<Option<String>> =
+            records.iter().map(|r| Some(r.table_name.clone())).collect();
+        let index: Vec<Option<i32>> = records.iter().map(|r| Some(r.idx)).collect();
+        let key: Vec<Option<String>> = records.iter().map(|r| Some(r.key.clone())).collect();
+        let value = BinaryArray::<i32>::from_iter(records.iter().map(|r| Some(&r.value)));
 
-        let polars = py.import_bound("polars")?;
         let all_series_py = [
-            account_id,
-            def_index,
-            dropreason,
-            inventory,
-            item_id,
-            paint_index,
-            paint_seed,
-            paint_wear,
-            custom_name,
+            arr_to_py(Box::new(Int32Array::from(tick))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(table_name))).unwrap(),
+            arr_to_py(Box::new(Int32Array::from(index))).unwrap(),
+            arr_to_py(Box::new(Utf8Array::<i32>::from(key))).unwrap(),
+            arr_to_py(Box::new(value)).unwrap(),
         ]
         .to_object(py);
+        let polars = py.import_bound("polars")?;
         Python::with_gil(|py| {
             let df = polars.call_method1("DataFrame", (all_series_py,))?;
-            // Set column names
-            let column_names = [
-                "account_id",
-                "def_index",
-                "dropreason",
-                "inventory",
-                "item_id",
-                "paint_index",
-                "paint_seed",
-                "paint_wear",
-                "custom_name",
-            ];
-            df.setattr("columns", column_names.to_object(py))?;
-            // Call to_pandas with use_pyarrow_extension_array = true
+            let column_names = ["tick", "table_name", "index", "key", "value"];
+            df.setattr("columns", column_names.to_object(py)).unwrap();
             let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
-            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs))?;
+            let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
             Ok(pandas_df.to_object(py))
         })
     }
@@ -363,6 +2306,12 @@ impl DemoParser {
             only_convars: false,
             huffman_lookup_table: &self.huf,
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
         };
         let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
         let output = match parser.parse_demo(&self.mmap) {
@@ -418,13 +2367,21 @@ impl DemoParser {
         })
     }
 
-    #[pyo3(signature = (event_name, *, player=None, other=None))]
+    /// With shot_details=True weapon_fire events get the subtick timing of the shot
+    /// (subtick_fraction, render_tick_fraction), the view angles (shot_pitch, shot_yaw) and the
+    /// position (shot_X, shot_Y, shot_Z). Shots fired by holding down attack with automatic
+    /// weapons have no subtick data and get None. The movement state at the shot is added too:
+    /// shot_velocity, shot_accurate_speed, shot_ducking, shot_walking, shot_airborne,
+    /// shot_movement_keys and shot_counter_strafe. Decodes the usercmds, so this is slower.
+    #[pyo3(signature = (event_name, *, player=None, other=None, nav_path=None, shot_details=false))]
     pub fn parse_event(
         &self,
         py: Python<'_>,
         event_name: String,
         player: Option<Vec<String>>,
         other: Option<Vec<String>>,
+        nav_path: Option<String>,
+        shot_details: bool,
     ) -> PyResult<Py<PyAny>> {
         let wanted_player_props = player.unwrap_or_default();
         let wanted_other_props = other.unwrap_or_default();
@@ -464,12 +2421,25 @@ impl DemoParser {
             only_convars: false,
             huffman_lookup_table: &self.huf,
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: shot_details,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
         };
         let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let mut output = match parser.parse_demo(&self.mmap) {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
+        if let Some(nav_path) = nav_path {
+            let mut nav = match NavMesh::from_path(&nav_path) {
+                Ok(nav) => nav,
+                Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+            };
+            add_nav_fields(&mut output.game_events, &mut nav);
+        }
         let event_series = match series_from_event(&output.game_events, py) {
             Ok(ser) => ser,
             Err(_e) => return Ok(PyList::empty_bound(py).into()),
@@ -477,13 +2447,16 @@ impl DemoParser {
         Ok(event_series)
     }
 
-    #[pyo3(signature = (event_name, *, player=None, other=None))]
+    /// shot_details works like in parse_event.
+    #[pyo3(signature = (event_name, *, player=None, other=None, nav_path=None, shot_details=false))]
     pub fn parse_events(
         &self,
         py: Python<'_>,
         event_name: Vec<String>,
         player: Option<Vec<String>>,
         other: Option<Vec<String>>,
+        nav_path: Option<String>,
+        shot_details: bool,
     ) -> PyResult<Py<PyAny>> {
         let wanted_player_props = player.unwrap_or_default();
         let wanted_other_props = other.unwrap_or_default();
@@ -523,12 +2496,25 @@ impl DemoParser {
             only_convars: false,
             huffman_lookup_table: &self.huf,
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: shot_details,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
         };
         let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
-        let output = match parser.parse_demo(&self.mmap) {
+        let mut output = match parser.parse_demo(&self.mmap) {
             Ok(output) => output,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
         };
+        if let Some(nav_path) = nav_path {
+            let mut nav = match NavMesh::from_path(&nav_path) {
+                Ok(nav) => nav,
+                Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+            };
+            add_nav_fields(&mut output.game_events, &mut nav);
+        }
         let event_series = match series_from_multiple_events(&output.game_events, py) {
             Ok(ser) => ser,
             Err(e) => return Err(Exception::new_err(format!("{e}"))),
@@ -552,6 +2538,12 @@ impl DemoParser {
             only_convars: false,
             huffman_lookup_table: &vec![],
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
         };
         let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
         let output = match parser.parse_demo(&self.mmap) {
@@ -567,7 +2559,7 @@ impl DemoParser {
         Ok(out_hm.to_object(py))
     }
 
-    #[pyo3(signature = (wanted_props, *, players=None, ticks=None, prop_states=None))]
+    #[pyo3(signature = (wanted_props, *, players=None, ticks=None, prop_states=None, nav_path=None))]
     pub fn parse_ticks(
         &self,
         py: Python,
@@ -575,6 +2567,7 @@ impl DemoParser {
         players: Option<Vec<u64>>,
         ticks: Option<Vec<i32>>,
         prop_states: Option<Vec<WantedPropState>>,
+        nav_path: Option<String>,
     ) -> PyResult<PyObject> {
         let wanted_players = players.unwrap_or_default();
         let wanted_ticks = ticks.unwrap_or_default();
@@ -622,6 +2615,12 @@ impl DemoParser {
             only_convars: false,
             huffman_lookup_table: &arc_huf,
             order_by_steamid: false,
+            parse_economy: false,
+            parse_visibility: false,
+            parse_ticks_with_events: false,
+            parse_shot_details: false,
+            wanted_net_messages: vec![],
+            parse_string_tables: false,
         };
         let mut parser = Parser::new(settings, parser::parse_demo::ParsingMode::Normal);
         let output = match parser.parse_demo(&self.mmap) {
@@ -630,6 +2629,12 @@ impl DemoParser {
         };
         let mut all_series = vec![];
         let mut all_pyobjects = vec![];
+        let nav_series = match &nav_path {
+            Some(nav_path) => {
+                nav_columns(&output.df, &output.prop_controller.prop_infos, nav_path)?
+            }
+            None => vec![],
+        };
         let prop_infos = output.prop_controller.prop_infos;
         let mut df_column_names_arrow = vec![];
         let mut df_column_names_py = vec![];
@@ -735,6 +2740,10 @@ impl DemoParser {
                 }
             }
         }
+        for (column_name, series) in nav_series {
+            df_column_names_arrow.push(column_name.to_string());
+            all_series.push(series);
+        }
         Python::with_gil(|py| {
             let polars = py.import_bound("polars")?;
             let all_series_py = all_series.to_object(py);
@@ -754,6 +2763,105 @@ impl DemoParser {
     }
 }
 
+// Nav area columns for parse_ticks. Place names are learned from last_place_name if it was parsed.
+fn nav_columns(
+    df: &AHashMap<u32, PropColumn>,
+    prop_infos: &[PropInfo],
+    nav_path: &str,
+) -> PyResult<Vec<(&'static str, PyObject)>> {
+    let mut nav = match NavMesh::from_path(nav_path) {
+        Ok(nav) => nav,
+        Err(e) => return Err(PyValueError::new_err(format!("{e}"))),
+    };
+    let positions = match df_positions(df) {
+        Some(positions) => positions,
+        None => {
+            return Err(PyValueError::new_err(
+                "nav_path requires X, Y and Z in wanted_props",
+            ))
+        }
+    };
+    learn_df_places(&mut nav, df, prop_infos, &positions);
+    let areas: Vec<Option<&NavArea>> = positions
+        .iter()
+        .map(|pos| pos.and_then(|pos| nav.find_area(pos)))
+        .collect();
+    let centroid = |axis: usize| -> Vec<Option<f32>> {
+        areas
+            .iter()
+            .map(|area| area.map(|a| a.centroid()[axis]))
+            .collect()
+    };
+    let area_id: Vec<Option<u32>> = areas.iter().map(|area| area.map(|a| a.area_id)).collect();
+    let place: Vec<Option<String>> = areas
+        .iter()
+        .map(|area| area.and_then(|a| nav.place_name(a.area_id)).cloned())
+        .collect();
+    Ok(vec![
+        (
+            "nav_area_id",
+            arr_to_py(Box::new(UInt32Array::from(area_id)))?,
+        ),
+        (
+            "nav_area_X",
+            arr_to_py(Box::new(Float32Array::from(centroid(0))))?,
+        ),
+        (
+            "nav_area_Y",
+            arr_to_py(Box::new(Float32Array::from(centroid(1))))?,
+        ),
+        (
+            "nav_area_Z",
+            arr_to_py(Box::new(Float32Array::from(centroid(2))))?,
+        ),
+        (
+            "nav_place",
+            arr_to_py(Box::new(Utf8Array::<i32>::from(place)))?,
+        ),
+    ])
+}
+
+fn df_positions(df: &AHashMap<u32, PropColumn>) -> Option<Vec<Option<[f32; 3]>>> {
+    let coordinate = |id: u32| match df.get(&id).and_then(|col| col.data.as_ref()) {
+        Some(VarVec::F32(data)) => Some(data),
+        _ => None,
+    };
+    let (xs, ys, zs) = (
+        coordinate(PLAYER_X_ID)?,
+        coordinate(PLAYER_Y_ID)?,
+        coordinate(PLAYER_Z_ID)?,
+    );
+    Some(
+        xs.iter()
+            .zip(ys)
+            .zip(zs)
+            .map(|((x, y), z)| Some([(*x)?, (*y)?, (*z)?]))
+            .collect(),
+    )
+}
+
+// Learns the nav place names from the last_place_name column, if it was parsed
+fn learn_df_places(
+    nav: &mut NavMesh,
+    df: &AHashMap<u32, PropColumn>,
+    prop_infos: &[PropInfo],
+    positions: &[Option<[f32; 3]>],
+) {
+    let place_column = prop_infos
+        .iter()
+        .find(|info| info.prop_name == LAST_PLACE_NAME)
+        .and_then(|info| df.get(&info.id))
+        .and_then(|col| col.data.as_ref());
+    if let Some(VarVec::String(places)) = place_column {
+        let samples: Vec<([f32; 3], String)> = positions
+            .iter()
+            .zip(places)
+            .filter_map(|(pos, place)| Some(((*pos)?, place.clone()?)))
+            .collect();
+        nav.learn_places(&samples);
+    }
+}
+
 /// <https://github.com/pola-rs/polars/blob/master/examples/python_rust_compiled_function/src/ffi.rs>
 pub(crate) fn to_py_array(
     py: Python,
@@ -778,6 +2886,114 @@ pub(crate) fn to_py_array(
     Ok(array.to_object(py))
 }
 
+fn flash_stats_to_df(py: Python<'_>, stats: &[FlashStats]) -> PyResult<Py<PyAny>> {
+    let steamid: Vec<Option<u64>> = stats.iter().map(|s| Some(s.steamid)).collect();
+    let name: Vec<Option<String>> = stats.iter().map(|s| s.name.clone()).collect();
+    let thrown: Vec<Option<i32>> = stats.iter().map(|s| Some(s.thrown)).collect();
+    let enemies_blinded: Vec<Option<i32>> = stats.iter().map(|s| Some(s.enemies_blinded)).collect();
+    let teammates_blinded: Vec<Option<i32>> =
+        stats.iter().map(|s| Some(s.teammates_blinded)).collect();
+    let enemy_blind_duration: Vec<Option<f32>> =
+        stats.iter().map(|s| Some(s.enemy_blind_duration)).collect();
+    let team_blind_duration: Vec<Option<f32>> =
+        stats.iter().map(|s| Some(s.team_blind_duration)).collect();
+    let blinded_enemies_killed: Vec<Option<i32>> = stats
+        .iter()
+        .map(|s| Some(s.blinded_enemies_killed))
+        .collect();
+    let flash_assists: Vec<Option<i32>> = stats.iter().map(|s| Some(s.flash_assists)).collect();
+
+    let all_series_py = [
+        arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap(),
+        arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(thrown))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(enemies_blinded))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(teammates_blinded))).unwrap(),
+        arr_to_py(Box::new(Float32Array::from(enemy_blind_duration))).unwrap(),
+        arr_to_py(Box::new(Float32Array::from(team_blind_duration))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(blinded_enemies_killed))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(flash_assists))).unwrap(),
+    ]
+    .to_object(py);
+    let polars = py.import_bound("polars")?;
+    let df = polars.call_method1("DataFrame", (all_series_py,))?;
+    let column_names = [
+        "steamid",
+        "name",
+        "thrown",
+        "enemies_blinded",
+        "teammates_blinded",
+        "enemy_blind_duration",
+        "team_blind_duration",
+        "blinded_enemies_killed",
+        "flash_assists",
+    ];
+    df.setattr("columns", column_names.to_object(py)).unwrap();
+    let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+    let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+    Ok(pandas_df.to_object(py))
+}
+
+fn player_round_stats_to_df(py: Python<'_>, stats: &[PlayerRoundStats]) -> PyResult<Py<PyAny>> {
+    let round: Vec<Option<i32>> = stats.iter().map(|s| Some(s.round)).collect();
+    let steamid: Vec<Option<u64>> = stats.iter().map(|s| Some(s.steamid)).collect();
+    let name: Vec<Option<String>> = stats.iter().map(|s| s.name.clone()).collect();
+    let team_num: Vec<Option<i32>> = stats.iter().map(|s| s.team_num).collect();
+    let kills: Vec<Option<i32>> = stats.iter().map(|s| Some(s.kills)).collect();
+    let deaths: Vec<Option<i32>> = stats.iter().map(|s| Some(s.deaths)).collect();
+    let assists: Vec<Option<i32>> = stats.iter().map(|s| Some(s.assists)).collect();
+    let flash_assists: Vec<Option<i32>> = stats.iter().map(|s| Some(s.flash_assists)).collect();
+    let headshot_kills: Vec<Option<i32>> = stats.iter().map(|s| Some(s.headshot_kills)).collect();
+    let damage: Vec<Option<i32>> = stats.iter().map(|s| Some(s.damage)).collect();
+    let utility_damage: Vec<Option<i32>> = stats.iter().map(|s| Some(s.utility_damage)).collect();
+    let opening_kill: Vec<Option<bool>> = stats.iter().map(|s| Some(s.opening_kill)).collect();
+    let opening_death: Vec<Option<bool>> = stats.iter().map(|s| Some(s.opening_death)).collect();
+    let traded_death: Vec<Option<bool>> = stats.iter().map(|s| Some(s.traded_death)).collect();
+    let kast: Vec<Option<bool>> = stats.iter().map(|s| Some(s.kast)).collect();
+
+    let all_series_py = [
+        arr_to_py(Box::new(Int32Array::from(round))).unwrap(),
+        arr_to_py(Box::new(UInt64Array::from(steamid))).unwrap(),
+        arr_to_py(Box::new(Utf8Array::<i32>::from(name))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(team_num))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(kills))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(deaths))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(assists))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(flash_assists))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(headshot_kills))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(damage))).unwrap(),
+        arr_to_py(Box::new(Int32Array::from(utility_damage))).unwrap(),
+        arr_to_py(Box::new(BooleanArray::from(opening_kill))).unwrap(),
+        arr_to_py(Box::new(BooleanArray::from(opening_death))).unwrap(),
+        arr_to_py(Box::new(BooleanArray::from(traded_death))).unwrap(),
+        arr_to_py(Box::new(BooleanArray::from(kast))).unwrap(),
+    ]
+    .to_object(py);
+    let polars = py.import_bound("polars")?;
+    let df = polars.call_method1("DataFrame", (all_series_py,))?;
+    let column_names = [
+        "round",
+        "steamid",
+        "name",
+        "team_num",
+        "kills",
+        "deaths",
+        "assists",
+        "flash_assists",
+        "headshot_kills",
+        "damage",
+        "utility_damage",
+        "opening_kill",
+        "opening_death",
+        "traded_death",
+        "kast",
+    ];
+    df.setattr("columns", column_names.to_object(py)).unwrap();
+    let kwargs = vec![("use_pyarrow_extension_array", true)].into_py_dict_bound(py);
+    let pandas_df = df.call_method("to_pandas", (), Some(&kwargs)).unwrap();
+    Ok(pandas_df.to_object(py))
+}
+
 /// <https://github.com/pola-rs/polars/blob/master/examples/python_rust_compiled_function/src/ffi.rs>
 pub fn rust_series_to_py_series(series: &Series) -> PyResult<PyObject> {
     // ensure we have a single chunk
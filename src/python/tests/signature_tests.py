@@ -21,6 +21,20 @@ class SignatureTest(TestCase):
             self.assertIsInstance(key, str)
             self.assertIsInstance(value, str)
 
+    def test_parse_file_info_signature(self):
+        parser = DemoParser(demo_path)
+        file_info = parser.parse_file_info()
+        self.assertIsInstance(file_info, dict)
+        self.assertIsInstance(file_info["round_start_ticks"], list)
+
+    def test_parse_map_data_signature(self):
+        parser = DemoParser(demo_path)
+        map_data = parser.parse_map_data()
+        if map_data is not None:
+            self.assertIsInstance(map_data, dict)
+            self.assertIsInstance(map_data["scale"], float)
+            self.assertIsInstance(map_data["levels"], list)
+
     def test_list_game_events_signature(self):
         parser = DemoParser(demo_path)
         game_events = parser.list_game_events()
@@ -33,16 +47,121 @@ class SignatureTest(TestCase):
         grenades = parser.parse_grenades()
         self.assertIsInstance(grenades, pd.DataFrame)
 
+    def test_parse_infernos_signature(self):
+        parser = DemoParser(demo_path)
+        infernos = parser.parse_infernos()
+        self.assertIsInstance(infernos, pd.DataFrame)
+
+    def test_parse_smokes_signature(self):
+        parser = DemoParser(demo_path)
+        smokes = parser.parse_smokes()
+        self.assertIsInstance(smokes, pd.DataFrame)
+
     def test_parse_player_info_signature(self):
         parser = DemoParser(demo_path)
         player_info = parser.parse_player_info()
         self.assertIsInstance(player_info, pd.DataFrame)
 
+    def test_parse_rounds_signature(self):
+        parser = DemoParser(demo_path)
+        rounds = parser.parse_rounds()
+        self.assertIsInstance(rounds, pd.DataFrame)
+
+    def test_parse_economy_signature(self):
+        parser = DemoParser(demo_path)
+        economy = parser.parse_economy()
+        self.assertIsInstance(economy, pd.DataFrame)
+
+    def test_parse_player_stats_signature(self):
+        parser = DemoParser(demo_path)
+        player_stats = parser.parse_player_stats()
+        self.assertIsInstance(player_stats, pd.DataFrame)
+        player_round_stats = parser.parse_player_stats(per_round=True)
+        self.assertIsInstance(player_round_stats, pd.DataFrame)
+
+    def test_parse_trade_kills_signature(self):
+        parser = DemoParser(demo_path)
+        trade_kills = parser.parse_trade_kills()
+        self.assertIsInstance(trade_kills, pd.DataFrame)
+        trade_kills = parser.parse_trade_kills(window_ticks=128)
+        self.assertIsInstance(trade_kills, pd.DataFrame)
+
+    def test_parse_weapon_accuracy_signature(self):
+        parser = DemoParser(demo_path)
+        weapon_accuracy = parser.parse_weapon_accuracy()
+        self.assertIsInstance(weapon_accuracy, pd.DataFrame)
+
+    def test_parse_flashes_signature(self):
+        parser = DemoParser(demo_path)
+        flashes = parser.parse_flashes()
+        self.assertIsInstance(flashes, pd.DataFrame)
+        flash_stats = parser.parse_flashes(per_thrower=True)
+        self.assertIsInstance(flash_stats, pd.DataFrame)
+
+    def test_parse_grenade_damage_signature(self):
+        parser = DemoParser(demo_path)
+        grenade_damage = parser.parse_grenade_damage()
+        self.assertIsInstance(grenade_damage, pd.DataFrame)
+
+    def test_parse_clutches_signature(self):
+        parser = DemoParser(demo_path)
+        clutches = parser.parse_clutches()
+        self.assertIsInstance(clutches, pd.DataFrame)
+
+    def test_parse_visibility_signature(self):
+        parser = DemoParser(demo_path)
+        visibility = parser.parse_visibility()
+        self.assertIsInstance(visibility, pd.DataFrame)
+
+    def test_parse_aim_signature(self):
+        parser = DemoParser(demo_path)
+        aim = parser.parse_aim()
+        self.assertIsInstance(aim, pd.DataFrame)
+
     def test_parse_item_drops_signature(self):
         parser = DemoParser(demo_path)
         item_drops = parser.parse_item_drops()
         self.assertIsInstance(item_drops, pd.DataFrame)
 
+    def test_parse_player_stats_updates_signature(self):
+        parser = DemoParser(demo_path)
+        player_stats_updates = parser.parse_player_stats_updates()
+        self.assertIsInstance(player_stats_updates, pd.DataFrame)
+
+    def test_parse_accolades_signature(self):
+        parser = DemoParser(demo_path)
+        accolades = parser.parse_accolades()
+        self.assertIsInstance(accolades, pd.DataFrame)
+
+    def test_parse_match_interruptions_signature(self):
+        parser = DemoParser(demo_path)
+        match_interruptions = parser.parse_match_interruptions()
+        self.assertIsInstance(match_interruptions, pd.DataFrame)
+
+    def test_parse_net_messages_signature(self):
+        parser = DemoParser(demo_path)
+        net_messages = parser.parse_net_messages(["svc_ServerInfo"])
+        self.assertIsInstance(net_messages, list)
+        for msg in net_messages:
+            self.assertIsInstance(msg["data"], dict)
+        with self.assertRaises(ValueError):
+            parser.parse_net_messages(["svc_NotAMessage"])
+
+    def test_parse_convars_signature(self):
+        parser = DemoParser(demo_path)
+        convars = parser.parse_convars()
+        self.assertIsInstance(convars, dict)
+
+    def test_parse_convar_changes_signature(self):
+        parser = DemoParser(demo_path)
+        convar_changes = parser.parse_convar_changes()
+        self.assertIsInstance(convar_changes, pd.DataFrame)
+
+    def test_parse_string_tables_signature(self):
+        parser = DemoParser(demo_path)
+        string_tables = parser.parse_string_tables(tables=["modelprecache"])
+        self.assertIsInstance(string_tables, pd.DataFrame)
+
     def test_parse_skins_signature(self):
         parser = DemoParser(demo_path)
         skins = parser.parse_skins()
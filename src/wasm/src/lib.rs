@@ -57,6 +57,12 @@ pub fn parseEvent(
         only_convars: false,
         huffman_lookup_table: &arc_huf,
         order_by_steamid: false,
+        parse_economy: false,
+        parse_visibility: false,
+        parse_ticks_with_events: false,
+        parse_shot_details: false,
+        wanted_net_messages: vec![],
+        parse_string_tables: false,
         wanted_prop_states: HashMap::default().into(),
     };
     let mut parser = Parser::new(settings, ForceSingleThreaded);
@@ -120,6 +126,12 @@ pub fn parseEvents(
         only_convars: false,
         huffman_lookup_table: &arc_huf,
         order_by_steamid: false,
+        parse_economy: false,
+        parse_visibility: false,
+        parse_ticks_with_events: false,
+        parse_shot_details: false,
+        wanted_net_messages: vec![],
+        parse_string_tables: false,
         wanted_prop_states: HashMap::default().into(),
     };
     let mut parser = Parser::new(settings, ForceSingleThreaded);
@@ -151,6 +163,12 @@ pub fn listGameEvents(fileBytes: Vec<u8>) -> Result<JsValue, JsError> {
         only_convars: false,
         huffman_lookup_table: &arc_huf.clone(),
         order_by_steamid: false,
+        parse_economy: false,
+        parse_visibility: false,
+        parse_ticks_with_events: false,
+        parse_shot_details: false,
+        wanted_net_messages: vec![],
+        parse_string_tables: false,
         wanted_prop_states: HashMap::default().into(),
     };
     let mut parser = Parser::new(settings, ForceSingleThreaded);
@@ -212,6 +230,12 @@ pub fn parseTicks(
         only_convars: false,
         huffman_lookup_table: &arc_huf.clone(),
         order_by_steamid: false,
+        parse_economy: false,
+        parse_visibility: false,
+        parse_ticks_with_events: false,
+        parse_shot_details: false,
+        wanted_net_messages: vec![],
+        parse_string_tables: false,
         wanted_prop_states: HashMap::default().into(),
     };
     let mut parser = Parser::new(settings, ForceSingleThreaded);
@@ -267,6 +291,12 @@ pub fn parseGrenades(file: Vec<u8>) -> Result<JsValue, JsError> {
         only_convars: false,
         huffman_lookup_table: &arc_huf.clone(),
         order_by_steamid: false,
+        parse_economy: false,
+        parse_visibility: false,
+        parse_ticks_with_events: false,
+        parse_shot_details: false,
+        wanted_net_messages: vec![],
+        parse_string_tables: false,
         wanted_prop_states: HashMap::default().into(),
     };
     let mut parser = Parser::new(settings, ForceSingleThreaded);
@@ -300,6 +330,12 @@ pub fn parseHeader(file: Vec<u8>) -> Result<JsValue, JsError> {
         only_convars: false,
         huffman_lookup_table: &arc_huf.clone(),
         order_by_steamid: false,
+        parse_economy: false,
+        parse_visibility: false,
+        parse_ticks_with_events: false,
+        parse_shot_details: false,
+        wanted_net_messages: vec![],
+        parse_string_tables: false,
         wanted_prop_states: HashMap::default().into(),
     };
     let mut parser = Parser::new(settings, ForceSingleThreaded);